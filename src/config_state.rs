@@ -0,0 +1,82 @@
+use pinocchio::pubkey::Pubkey;
+
+/// Program-wide settings, stored in the single `["config"]` PDA.
+#[repr(C, packed)]
+pub struct Config {
+    pub discriminator: u8,
+    pub version: u8,
+    pub admin: Pubkey,
+    pub claims_enabled: u8,
+    pub bump: u8,
+}
+
+use crate::{Discriminator, PinocchioError, ACCOUNT_VERSION};
+
+impl Discriminator for Config {
+    const LEN: usize = Self::LEN;
+    const DISCRIMINATOR: u8 = Self::DISCRIMINATOR;
+}
+
+impl Config {
+    pub const LEN: usize = std::mem::size_of::<Pubkey>() + std::mem::size_of::<u8>() * 4;
+    pub const DISCRIMINATOR: u8 = 2;
+
+    #[inline(always)]
+    pub fn load_mut(bytes: &mut [u8]) -> Result<&mut Self, pinocchio::program_error::ProgramError> {
+        if bytes.len() != Config::LEN {
+            return Err(pinocchio::program_error::ProgramError::InvalidAccountData);
+        }
+        Self::check_version(bytes[1])?;
+        Ok(unsafe { &mut *core::mem::transmute::<*mut u8, *mut Self>(bytes.as_mut_ptr()) })
+    }
+
+    #[inline(always)]
+    pub fn load(bytes: &[u8]) -> Result<&Self, pinocchio::program_error::ProgramError> {
+        if bytes.len() != Config::LEN {
+            return Err(pinocchio::program_error::ProgramError::InvalidAccountData);
+        }
+        Self::check_version(bytes[1])?;
+
+        Ok(unsafe { &*core::mem::transmute::<*const u8, *const Self>(bytes.as_ptr()) })
+    }
+
+    /// Version 0 is a freshly allocated, not yet written account.
+    #[inline(always)]
+    fn check_version(version: u8) -> Result<(), pinocchio::program_error::ProgramError> {
+        match version {
+            0..=ACCOUNT_VERSION => Ok(()),
+            _ => Err(PinocchioError::UnsupportedVersion.into()),
+        }
+    }
+
+    #[inline(always)]
+    pub fn admin(&self) -> &Pubkey {
+        &self.admin
+    }
+
+    #[inline(always)]
+    pub fn claims_enabled(&self) -> bool {
+        self.claims_enabled != 0
+    }
+
+    #[inline(always)]
+    pub fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    pub fn set_admin(&mut self, admin: Pubkey) {
+        self.admin = admin;
+    }
+
+    pub fn set_claims_enabled(&mut self, enabled: bool) {
+        self.claims_enabled = enabled as u8;
+    }
+
+    pub fn set_inner(&mut self, admin: Pubkey, bump: u8) {
+        self.discriminator = Config::DISCRIMINATOR;
+        self.version = ACCOUNT_VERSION;
+        self.admin = admin;
+        self.claims_enabled = 1;
+        self.bump = bump;
+    }
+}
@@ -1,5 +1,7 @@
 use {pinocchio::program_error::ProgramError, thiserror::Error};
 
+/// Surfaced to clients as `ProgramError::Custom(code)`, where the code is the variant's
+/// position. Clients decode by that number, so new variants are only ever appended.
 #[derive(Debug, Error)]
 pub enum PinocchioError {
     #[error("Lamport balance below rent-exempt threshold")]
@@ -28,6 +30,72 @@ pub enum PinocchioError {
     InvalidSeed,
     #[error("Invalid state discriminator")]
     InvalidDiscriminator,
+    #[error("Claim requires the schedule co-signer")]
+    CosignerRequired,
+    #[error("Vault balance does not cover the required amount")]
+    VaultUnderfunded,
+    #[error("Unsupported account version")]
+    UnsupportedVersion,
+    #[error("Step duration exceeds the vesting period after the cliff")]
+    StepExceedsVestingPeriod,
+    #[error("Token program does not match the schedule")]
+    InvalidTokenProgram,
+    #[error("Schedule does not accept participants after its start")]
+    ScheduleAlreadyStarted,
+    #[error("Cliff has not been reached yet")]
+    CliffNotReached,
+    #[error("Claimed amount can not decrease")]
+    ClaimedAmountDecrease,
+    #[error("Vault does not match the schedule's vault")]
+    VaultMismatch,
+    #[error("Participant vesting has been revoked")]
+    VestingRevoked,
+    #[error("Participant still has unvested tokens")]
+    VestingNotComplete,
+    #[error("Claims are disabled program-wide")]
+    ClaimsDisabled,
+    #[error("Vault still holds tokens")]
+    VaultNotEmpty,
+    #[error("Total allocation overflows")]
+    AllocationOverflow,
+    #[error("Schedule is paused")]
+    SchedulePaused,
+    #[error("Schedule is not paused")]
+    ScheduleNotPaused,
+    #[error("Token account mint does not match the schedule mint")]
+    MintMismatch,
+    #[error("Mint uses a Token-2022 extension the program does not support")]
+    UnsupportedMintExtension,
+    #[error("Allocations would exceed the tokens held by the vault")]
+    OverAllocated,
+    #[error("Participant state belongs to a different schedule")]
+    WrongSchedule,
+    #[error("Authority token account does not cover the deposit")]
+    InsufficientDeposit,
+    #[error("Config is not the program's config account")]
+    ConfigMismatch,
+    #[error("Participant state does not belong to the participant")]
+    ParticipantMismatch,
+    #[error("Destination token account is frozen")]
+    DestinationFrozen,
+    #[error("Schedule has reached its participant cap")]
+    ParticipantCapReached,
+    #[error("Vault holds nothing beyond the outstanding allocations")]
+    NoSurplus,
+    #[error("Claims on this schedule are frozen")]
+    ClaimsFrozen,
+    #[error("Claims on this schedule are not frozen")]
+    ClaimsNotFrozen,
+    #[error("Cluster clock reports a negative timestamp")]
+    InvalidClock,
+    #[error("Schedule does not allow claims into escrow token accounts")]
+    EscrowNotAllowed,
+    #[error("Participant claimed too recently; the schedule's claim cooldown has not elapsed")]
+    ClaimCooldown,
+    #[error("Vesting has completed and the allocation is fully claimed")]
+    FullyClaimed,
+    #[error("Vested tokens left the claim window before they were claimed")]
+    ClaimWindowExpired,
 }
 
 impl From<PinocchioError> for ProgramError {
@@ -0,0 +1,85 @@
+use pinocchio::program_error::ProgramError;
+
+/// Counts how many times a schedule seed has been initialized, stored in the
+/// `["vest_gen", seed]` PDA. It outlives `CloseSchedule`, so indexers can tell a reused
+/// seed's generations apart.
+#[repr(C, packed)]
+pub struct VestGeneration {
+    pub discriminator: u8,
+    pub version: u8,
+    pub seed: u64,
+    pub generation: u32,
+    pub bump: u8,
+}
+
+use crate::{Discriminator, PinocchioError, ACCOUNT_VERSION};
+
+impl Discriminator for VestGeneration {
+    const LEN: usize = Self::LEN;
+    const DISCRIMINATOR: u8 = Self::DISCRIMINATOR;
+}
+
+impl VestGeneration {
+    pub const LEN: usize = size_of::<u64>() + size_of::<u32>() + size_of::<u8>() * 3;
+    pub const DISCRIMINATOR: u8 = 3;
+
+    #[inline(always)]
+    pub fn load_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        if bytes.len() != VestGeneration::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Self::check_version(bytes[1])?;
+        Ok(unsafe { &mut *core::mem::transmute::<*mut u8, *mut Self>(bytes.as_mut_ptr()) })
+    }
+
+    #[inline(always)]
+    pub fn load(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() != VestGeneration::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Self::check_version(bytes[1])?;
+
+        Ok(unsafe { &*core::mem::transmute::<*const u8, *const Self>(bytes.as_ptr()) })
+    }
+
+    /// Version 0 is a freshly allocated, not yet written account.
+    #[inline(always)]
+    fn check_version(version: u8) -> Result<(), ProgramError> {
+        match version {
+            0..=ACCOUNT_VERSION => Ok(()),
+            _ => Err(PinocchioError::UnsupportedVersion.into()),
+        }
+    }
+
+    #[inline(always)]
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// 1 for the first schedule created with this seed.
+    #[inline(always)]
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+
+    #[inline(always)]
+    pub fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    pub fn increment(&mut self) -> Result<(), ProgramError> {
+        self.generation = self
+            .generation
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    pub fn set_inner(&mut self, seed: u64, bump: u8) {
+        self.discriminator = VestGeneration::DISCRIMINATOR;
+        self.version = ACCOUNT_VERSION;
+        self.seed = seed;
+        self.generation = 1;
+        self.bump = bump;
+    }
+}
@@ -0,0 +1,264 @@
+//! Off-chain construction of the program's instructions, for integrators and tests. Every
+//! builder returns a `solana_sdk` instruction with its accounts in the order the on-chain
+//! `TryFrom` destructures them. Only compiled with the `client` feature, so the program
+//! itself doesn't carry `solana-sdk`.
+//!
+//! Optional trailing accounts (co-signers, fee recipients, vault authorities, registries)
+//! are left to the caller to push onto `Instruction::accounts`.
+
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+use crate::{
+    AddParticipant, Claim, Initialize, InitializeInstructionData, VestSchedule, CONFIG_SEED,
+    VAULT_AUTHORITY_SEED, VEST_GENERATION_SEED, VEST_PARTICIPANT_SEED, VEST_SCHEDULE_SEED,
+};
+
+pub fn program_id() -> Pubkey {
+    Pubkey::new_from_array(crate::ID)
+}
+
+fn system_program_id() -> Pubkey {
+    Pubkey::new_from_array(pinocchio_system::ID)
+}
+
+fn ata_program_id() -> Pubkey {
+    Pubkey::new_from_array(pinocchio_associated_token_account::ID)
+}
+
+/// `["vest_schedule", seed]`
+pub fn vest_schedule_pda(seed: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VEST_SCHEDULE_SEED, &seed.to_le_bytes()], &program_id())
+}
+
+/// `["vest_participant", participant, schedule]`
+pub fn vest_participant_pda(participant: &Pubkey, schedule: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            VEST_PARTICIPANT_SEED,
+            participant.as_ref(),
+            schedule.as_ref(),
+        ],
+        &program_id(),
+    )
+}
+
+/// `["vault", schedule]`
+pub fn vault_authority_pda(schedule: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_AUTHORITY_SEED, schedule.as_ref()], &program_id())
+}
+
+/// `["vest_gen", seed]`
+pub fn generation_pda(seed: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VEST_GENERATION_SEED, &seed.to_le_bytes()], &program_id())
+}
+
+/// `["config"]`
+pub fn config_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CONFIG_SEED], &program_id())
+}
+
+/// ATA of `owner` for `token_mint` under `token_program` (SPL Token or Token-2022).
+pub fn associated_token_address(
+    owner: &Pubkey,
+    token_mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[owner.as_ref(), token_program.as_ref(), token_mint.as_ref()],
+        &ata_program_id(),
+    )
+    .0
+}
+
+/// The vault `Initialize` creates: the ATA of the vault authority PDA when one is used,
+/// else of the schedule.
+pub fn vault_address(
+    schedule: &Pubkey,
+    token_mint: &Pubkey,
+    token_program: &Pubkey,
+    use_vault_authority: bool,
+) -> Pubkey {
+    let vault_owner = if use_vault_authority {
+        vault_authority_pda(schedule).0
+    } else {
+        *schedule
+    };
+
+    associated_token_address(&vault_owner, token_mint, token_program)
+}
+
+impl InitializeInstructionData {
+    /// Stepped schedule with every optional setting off and the canonical schedule bump.
+    pub fn new(
+        seed: u64,
+        start_timestamp: u64,
+        cliff_duration: u64,
+        total_duration: u64,
+        step_duration: u64,
+    ) -> Self {
+        Self {
+            seed,
+            start_timestamp,
+            cliff_duration,
+            total_duration,
+            step_duration,
+            bump: vest_schedule_pda(seed).1,
+            cosigner: [0; 32],
+            cosign_threshold: 0,
+            claim_window: 0,
+            use_vault_authority: false,
+            vault_authority_bump: 0,
+            fee_recipient: [0; 32],
+            claim_fee_bps: 0,
+            allow_add_after_start: true,
+            vesting_mode: VestSchedule::VESTING_MODE_STEPPED,
+            cliff_unlock_bps: 0,
+            revoke_destination: [0; 32],
+            pause_freezes_accrual: false,
+            max_participants: 0,
+            min_claim_amount: 0,
+            allow_escrow_destination: false,
+            label: [0; VestSchedule::LABEL_LEN],
+            claim_cooldown: 0,
+            cooldown_exempts_participant: false,
+        }
+    }
+
+    /// Serializes the fields in the order `parse` reads them, without the discriminator.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(size_of::<Self>());
+        data.extend_from_slice(&self.seed.to_le_bytes());
+        data.extend_from_slice(&self.start_timestamp.to_le_bytes());
+        data.extend_from_slice(&self.cliff_duration.to_le_bytes());
+        data.extend_from_slice(&self.total_duration.to_le_bytes());
+        data.extend_from_slice(&self.step_duration.to_le_bytes());
+        data.push(self.bump);
+        data.extend_from_slice(&self.cosigner);
+        data.extend_from_slice(&self.cosign_threshold.to_le_bytes());
+        data.extend_from_slice(&self.claim_window.to_le_bytes());
+        data.push(self.use_vault_authority as u8);
+        data.push(self.vault_authority_bump);
+        data.extend_from_slice(&self.fee_recipient);
+        data.extend_from_slice(&self.claim_fee_bps.to_le_bytes());
+        data.push(self.allow_add_after_start as u8);
+        data.push(self.vesting_mode);
+        data.extend_from_slice(&self.cliff_unlock_bps.to_le_bytes());
+        data.extend_from_slice(&self.revoke_destination);
+        data.push(self.pause_freezes_accrual as u8);
+        data.extend_from_slice(&self.max_participants.to_le_bytes());
+        data.extend_from_slice(&self.min_claim_amount.to_le_bytes());
+        data.push(self.allow_escrow_destination as u8);
+        data.extend_from_slice(&self.label);
+        data.extend_from_slice(&self.claim_cooldown.to_le_bytes());
+        data.push(self.cooldown_exempts_participant as u8);
+        data
+    }
+}
+
+/// `Initialize` for the schedule derived from `data.seed`, paid by `initializer`. Appends
+/// the vault authority PDA when `data.use_vault_authority` is set; its bump must be in
+/// `data.vault_authority_bump`.
+pub fn initialize_ix(
+    initializer: &Pubkey,
+    token_mint: &Pubkey,
+    token_program: &Pubkey,
+    data: &InitializeInstructionData,
+) -> Instruction {
+    let seed = data.seed;
+    let use_vault_authority = data.use_vault_authority;
+    let (schedule, _) = vest_schedule_pda(seed);
+    let vault = vault_address(&schedule, token_mint, token_program, use_vault_authority);
+
+    let mut accounts = vec![
+        AccountMeta::new(*initializer, true),
+        AccountMeta::new(schedule, false),
+        AccountMeta::new_readonly(*token_mint, false),
+        AccountMeta::new(vault, false),
+        AccountMeta::new_readonly(system_program_id(), false),
+        AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new_readonly(ata_program_id(), false),
+        AccountMeta::new(generation_pda(seed).0, false),
+    ];
+
+    if use_vault_authority {
+        accounts.push(AccountMeta::new_readonly(
+            vault_authority_pda(&schedule).0,
+            false,
+        ));
+    }
+
+    let mut instruction_data = vec![*Initialize::DISCRIMINATOR];
+    instruction_data.extend_from_slice(&data.to_bytes());
+
+    Instruction {
+        program_id: program_id(),
+        accounts,
+        data: instruction_data,
+    }
+}
+
+/// `AddParticipant` funding `allocated_amount` from the authority's ATA into `vault`, the
+/// vault recorded on the schedule.
+pub fn add_participant_ix(
+    authority: &Pubkey,
+    schedule: &Pubkey,
+    vault: &Pubkey,
+    token_mint: &Pubkey,
+    token_program: &Pubkey,
+    participant: &Pubkey,
+    allocated_amount: u64,
+) -> Instruction {
+    let mut data = vec![*AddParticipant::DISCRIMINATOR];
+    data.extend_from_slice(&allocated_amount.to_le_bytes());
+
+    Instruction {
+        program_id: program_id(),
+        accounts: vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(
+                associated_token_address(authority, token_mint, token_program),
+                false,
+            ),
+            AccountMeta::new(*vault, false),
+            AccountMeta::new_readonly(*participant, false),
+            AccountMeta::new(vest_participant_pda(participant, schedule).0, false),
+            AccountMeta::new(*schedule, false),
+            AccountMeta::new_readonly(*token_mint, false),
+            AccountMeta::new_readonly(system_program_id(), false),
+            AccountMeta::new_readonly(*token_program, false),
+        ],
+        data,
+    }
+}
+
+/// `Claim` signed by the participant into their ATA, which the claim creates if missing.
+pub fn claim_ix(
+    participant: &Pubkey,
+    schedule: &Pubkey,
+    vault: &Pubkey,
+    token_mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: program_id(),
+        accounts: vec![
+            AccountMeta::new(*participant, true),
+            AccountMeta::new(vest_participant_pda(participant, schedule).0, false),
+            AccountMeta::new(
+                associated_token_address(participant, token_mint, token_program),
+                false,
+            ),
+            AccountMeta::new(*schedule, false),
+            AccountMeta::new(*vault, false),
+            AccountMeta::new_readonly(*token_mint, false),
+            AccountMeta::new_readonly(system_program_id(), false),
+            AccountMeta::new_readonly(*token_program, false),
+            AccountMeta::new_readonly(ata_program_id(), false),
+            AccountMeta::new_readonly(config_pda().0, false),
+        ],
+        data: vec![*Claim::DISCRIMINATOR],
+    }
+}
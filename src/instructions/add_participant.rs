@@ -1,14 +1,9 @@
-use pinocchio::{
-    account_info::AccountInfo,
-    instruction::Seed,
-    program_error::ProgramError,
-    sysvars::{clock::Clock, Sysvar},
-};
-use pinocchio_token::{instructions::Transfer, state::TokenAccount};
+use pinocchio::{account_info::AccountInfo, instruction::Seed, program_error::ProgramError};
 
 use crate::{
-    participant_state, AssociatedToken, Mint, PinocchioError, ProgramAccount, SignerAccount,
-    VestParticipant, VestSchedule,
+    current_unix_timestamp, participant_state, ActivityLog, AssociatedToken, Mint, PinocchioError,
+    ProgramAccount, SignerAccount, Token, TokenTransfer, VestParticipant, VestSchedule,
+    VEST_PARTICIPANT_SEED,
 };
 
 pub struct AddParticipantAccounts<'a> {
@@ -53,22 +48,53 @@ impl<'a> TryFrom<&'a [AccountInfo]> for AddParticipantAccounts<'a> {
     }
 }
 
-#[repr(C, packed)]
+/// `[allocated_amount: u64]`, optionally followed by a legacy `participant_bump: u8` and
+/// then by `[cliff_override: u64, total_override: u64]` to put the participant on their own
+/// timeline. Parsed field by field rather than cast from the bytes, so it carries no
+/// `repr(C, packed)` and the optional bump can be an `Option`.
 pub struct AddParticipantInstructionData {
     pub allocated_amount: u64,
-    pub participant_bump: u8,
+    /// Only accepted for older clients; it must be the canonical bump, which is derived
+    /// on-chain either way.
+    pub participant_bump: Option<u8>,
+    /// Zero keeps the schedule's cliff duration.
+    pub cliff_override: u64,
+    /// Zero keeps the schedule's total duration.
+    pub total_override: u64,
+}
+
+impl AddParticipantInstructionData {
+    const BASE_LEN: usize = size_of::<u64>();
+    const BASE_LEN_WITH_BUMP: usize = Self::BASE_LEN + size_of::<u8>();
+    const LEN_WITH_OVERRIDES: usize = Self::BASE_LEN + size_of::<u64>() * 2;
+    const LEN_WITH_BUMP_AND_OVERRIDES: usize = Self::BASE_LEN_WITH_BUMP + size_of::<u64>() * 2;
+
+    pub fn has_overrides(&self) -> bool {
+        self.cliff_override != 0 || self.total_override != 0
+    }
 }
 
 impl TryFrom<&[u8]> for AddParticipantInstructionData {
     type Error = ProgramError;
 
     fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
-        if data.len() != size_of::<u64>() + size_of::<u8>() {
-            return Err(ProgramError::InvalidInstructionData);
-        }
+        let (participant_bump, overrides) = match data.len() {
+            Self::BASE_LEN => (None, None),
+            Self::BASE_LEN_WITH_BUMP => (Some(data[8]), None),
+            Self::LEN_WITH_OVERRIDES => (None, Some(&data[8..24])),
+            Self::LEN_WITH_BUMP_AND_OVERRIDES => (Some(data[8]), Some(&data[9..25])),
+            _ => return Err(ProgramError::InvalidInstructionData),
+        };
+
+        let (cliff_override, total_override) = match overrides {
+            Some(overrides) => (
+                u64::from_le_bytes(overrides[0..8].try_into().unwrap()),
+                u64::from_le_bytes(overrides[8..16].try_into().unwrap()),
+            ),
+            None => (0, 0),
+        };
 
         let allocated_amount = u64::from_le_bytes(data[0..8].try_into().unwrap());
-        let participant_bump = u8::from_le_bytes(data[8..9].try_into().unwrap());
 
         if allocated_amount == 0 {
             return Err(ProgramError::InvalidInstructionData);
@@ -77,6 +103,8 @@ impl TryFrom<&[u8]> for AddParticipantInstructionData {
         Ok(Self {
             allocated_amount,
             participant_bump,
+            cliff_override,
+            total_override,
         })
     }
 }
@@ -84,6 +112,8 @@ impl TryFrom<&[u8]> for AddParticipantInstructionData {
 pub struct AddParticipant<'a> {
     pub accounts: AddParticipantAccounts<'a>,
     pub instruction_data: AddParticipantInstructionData,
+    /// Canonical bump of the participant state PDA, derived on-chain.
+    pub participant_bump: u8,
 }
 
 impl<'a> TryFrom<(&[u8], &'a [AccountInfo])> for AddParticipant<'a> {
@@ -93,55 +123,64 @@ impl<'a> TryFrom<(&[u8], &'a [AccountInfo])> for AddParticipant<'a> {
         let accounts = AddParticipantAccounts::try_from(accounts)?;
         let instruction_data = AddParticipantInstructionData::try_from(data)?;
 
-        let vest_schedule_data = accounts.schedule.try_borrow_data()?;
-        let vest_schedule = VestSchedule::load(&vest_schedule_data)?;
-
-        let current_timestamp = Clock::get()?.unix_timestamp as u64;
-
-        if vest_schedule.is_cliff_completed(current_timestamp) {
-            return Err(PinocchioError::CannotAddParticipantsAfterCliff.into());
-        }
-
-        if accounts.authority.key() != vest_schedule.authority() {
-            return Err(ProgramError::IllegalOwner);
-        }
-
-        if *accounts.token_mint.key() != *vest_schedule.token_mint() {
-            return Err(ProgramError::InvalidAccountData);
-        }
+        AddParticipant::check_schedule(
+            accounts.authority,
+            accounts.authority_ata,
+            accounts.vault,
+            accounts.schedule,
+            accounts.token_mint,
+            accounts.token_program,
+        )?;
 
-        ProgramAccount::verify(
+        // Checking the PDA derives the canonical bump anyway, so taking it from the caller
+        // would save no compute. The state is only written if it is this participant's, so
+        // it can never name accounts other than the ones it was derived from.
+        let participant_bump = ProgramAccount::find_bump(
             &[
-                Seed::from(b"vest_participant"),
+                Seed::from(VEST_PARTICIPANT_SEED),
                 Seed::from(accounts.participant.key().as_ref()),
                 Seed::from(accounts.schedule.key().as_ref()),
             ],
             accounts.participant_state,
-            instruction_data.participant_bump,
-        )?;
-
-        AssociatedToken::check(
-            accounts.authority_ata,
-            *accounts.authority.key(),
-            *accounts.token_mint.key(),
-            *accounts.token_program.key(),
-        )?;
+        )
+        .map_err(|_| PinocchioError::ParticipantMismatch)?;
+
+        if instruction_data
+            .participant_bump
+            .is_some_and(|bump| bump != participant_bump)
+        {
+            return Err(ProgramError::InvalidSeeds);
+        }
 
-        let authority_ata = TokenAccount::from_account_info(accounts.authority_ata)?;
-        if authority_ata.amount() < instruction_data.allocated_amount {
-            return Err(ProgramError::InsufficientFunds);
+        if Token::amount(accounts.authority_ata)? < instruction_data.allocated_amount {
+            return Err(PinocchioError::InsufficientDeposit.into());
         }
 
-        AssociatedToken::check(
+        AddParticipant::check_not_over_allocated(
+            accounts.schedule,
             accounts.vault,
-            *accounts.schedule.key(),
-            *accounts.token_mint.key(),
-            *accounts.token_program.key(),
+            instruction_data.allocated_amount,
         )?;
 
+        // The participant's own timeline must be one the vesting math can handle, and end
+        // where the clock can reach even if `UpdateStart` later moves the start
+        if instruction_data.has_overrides() {
+            let vest_schedule_data = accounts.schedule.try_borrow_data()?;
+            let terms = VestSchedule::load(&vest_schedule_data)?.with_duration_overrides(
+                instruction_data.cliff_override,
+                instruction_data.total_override,
+            );
+            terms.validate()?;
+
+            if !terms.ends_within_clock() {
+                return Err(PinocchioError::InvalidDurations.into());
+            }
+        }
+
         Ok(Self {
             accounts,
             instruction_data,
+            participant_bump,
         })
     }
 }
@@ -149,10 +188,92 @@ impl<'a> TryFrom<(&[u8], &'a [AccountInfo])> for AddParticipant<'a> {
 impl<'a> AddParticipant<'a> {
     pub const DISCRIMINATOR: &'a u8 = &1;
 
+    /// Checks that `authority` may still add participants to `schedule` and that the
+    /// token accounts match it. Shared with `BatchAddParticipant`.
+    pub fn check_schedule(
+        authority: &AccountInfo,
+        authority_ata: &AccountInfo,
+        vault: &AccountInfo,
+        schedule: &AccountInfo,
+        token_mint: &AccountInfo,
+        token_program: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        let vest_schedule_data = schedule.try_borrow_data()?;
+        let vest_schedule = VestSchedule::load(&vest_schedule_data)?;
+
+        let current_timestamp = current_unix_timestamp()?;
+
+        if vest_schedule.is_cliff_completed(current_timestamp) {
+            return Err(PinocchioError::CannotAddParticipantsAfterCliff.into());
+        }
+
+        if !vest_schedule.allow_add_after_start() && vest_schedule.has_started(current_timestamp) {
+            return Err(PinocchioError::ScheduleAlreadyStarted.into());
+        }
+
+        if authority.key() != vest_schedule.authority() {
+            return Err(ProgramError::IllegalOwner);
+        }
+
+        if *token_mint.key() != *vest_schedule.token_mint() {
+            return Err(PinocchioError::MintMismatch.into());
+        }
+
+        if token_program.key() != vest_schedule.token_program() {
+            return Err(PinocchioError::InvalidTokenProgram.into());
+        }
+
+        // Schedules created before transfer-fee mints were rejected must not take deposits
+        Mint::check_supported_extensions(token_mint)?;
+
+        AssociatedToken::check(
+            authority_ata,
+            *authority.key(),
+            *token_mint.key(),
+            *token_program.key(),
+        )?;
+
+        if vault.key() != vest_schedule.vault() {
+            return Err(PinocchioError::VaultMismatch.into());
+        }
+
+        AssociatedToken::check(
+            vault,
+            *vest_schedule.vault_owner(schedule.key()),
+            *token_mint.key(),
+            *token_program.key(),
+        )
+    }
+
+    /// Checks that the vault, once `new_allocation` is deposited, still covers every
+    /// allocation on the schedule not yet paid out of it. Top-ups and late additions can land
+    /// after claims, so the vault is compared with `total_allocated - total_claimed`.
+    pub fn check_not_over_allocated(
+        schedule: &AccountInfo,
+        vault: &AccountInfo,
+        new_allocation: u64,
+    ) -> Result<(), ProgramError> {
+        let outstanding = {
+            let vest_schedule_data = schedule.try_borrow_data()?;
+            VestSchedule::load(&vest_schedule_data)?.outstanding()
+        };
+
+        let committed = outstanding
+            .checked_add(new_allocation)
+            .ok_or(PinocchioError::AllocationOverflow)?;
+        let funded = Token::amount(vault)?.saturating_add(new_allocation);
+
+        if committed > funded {
+            return Err(PinocchioError::OverAllocated.into());
+        }
+
+        Ok(())
+    }
+
     pub fn process(&self) -> Result<(), ProgramError> {
-        let bump_binding = [self.instruction_data.participant_bump];
+        let bump_binding = [self.participant_bump];
         let participant_seeds = [
-            Seed::from(b"vest_participant"),
+            Seed::from(VEST_PARTICIPANT_SEED),
             Seed::from(self.accounts.participant.key().as_ref()),
             Seed::from(self.accounts.schedule.key().as_ref()),
             Seed::from(&bump_binding),
@@ -174,16 +295,32 @@ impl<'a> AddParticipant<'a> {
             *self.accounts.schedule.key(),
             self.instruction_data.allocated_amount,
             0,
-            self.instruction_data.participant_bump,
+            self.participant_bump,
         );
+        participant_state.set_duration_overrides(
+            self.instruction_data.cliff_override,
+            self.instruction_data.total_override,
+        );
+
+        let mut vest_schedule_data = self.accounts.schedule.try_borrow_mut_data()?;
+        let vest_schedule = VestSchedule::load_mut(&mut vest_schedule_data)?;
+        vest_schedule.add_participants(1)?;
+        vest_schedule.add_allocation(self.instruction_data.allocated_amount)?;
 
-        Transfer {
+        TokenTransfer {
             from: self.accounts.authority_ata,
             to: self.accounts.vault,
             authority: self.accounts.authority,
+            token_program: self.accounts.token_program,
             amount: self.instruction_data.allocated_amount,
         }
         .invoke()?;
+
+        ActivityLog::add(
+            self.accounts.participant.key(),
+            self.instruction_data.allocated_amount,
+        );
+
         Ok(())
     }
 }
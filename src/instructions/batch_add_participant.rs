@@ -0,0 +1,234 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, pubkey::Pubkey,
+};
+
+use crate::{
+    ActivityLog, AddParticipant, Mint, PinocchioError, ProgramAccount, SignerAccount, Token,
+    TokenTransfer, Vault, VestParticipant, VestSchedule, VEST_PARTICIPANT_SEED,
+};
+
+pub struct BatchAddParticipantAccounts<'a> {
+    pub authority: &'a AccountInfo,
+    pub authority_ata: &'a AccountInfo,
+    pub vault: &'a AccountInfo,
+    pub schedule: &'a AccountInfo,
+    pub token_mint: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub token_program: &'a AccountInfo,
+    /// One participant state per entry, in instruction data order.
+    pub participant_states: &'a [AccountInfo],
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for BatchAddParticipantAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, authority_ata, vault, schedule, token_mint, system_program, token_program, participant_states @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(authority)?;
+        ProgramAccount::check_system_program(system_program)?;
+        ProgramAccount::check_token_program(token_program)?;
+        ProgramAccount::check::<VestSchedule>(schedule)?;
+        Mint::check(token_mint)?;
+
+        Ok(Self {
+            authority,
+            authority_ata,
+            vault,
+            schedule,
+            token_mint,
+            system_program,
+            token_program,
+            participant_states,
+        })
+    }
+}
+
+pub struct BatchAddParticipantEntry {
+    pub participant: Pubkey,
+    pub allocated_amount: u64,
+    pub participant_bump: u8,
+}
+
+impl BatchAddParticipantEntry {
+    pub const LEN: usize = size_of::<Pubkey>() + size_of::<u64>() + size_of::<u8>();
+}
+
+pub struct BatchAddParticipantInstructionData {
+    pub entries: Vec<BatchAddParticipantEntry>,
+}
+
+impl TryFrom<&[u8]> for BatchAddParticipantInstructionData {
+    type Error = ProgramError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        if data.is_empty() || !data.len().is_multiple_of(BatchAddParticipantEntry::LEN) {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        if data.len() / BatchAddParticipantEntry::LEN > BatchAddParticipant::MAX_BATCH_SIZE {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let entries = data
+            .chunks_exact(BatchAddParticipantEntry::LEN)
+            .map(|entry| {
+                let allocated_amount = u64::from_le_bytes(entry[32..40].try_into().unwrap());
+
+                if allocated_amount == 0 {
+                    return Err(ProgramError::InvalidInstructionData);
+                }
+
+                Ok(BatchAddParticipantEntry {
+                    participant: entry[0..32].try_into().unwrap(),
+                    allocated_amount,
+                    participant_bump: entry[40],
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { entries })
+    }
+}
+
+impl BatchAddParticipantInstructionData {
+    pub fn total_allocated_amount(&self) -> Result<u64, ProgramError> {
+        let amounts: Vec<u64> = self
+            .entries
+            .iter()
+            .map(|entry| entry.allocated_amount)
+            .collect();
+
+        Vault::required_amount(&amounts)
+    }
+}
+
+/// `AddParticipant` for several participants at once. Instruction data is a list of
+/// `(participant, allocated_amount, bump)` entries; all allocations are funded by a single
+/// transfer of their sum.
+pub struct BatchAddParticipant<'a> {
+    pub accounts: BatchAddParticipantAccounts<'a>,
+    pub instruction_data: BatchAddParticipantInstructionData,
+}
+
+impl<'a> TryFrom<(&[u8], &'a [AccountInfo])> for BatchAddParticipant<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&[u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = BatchAddParticipantAccounts::try_from(accounts)?;
+        let instruction_data = BatchAddParticipantInstructionData::try_from(data)?;
+
+        if accounts.participant_states.len() != instruction_data.entries.len() {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        AddParticipant::check_schedule(
+            accounts.authority,
+            accounts.authority_ata,
+            accounts.vault,
+            accounts.schedule,
+            accounts.token_mint,
+            accounts.token_program,
+        )?;
+
+        for (entry, participant_state) in instruction_data
+            .entries
+            .iter()
+            .zip(accounts.participant_states)
+        {
+            ProgramAccount::verify(
+                &[
+                    Seed::from(VEST_PARTICIPANT_SEED),
+                    Seed::from(entry.participant.as_ref()),
+                    Seed::from(accounts.schedule.key().as_ref()),
+                ],
+                participant_state,
+                entry.participant_bump,
+            )?;
+        }
+
+        if Token::amount(accounts.authority_ata)? < instruction_data.total_allocated_amount()? {
+            return Err(PinocchioError::InsufficientDeposit.into());
+        }
+
+        AddParticipant::check_not_over_allocated(
+            accounts.schedule,
+            accounts.vault,
+            instruction_data.total_allocated_amount()?,
+        )?;
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+        })
+    }
+}
+
+impl<'a> BatchAddParticipant<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &11;
+    /// Keeps a full batch within the transaction size and compute limits.
+    pub const MAX_BATCH_SIZE: usize = 10;
+
+    pub fn process(&self) -> Result<(), ProgramError> {
+        for (entry, participant_state) in self
+            .instruction_data
+            .entries
+            .iter()
+            .zip(self.accounts.participant_states)
+        {
+            let bump_binding = [entry.participant_bump];
+            let participant_seeds = [
+                Seed::from(VEST_PARTICIPANT_SEED),
+                Seed::from(entry.participant.as_ref()),
+                Seed::from(self.accounts.schedule.key().as_ref()),
+                Seed::from(&bump_binding),
+            ];
+
+            ProgramAccount::init::<VestParticipant>(
+                self.accounts.authority,
+                participant_state,
+                &participant_seeds,
+                VestParticipant::LEN,
+            )?;
+
+            let mut participant_state_data = participant_state.try_borrow_mut_data()?;
+            let participant_state = VestParticipant::load_mut(&mut participant_state_data)?;
+
+            participant_state.set_inner(
+                entry.participant,
+                *self.accounts.schedule.key(),
+                entry.allocated_amount,
+                0,
+                entry.participant_bump,
+            );
+        }
+
+        let total_allocated_amount = self.instruction_data.total_allocated_amount()?;
+
+        {
+            let mut vest_schedule_data = self.accounts.schedule.try_borrow_mut_data()?;
+            let vest_schedule = VestSchedule::load_mut(&mut vest_schedule_data)?;
+            vest_schedule.add_participants(self.instruction_data.entries.len() as u64)?;
+            vest_schedule.add_allocation(total_allocated_amount)?;
+        }
+
+        TokenTransfer {
+            from: self.accounts.authority_ata,
+            to: self.accounts.vault,
+            authority: self.accounts.authority,
+            token_program: self.accounts.token_program,
+            amount: total_allocated_amount,
+        }
+        .invoke()?;
+
+        for entry in &self.instruction_data.entries {
+            ActivityLog::add(&entry.participant, entry.allocated_amount);
+        }
+
+        Ok(())
+    }
+}
@@ -1,14 +1,12 @@
 use pinocchio::{
-    account_info::AccountInfo,
-    instruction::{Seed, Signer},
-    program_error::ProgramError,
-    sysvars::{clock::Clock, Sysvar},
+    account_info::AccountInfo, cpi::set_return_data, log::sol_log_data,
+    program_error::ProgramError, pubkey::find_program_address,
 };
-use pinocchio_token::{instructions::Transfer, state::TokenAccount};
 
 use crate::{
-    AssociatedToken, Mint, PinocchioError, ProgramAccount, SignerAccount, VestParticipant,
-    VestSchedule,
+    current_unix_timestamp, AssociatedToken, Claimable, Config, Mint, PinocchioError,
+    ProgramAccount, SignerAccount, Token, Vault, VaultSigner, VestParticipant, VestSchedule,
+    CONFIG_SEED, VEST_PARTICIPANT_SEED,
 };
 
 pub struct ClaimAccounts<'a> {
@@ -21,19 +19,31 @@ pub struct ClaimAccounts<'a> {
     pub system_program: &'a AccountInfo,
     pub token_program: &'a AccountInfo,
     pub ata_program: &'a AccountInfo,
+    pub config: &'a AccountInfo,
+    pub remaining: &'a [AccountInfo],
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for ClaimAccounts<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [participant, participant_state, participant_ata, vest_schedule, vault, token_mint, system_program, token_program, ata_program] =
+        let accounts = Self::try_from_unsigned(accounts)?;
+
+        SignerAccount::check(accounts.participant)?;
+
+        Ok(accounts)
+    }
+}
+
+impl<'a> ClaimAccounts<'a> {
+    /// Like `try_from`, but the participant doesn't have to sign (crank claims).
+    pub fn try_from_unsigned(accounts: &'a [AccountInfo]) -> Result<Self, ProgramError> {
+        let [participant, participant_state, participant_ata, vest_schedule, vault, token_mint, system_program, token_program, ata_program, config, remaining @ ..] =
             accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
-        SignerAccount::check(participant)?;
         ProgramAccount::check_token_program(token_program)?;
         ProgramAccount::check_system_program(system_program)?;
         ProgramAccount::check_ata_program(ata_program)?;
@@ -51,136 +61,370 @@ impl<'a> TryFrom<&'a [AccountInfo]> for ClaimAccounts<'a> {
             system_program,
             token_program,
             ata_program,
+            config,
+            remaining,
         })
     }
 }
 
 pub struct Claim<'a> {
     pub accounts: ClaimAccounts<'a>,
+    /// Copied out by `check`, so `process` doesn't borrow and load the schedule again.
+    pub vest_schedule: VestSchedule,
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for Claim<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let accounts = ClaimAccounts::try_from(accounts)?;
+        let accounts = ClaimAccounts::try_from_unsigned(accounts)?;
+
+        let vest_schedule = Self::check(&accounts)?;
+        let claimant = Self::claimant(&accounts)?;
+
+        // The token program was checked against the schedule, so the participant ATA is
+        // derived with the schedule's program id (it differs for Token-2022)
+        AssociatedToken::init_if_needed(
+            accounts.participant_ata,
+            accounts.token_mint,
+            claimant,
+            accounts.participant,
+            accounts.system_program,
+            accounts.token_program,
+        )?;
 
-        {
-            let vest_schedule_data = accounts.vest_schedule.try_borrow_data()?;
-            let vest_schedule = VestSchedule::load(&vest_schedule_data)?;
+        Ok(Self {
+            accounts,
+            vest_schedule,
+        })
+    }
+}
+
+impl<'a> Claim<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &2;
+    /// Upper bound on step events logged by a single claim, so catching up on a long
+    /// schedule can't exhaust the compute budget.
+    pub const MAX_STEP_EVENTS: usize = 16;
+
+    /// The signer claiming: the participant, or else their claim delegate, which signs among
+    /// the remaining accounts and pays for a missing ATA in the participant's place.
+    pub fn claimant(accounts: &ClaimAccounts<'a>) -> Result<&'a AccountInfo, ProgramError> {
+        if accounts.participant.is_signer() {
+            return Ok(accounts.participant);
+        }
 
-            let participant_state_data = accounts.participant_state.try_borrow_data()?;
-            let participant_state = VestParticipant::load(&participant_state_data)?;
+        let participant_state_data = accounts.participant_state.try_borrow_data()?;
+        let participant_state = VestParticipant::load(&participant_state_data)?;
 
-            if accounts.token_mint.key() != vest_schedule.token_mint() {
-                return Err(ProgramError::InvalidAccountData);
+        if !participant_state.has_claim_delegate() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        accounts
+            .remaining
+            .iter()
+            .find(|account| {
+                account.key() == participant_state.claim_delegate() && account.is_signer()
+            })
+            .ok_or(ProgramError::MissingRequiredSignature)
+    }
+
+    /// Everything a claim checks except the participant's signature and ATA, which differ
+    /// between `Claim` and `CrankClaim`. Returns the loaded schedule for `process`.
+    pub fn check(accounts: &ClaimAccounts) -> Result<VestSchedule, ProgramError> {
+        // The config must be the program's own PDA so the kill switch can't be dodged. Once
+        // it exists its stored bump makes that a single hash; a config that was never
+        // initialized leaves claims enabled.
+        if ProgramAccount::is_initialized::<Config>(accounts.config)? {
+            let config_data = accounts.config.try_borrow_data()?;
+            let config = Config::load(&config_data)?;
+
+            ProgramAccount::verify_address(&[CONFIG_SEED, &[config.bump()]], accounts.config)
+                .map_err(|_| PinocchioError::ConfigMismatch)?;
+
+            if !config.claims_enabled() {
+                return Err(PinocchioError::ClaimsDisabled.into());
+            }
+        } else {
+            let (config_address, _) = find_program_address(&[CONFIG_SEED], &crate::ID);
+            if accounts.config.key() != &config_address {
+                return Err(PinocchioError::ConfigMismatch.into());
             }
+        }
 
-            AssociatedToken::check(
-                accounts.vault,
-                *accounts.vest_schedule.key(),
-                *vest_schedule.token_mint(),
-                *accounts.token_program.key(),
-            )?;
+        let vest_schedule_data = accounts.vest_schedule.try_borrow_data()?;
+        let vest_schedule = VestSchedule::load(&vest_schedule_data)?;
+        vest_schedule.validate()?;
 
-            AssociatedToken::init_if_needed(
-                accounts.participant_ata,
-                accounts.token_mint,
-                accounts.participant,
-                accounts.participant,
-                accounts.system_program,
-                accounts.token_program,
-            )?;
+        // Unless the pause freezes accrual, it simply holds claims until it ends
+        if vest_schedule.is_paused() && !vest_schedule.pause_freezes_accrual() {
+            return Err(PinocchioError::SchedulePaused.into());
+        }
 
-            ProgramAccount::verify(
-                &[
-                    Seed::from(b"vest_participant"),
-                    Seed::from(accounts.participant.key().as_ref()),
-                    Seed::from(accounts.vest_schedule.key().as_ref()),
-                ],
-                accounts.participant_state,
-                participant_state.bump(),
-            )?;
+        if vest_schedule.claims_frozen() {
+            return Err(PinocchioError::ClaimsFrozen.into());
+        }
+
+        let participant_state_data = accounts.participant_state.try_borrow_data()?;
+        let participant_state = VestParticipant::load(&participant_state_data)?;
+
+        // Checked before the schedule's accounts so a mixed-up schedule is reported as such
+        if participant_state.schedule() != accounts.vest_schedule.key() {
+            return Err(PinocchioError::WrongSchedule.into());
+        }
+
+        if accounts.token_mint.key() != vest_schedule.token_mint() {
+            return Err(PinocchioError::MintMismatch.into());
+        }
 
-            if participant_state.participant() != accounts.participant.key() {
-                return Err(ProgramError::IllegalOwner);
+        if accounts.token_program.key() != vest_schedule.token_program() {
+            return Err(PinocchioError::InvalidTokenProgram.into());
+        }
+
+        if accounts.vault.key() != vest_schedule.vault() {
+            return Err(PinocchioError::VaultMismatch.into());
+        }
+
+        AssociatedToken::check(
+            accounts.vault,
+            *vest_schedule.vault_owner(accounts.vest_schedule.key()),
+            *vest_schedule.token_mint(),
+            *vest_schedule.token_program(),
+        )?;
+
+        // An existing destination for another mint, or one the freeze authority froze,
+        // would otherwise only fail inside the token transfer
+        if Token::check(accounts.participant_ata).is_ok() {
+            if &Token::mint(accounts.participant_ata)? != accounts.token_mint.key() {
+                return Err(PinocchioError::MintMismatch.into());
             }
 
-            if participant_state.schedule() != accounts.vest_schedule.key() {
-                return Err(ProgramError::InvalidAccountData);
+            if Token::is_frozen(accounts.participant_ata)? {
+                return Err(PinocchioError::DestinationFrozen.into());
             }
         }
-        Ok(Self { accounts })
-    }
-}
 
-impl<'a> Claim<'a> {
-    pub const DISCRIMINATOR: &'a u8 = &2;
+        // The stored bump was checked to be canonical when the participant was added
+        ProgramAccount::verify_address(
+            &[
+                VEST_PARTICIPANT_SEED,
+                accounts.participant.key().as_ref(),
+                accounts.vest_schedule.key().as_ref(),
+                &[participant_state.bump()],
+            ],
+            accounts.participant_state,
+        )?;
+
+        if participant_state.participant() != accounts.participant.key() {
+            return Err(ProgramError::IllegalOwner);
+        }
 
-    pub fn process(&self) -> Result<(), ProgramError> {
-        let (claimable_amount, allocated_amount, schedule_seed, schedule_bump) = {
-            let vest_schedule_data = self.accounts.vest_schedule.try_borrow_data()?;
-            let vest_schedule = VestSchedule::load(&vest_schedule_data)?;
+        Ok(*vest_schedule)
+    }
 
-            let participant_state_data = self.accounts.participant_state.try_borrow_data()?;
-            let participant_state = VestParticipant::load(&participant_state_data)?;
+    pub fn process(&self) -> Result<(), ProgramError> {
+        let vest_schedule = &self.vest_schedule;
+
+        // The participant state is read and updated under a single borrow, released before
+        // the transfers
+        let (
+            claimable_amount,
+            fee_amount,
+            allocated_amount,
+            claimed_amount,
+            forfeited_amount,
+            new_claimed,
+            new_forfeited,
+            terms,
+            is_revoked,
+            next_unlock_timestamp,
+        ) = {
+            let mut participant_state_data =
+                self.accounts.participant_state.try_borrow_mut_data()?;
+            let participant_state = VestParticipant::load_mut(&mut participant_state_data)?;
+
+            // The participant may vest on their own cliff and duration
+            let terms = participant_state.terms(vest_schedule);
+
+            let current_timestamp = current_unix_timestamp()?;
+            let claimable = participant_state.claimable(vest_schedule, current_timestamp);
+            if claimable == Claimable::BeforeCliff {
+                return Err(PinocchioError::CliffNotReached.into());
+            }
 
-            let current_timestamp = Clock::get()?.unix_timestamp as u64;
-            let claimable_amount = vest_schedule.calculate_claimable_amount(
+            // Crank and delegate claims leave the participant unsigned, so they can never
+            // skip the cooldown
+            if vest_schedule.is_cooling_down(
+                participant_state.last_claim_ts(),
                 current_timestamp,
-                participant_state.allocated_amount(),
-                participant_state.claimed_amount(),
+                self.accounts.participant.is_signer(),
+            ) {
+                return Err(PinocchioError::ClaimCooldown.into());
+            }
+
+            // Distinct errors let wallets say "come back later" apart from "nothing left"
+            let claimable_amount = match claimable {
+                Claimable::Amount(amount) => amount,
+                // Nothing vests after a revoke, so once the frozen allocation is claimed say why
+                _ if participant_state.is_revoked() => {
+                    return Err(PinocchioError::VestingRevoked.into())
+                }
+                Claimable::Completed => return Err(PinocchioError::FullyClaimed.into()),
+                // Nothing transfers, so the forfeiture is left for `ForfeitExpired` to record
+                Claimable::Expired => return Err(PinocchioError::ClaimWindowExpired.into()),
+                Claimable::BeforeCliff | Claimable::NothingNewYet => {
+                    return Err(PinocchioError::NoClaimableAmount.into())
+                }
+            };
+
+            // Below the threshold the participant waits for more to accrue, unless nothing more
+            // will: completion and revocation always release the last dust
+            if claimable_amount < vest_schedule.min_claim_amount()
+                && !participant_state.is_revoked()
+                && !terms.is_completed(current_timestamp)
+            {
+                return Err(PinocchioError::NoClaimableAmount.into());
+            }
+
+            if vest_schedule.requires_cosign(claimable_amount) {
+                let cosigner = vest_schedule.cosigner();
+                if !self
+                    .accounts
+                    .remaining
+                    .iter()
+                    .any(|account| account.key() == cosigner && account.is_signer())
+                {
+                    return Err(PinocchioError::CosignerRequired.into());
+                }
+            }
+
+            Vault::check_funded(self.accounts.vault, &[claimable_amount])?;
+
+            let fee_amount = vest_schedule.claim_fee(claimable_amount);
+
+            let allocated_amount = participant_state.allocated_amount();
+            let claimed_amount = participant_state.claimed_amount();
+            let forfeited_amount = participant_state.forfeited_amount();
+            let new_claimed = claimed_amount.saturating_add(claimable_amount);
+
+            // Whatever left the claim window before being claimed is forfeited for good
+            let new_forfeited = participant_state.forfeited_at(vest_schedule, current_timestamp);
+
+            if new_claimed.saturating_add(new_forfeited) > allocated_amount {
+                return Err(PinocchioError::ClaimExceedsAllocation.into());
+            }
+
+            participant_state.set_claimed_amount(new_claimed);
+            participant_state.set_forfeited_amount(new_forfeited);
+            participant_state.set_last_claim_ts(current_timestamp);
+            participant_state.set_total_fees_paid(
+                participant_state
+                    .total_fees_paid()
+                    .saturating_add(fee_amount),
             );
 
             (
                 claimable_amount,
-                participant_state.allocated_amount(),
-                vest_schedule.seed(),
-                vest_schedule.bump(),
+                fee_amount,
+                allocated_amount,
+                claimed_amount,
+                forfeited_amount,
+                new_claimed,
+                new_forfeited,
+                terms,
+                participant_state.is_revoked(),
+                // A revoked allocation never unlocks anything more
+                if participant_state.is_revoked() {
+                    0
+                } else {
+                    terms.next_unlock_timestamp(current_timestamp)
+                },
             )
-        }; // Both borrows dropped here
-
-        if claimable_amount == 0 {
-            return Err(PinocchioError::NoClaimableAmount.into());
-        }
+        }; // Borrow dropped here
 
         {
-            let vault_account = TokenAccount::from_account_info(self.accounts.vault)?;
-            if vault_account.amount() < claimable_amount {
-                return Err(ProgramError::InsufficientFunds);
+            let mut vest_schedule_data = self.accounts.vest_schedule.try_borrow_mut_data()?;
+            let vest_schedule = VestSchedule::load_mut(&mut vest_schedule_data)?;
+            vest_schedule.add_claimed(claimable_amount)?;
+            // Forfeited tokens stay in the vault but are owed to no one, so they become surplus
+            if new_forfeited > forfeited_amount {
+                vest_schedule.remove_allocation(new_forfeited - forfeited_amount)?;
             }
         }
 
-        let seed_binding = schedule_seed.to_le_bytes();
-        let bump_binding = [schedule_bump];
-        let vest_schedule_seeds = [
-            Seed::from(b"vest_schedule"),
-            Seed::from(&seed_binding),
-            Seed::from(&bump_binding),
-        ];
-
-        let signer = Signer::from(&vest_schedule_seeds);
-
-        Transfer {
-            from: self.accounts.vault,
-            to: self.accounts.participant_ata,
-            authority: self.accounts.vest_schedule,
-            amount: claimable_amount,
+        let vault_signer = VaultSigner::new(self.accounts.vest_schedule.key(), vest_schedule);
+        let vault_authority =
+            vault_signer.authority(self.accounts.vest_schedule, self.accounts.remaining)?;
+
+        if fee_amount > 0 {
+            let fee_account = self
+                .accounts
+                .remaining
+                .iter()
+                .find(|account| account.key() == vest_schedule.fee_recipient())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+            vault_signer.transfer(
+                self.accounts.vault,
+                fee_account,
+                vault_authority,
+                self.accounts.token_program,
+                fee_amount,
+            )?;
         }
-        .invoke_signed(&[signer])?;
 
-        let mut participant_state_data = self.accounts.participant_state.try_borrow_mut_data()?;
-        let participant_state = VestParticipant::load_mut(&mut participant_state_data)?;
-
-        let new_claimed = participant_state
-            .claimed_amount()
-            .saturating_add(claimable_amount);
-
-        if new_claimed > allocated_amount {
-            return Err(PinocchioError::ClaimExceedsAllocation.into());
+        vault_signer.transfer(
+            self.accounts.vault,
+            self.accounts.participant_ata,
+            vault_authority,
+            self.accounts.token_program,
+            claimable_amount - fee_amount,
+        )?;
+
+        let schedule_seed = vest_schedule.seed();
+
+        // One event per step this claim took out of the allocation, so indexers can build an
+        // unlock ledger: [b"step", schedule_seed, participant, step_index, step_amount].
+        // Linear schedules and revoked allocations have no steps to report.
+        if !is_revoked && !terms.is_linear() {
+            let first_step = terms.steps_covered(
+                claimed_amount.saturating_add(forfeited_amount),
+                allocated_amount,
+            ) + 1;
+            let last_step =
+                terms.steps_covered(new_claimed.saturating_add(new_forfeited), allocated_amount);
+
+            for step in (first_step..=last_step).take(Self::MAX_STEP_EVENTS) {
+                let step_amount = terms.vested_at_step(step, allocated_amount)
+                    - terms.vested_at_step(step - 1, allocated_amount);
+
+                sol_log_data(&[
+                    b"step",
+                    &schedule_seed.to_le_bytes(),
+                    self.accounts.participant.key().as_ref(),
+                    &step.to_le_bytes(),
+                    &step_amount.to_le_bytes(),
+                ]);
+            }
         }
 
-        participant_state.set_claimed_amount(new_claimed);
+        // Stable success record for monitoring and the claim's activity event:
+        // [schedule_seed, participant, amount, new_claimed, remaining]
+        sol_log_data(&[
+            &schedule_seed.to_le_bytes(),
+            self.accounts.participant.key().as_ref(),
+            &claimable_amount.to_le_bytes(),
+            &new_claimed.to_le_bytes(),
+            &allocated_amount.saturating_sub(new_claimed).to_le_bytes(),
+        ]);
+
+        // [next_unlock_timestamp, claimed_amount]: lets wallets schedule the next claim and
+        // show what left the vault (fee included) without querying. The timestamp is zero
+        // when nothing more unlocks.
+        let mut return_data = [0u8; 16];
+        return_data[..8].copy_from_slice(&next_unlock_timestamp.to_le_bytes());
+        return_data[8..].copy_from_slice(&claimable_amount.to_le_bytes());
+        set_return_data(&return_data);
 
         Ok(())
     }
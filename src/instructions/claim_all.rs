@@ -0,0 +1,179 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{
+    AssociatedToken, Claim, ClaimAccounts, Mint, PinocchioError, ProgramAccount, SignerAccount,
+    VestParticipant, VestSchedule,
+};
+
+pub struct ClaimAllAccounts<'a> {
+    pub participant: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub token_program: &'a AccountInfo,
+    pub ata_program: &'a AccountInfo,
+    pub config: &'a AccountInfo,
+    /// `(schedule, participant_state, vault, participant_ata, token_mint)` per schedule.
+    pub groups: &'a [AccountInfo],
+    /// Co-signers, fee recipients and vault authorities, shared by every group.
+    pub remaining: &'a [AccountInfo],
+}
+
+impl<'a> ClaimAllAccounts<'a> {
+    pub const GROUP_LEN: usize = 5;
+
+    fn try_from_accounts(
+        accounts: &'a [AccountInfo],
+        group_count: usize,
+    ) -> Result<Self, ProgramError> {
+        let [participant, system_program, token_program, ata_program, config, rest @ ..] = accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        let groups_len = group_count * Self::GROUP_LEN;
+        if rest.len() < groups_len {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        let (groups, remaining) = rest.split_at(groups_len);
+
+        SignerAccount::check(participant)?;
+        ProgramAccount::check_token_program(token_program)?;
+        ProgramAccount::check_system_program(system_program)?;
+        ProgramAccount::check_ata_program(ata_program)?;
+
+        Ok(Self {
+            participant,
+            system_program,
+            token_program,
+            ata_program,
+            config,
+            groups,
+            remaining,
+        })
+    }
+}
+
+/// `[group_count: u8]`
+pub struct ClaimAllInstructionData {
+    pub group_count: u8,
+}
+
+impl TryFrom<&[u8]> for ClaimAllInstructionData {
+    type Error = ProgramError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        let [group_count] = data else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+
+        if *group_count == 0 || *group_count as usize > ClaimAll::MAX_GROUPS {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Ok(Self {
+            group_count: *group_count,
+        })
+    }
+}
+
+/// `Claim` from several schedules at once, for wallets vesting in more than one project.
+/// Each group is checked like a standalone claim and pays into the participant's ATA for
+/// that schedule's mint; groups with nothing to claim yet are skipped. All schedules must
+/// use the same token program.
+pub struct ClaimAll<'a> {
+    pub claims: Vec<Claim<'a>>,
+}
+
+impl<'a> TryFrom<(&[u8], &'a [AccountInfo])> for ClaimAll<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&[u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let instruction_data = ClaimAllInstructionData::try_from(data)?;
+        let accounts =
+            ClaimAllAccounts::try_from_accounts(accounts, instruction_data.group_count as usize)?;
+
+        let claims = accounts
+            .groups
+            .chunks_exact(ClaimAllAccounts::GROUP_LEN)
+            .map(|group| {
+                let [vest_schedule, participant_state, vault, participant_ata, token_mint] = group
+                else {
+                    return Err(ProgramError::NotEnoughAccountKeys);
+                };
+
+                ProgramAccount::check::<VestSchedule>(vest_schedule)?;
+                ProgramAccount::check::<VestParticipant>(participant_state)?;
+                Mint::check(token_mint)?;
+
+                let claim_accounts = ClaimAccounts {
+                    participant: accounts.participant,
+                    participant_state,
+                    participant_ata,
+                    vest_schedule,
+                    vault,
+                    token_mint,
+                    system_program: accounts.system_program,
+                    token_program: accounts.token_program,
+                    ata_program: accounts.ata_program,
+                    config: accounts.config,
+                    remaining: accounts.remaining,
+                };
+
+                let vest_schedule = Claim::check(&claim_accounts)?;
+
+                AssociatedToken::init_if_needed(
+                    participant_ata,
+                    token_mint,
+                    accounts.participant,
+                    accounts.participant,
+                    accounts.system_program,
+                    accounts.token_program,
+                )?;
+
+                Ok(Claim {
+                    accounts: claim_accounts,
+                    vest_schedule,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { claims })
+    }
+}
+
+impl<'a> ClaimAll<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &24;
+    /// Keeps a full set of groups within the transaction account and compute limits.
+    pub const MAX_GROUPS: usize = 4;
+
+    pub fn process(&self) -> Result<(), ProgramError> {
+        let mut claimed_any = false;
+
+        for claim in &self.claims {
+            match claim.process() {
+                Ok(()) => claimed_any = true,
+                // These are all raised before any transfer, so skipping leaves no partial claim
+                Err(error) if Self::is_nothing_to_claim(&error) => {}
+                Err(error) => return Err(error),
+            }
+        }
+
+        if !claimed_any {
+            return Err(PinocchioError::NoClaimableAmount.into());
+        }
+
+        Ok(())
+    }
+
+    fn is_nothing_to_claim(error: &ProgramError) -> bool {
+        [
+            PinocchioError::NoClaimableAmount,
+            PinocchioError::CliffNotReached,
+            PinocchioError::VestingRevoked,
+            PinocchioError::ClaimCooldown,
+            PinocchioError::FullyClaimed,
+            PinocchioError::ClaimWindowExpired,
+        ]
+        .into_iter()
+        .any(|nothing_to_claim| *error == nothing_to_claim.into())
+    }
+}
@@ -0,0 +1,43 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{AssociatedToken, Claim, ClaimAccounts, Token};
+
+/// `Claim` paying out to an ATA of the participant's choosing, e.g. an exchange deposit
+/// address. Takes `Claim`'s accounts with the destination in place of the participant's ATA.
+/// The destination must already exist and be the canonical ATA of its owner for the
+/// schedule's mint; the vesting terms are unchanged.
+pub struct ClaimTo<'a> {
+    pub claim: Claim<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ClaimTo<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let accounts = ClaimAccounts::try_from(accounts)?;
+
+        let vest_schedule = Claim::check(&accounts)?;
+
+        AssociatedToken::check(
+            accounts.participant_ata,
+            Token::owner(accounts.participant_ata)?,
+            *accounts.token_mint.key(),
+            *accounts.token_program.key(),
+        )?;
+
+        Ok(Self {
+            claim: Claim {
+                accounts,
+                vest_schedule,
+            },
+        })
+    }
+}
+
+impl<'a> ClaimTo<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &16;
+
+    pub fn process(&self) -> Result<(), ProgramError> {
+        self.claim.process()
+    }
+}
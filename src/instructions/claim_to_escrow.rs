@@ -0,0 +1,50 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{Claim, ClaimAccounts, PinocchioError, Token};
+
+/// `Claim` paying out to any existing token account of the schedule's mint, e.g. an escrow
+/// owned by a staking program's PDA, rather than to an ATA. Only for schedules whose
+/// authority allowed escrow destinations at initialization. Takes `Claim`'s accounts with
+/// the escrow in place of the participant's ATA.
+pub struct ClaimToEscrow<'a> {
+    pub claim: Claim<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ClaimToEscrow<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let accounts = ClaimAccounts::try_from(accounts)?;
+
+        let vest_schedule = Claim::check(&accounts)?;
+
+        if !vest_schedule.allow_escrow_destination() {
+            return Err(PinocchioError::EscrowNotAllowed.into());
+        }
+
+        // `check` has already matched an existing destination's mint; this makes sure it
+        // exists and belongs to the schedule's token program
+        Token::check(accounts.participant_ata)?;
+        if !accounts
+            .participant_ata
+            .is_owned_by(accounts.token_program.key())
+        {
+            return Err(PinocchioError::InvalidTokenProgram.into());
+        }
+
+        Ok(Self {
+            claim: Claim {
+                accounts,
+                vest_schedule,
+            },
+        })
+    }
+}
+
+impl<'a> ClaimToEscrow<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &30;
+
+    pub fn process(&self) -> Result<(), ProgramError> {
+        self.claim.process()
+    }
+}
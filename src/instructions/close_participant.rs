@@ -0,0 +1,67 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{
+    current_unix_timestamp, Claim, PinocchioError, ProgramAccount, VestParticipant, VestSchedule,
+};
+
+/// Closes a participant's state once nothing is left to vest, returning its rent to the
+/// participant. Any vested but unclaimed tokens are claimed first, so closing never
+/// discards what the participant is owed. Takes the same accounts as `Claim`.
+pub struct CloseParticipant<'a> {
+    pub claim: Claim<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for CloseParticipant<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let claim = Claim::try_from(accounts)?;
+
+        Ok(Self { claim })
+    }
+}
+
+impl<'a> CloseParticipant<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &5;
+
+    pub fn process(&self) -> Result<(), ProgramError> {
+        let accounts = &self.claim.accounts;
+
+        let vest_schedule = &self.claim.vest_schedule;
+
+        let (claimable_amount, newly_forfeited) = {
+            let participant_state_data = accounts.participant_state.try_borrow_data()?;
+            let participant_state = VestParticipant::load(&participant_state_data)?;
+
+            let current_timestamp = current_unix_timestamp()?;
+            let allocated_amount = participant_state.allocated_amount();
+            let claimed_amount = participant_state.claimed_amount();
+
+            let claimable_amount =
+                participant_state.claimable_now(vest_schedule, current_timestamp);
+            let previously_forfeited = participant_state.forfeited_amount();
+            let forfeited_amount = participant_state.forfeited_at(vest_schedule, current_timestamp);
+
+            // After the residual claim every allocated token must be claimed or forfeited
+            if claimed_amount
+                .saturating_add(forfeited_amount)
+                .saturating_add(claimable_amount)
+                < allocated_amount
+            {
+                return Err(PinocchioError::VestingNotComplete.into());
+            }
+
+            (claimable_amount, forfeited_amount - previously_forfeited)
+        }; // Borrow dropped here
+
+        if claimable_amount > 0 {
+            self.claim.process()?;
+        } else if newly_forfeited > 0 {
+            // The residual claim would have released the expired part; do it without one
+            let mut vest_schedule_data = accounts.vest_schedule.try_borrow_mut_data()?;
+            VestSchedule::load_mut(&mut vest_schedule_data)?.remove_allocation(newly_forfeited)?;
+        }
+
+        ProgramAccount::close(accounts.participant_state, accounts.participant)
+    }
+}
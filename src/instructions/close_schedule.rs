@@ -0,0 +1,95 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{PinocchioError, ProgramAccount, SignerAccount, Token, VaultSigner, VestSchedule};
+
+pub struct CloseScheduleAccounts<'a> {
+    pub authority: &'a AccountInfo,
+    pub schedule: &'a AccountInfo,
+    pub vault: &'a AccountInfo,
+    pub token_program: &'a AccountInfo,
+    pub remaining: &'a [AccountInfo],
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for CloseScheduleAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, schedule, vault, token_program, remaining @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(authority)?;
+        ProgramAccount::check_token_program(token_program)?;
+        ProgramAccount::check::<VestSchedule>(schedule)?;
+
+        Ok(Self {
+            authority,
+            schedule,
+            vault,
+            token_program,
+            remaining,
+        })
+    }
+}
+
+/// Closes a schedule whose vault has been emptied by claims and revocations, sending the
+/// rent of both the vault and the schedule to the authority.
+pub struct CloseSchedule<'a> {
+    pub accounts: CloseScheduleAccounts<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for CloseSchedule<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let accounts = CloseScheduleAccounts::try_from(accounts)?;
+
+        {
+            let vest_schedule_data = accounts.schedule.try_borrow_data()?;
+            let vest_schedule = VestSchedule::load(&vest_schedule_data)?;
+
+            if accounts.authority.key() != vest_schedule.authority() {
+                return Err(ProgramError::IllegalOwner);
+            }
+
+            if accounts.token_program.key() != vest_schedule.token_program() {
+                return Err(PinocchioError::InvalidTokenProgram.into());
+            }
+
+            if accounts.vault.key() != vest_schedule.vault() {
+                return Err(PinocchioError::VaultMismatch.into());
+            }
+        }
+
+        if Token::amount(accounts.vault)? != 0 {
+            return Err(PinocchioError::VaultNotEmpty.into());
+        }
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a> CloseSchedule<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &10;
+
+    pub fn process(&self) -> Result<(), ProgramError> {
+        let vault_signer = {
+            let vest_schedule_data = self.accounts.schedule.try_borrow_data()?;
+            let vest_schedule = VestSchedule::load(&vest_schedule_data)?;
+
+            VaultSigner::new(self.accounts.schedule.key(), vest_schedule)
+        }; // Borrow dropped here
+
+        let vault_authority =
+            vault_signer.authority(self.accounts.schedule, self.accounts.remaining)?;
+
+        vault_signer.close(
+            self.accounts.vault,
+            self.accounts.authority,
+            vault_authority,
+            self.accounts.token_program,
+        )?;
+
+        ProgramAccount::close(self.accounts.schedule, self.accounts.authority)
+    }
+}
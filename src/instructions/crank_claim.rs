@@ -0,0 +1,51 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{AssociatedToken, Claim, ClaimAccounts, SignerAccount};
+
+/// Permissionless claim on a participant's behalf, for keepers that push vested tokens out.
+/// Takes a paying signer followed by `Claim`'s accounts, with the participant not signing.
+/// Tokens can only go to the participant's canonical ATA, which must already exist, so the
+/// caller can't redirect them.
+pub struct CrankClaim<'a> {
+    pub payer: &'a AccountInfo,
+    pub claim: Claim<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for CrankClaim<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [payer, claim_accounts @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(payer)?;
+
+        let accounts = ClaimAccounts::try_from_unsigned(claim_accounts)?;
+
+        let vest_schedule = Claim::check(&accounts)?;
+
+        AssociatedToken::check(
+            accounts.participant_ata,
+            *accounts.participant.key(),
+            *accounts.token_mint.key(),
+            *accounts.token_program.key(),
+        )?;
+
+        Ok(Self {
+            payer,
+            claim: Claim {
+                accounts,
+                vest_schedule,
+            },
+        })
+    }
+}
+
+impl<'a> CrankClaim<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &15;
+
+    pub fn process(&self) -> Result<(), ProgramError> {
+        self.claim.process()
+    }
+}
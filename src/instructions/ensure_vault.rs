@@ -0,0 +1,146 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::{find_program_address, Pubkey},
+};
+
+use crate::{AssociatedToken, Mint, PinocchioError, ProgramAccount, SignerAccount, VestSchedule};
+
+pub struct EnsureVaultAccounts<'a> {
+    pub authority: &'a AccountInfo,
+    pub schedule: &'a AccountInfo,
+    pub vault: &'a AccountInfo,
+    pub token_mint: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub token_program: &'a AccountInfo,
+    pub ata_program: &'a AccountInfo,
+    /// The vault authority PDA, for schedules whose vault it owns.
+    pub remaining: &'a [AccountInfo],
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for EnsureVaultAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, schedule, vault, token_mint, system_program, token_program, ata_program, remaining @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(authority)?;
+        ProgramAccount::check_system_program(system_program)?;
+        ProgramAccount::check_token_program(token_program)?;
+        ProgramAccount::check_ata_program(ata_program)?;
+        ProgramAccount::check::<VestSchedule>(schedule)?;
+        Mint::check(token_mint)?;
+
+        Ok(Self {
+            authority,
+            schedule,
+            vault,
+            token_mint,
+            system_program,
+            token_program,
+            ata_program,
+            remaining,
+        })
+    }
+}
+
+/// Recovery for schedules left without a vault: creates the schedule's vault ATA if it is
+/// missing and records it on the schedule if none is recorded yet. Succeeds without
+/// changes when the vault already exists.
+pub struct EnsureVault<'a> {
+    pub accounts: EnsureVaultAccounts<'a>,
+    /// Wallet the vault ATA belongs to: the vault authority PDA or the schedule.
+    pub vault_owner: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for EnsureVault<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let accounts = EnsureVaultAccounts::try_from(accounts)?;
+
+        let vault_owner = {
+            let vest_schedule_data = accounts.schedule.try_borrow_data()?;
+            let vest_schedule = VestSchedule::load(&vest_schedule_data)?;
+
+            if accounts.authority.key() != vest_schedule.authority() {
+                return Err(ProgramError::IllegalOwner);
+            }
+
+            if accounts.token_mint.key() != vest_schedule.token_mint() {
+                return Err(PinocchioError::MintMismatch.into());
+            }
+
+            if accounts.token_program.key() != vest_schedule.token_program() {
+                return Err(PinocchioError::InvalidTokenProgram.into());
+            }
+
+            if *vest_schedule.vault() != Pubkey::default()
+                && accounts.vault.key() != vest_schedule.vault()
+            {
+                return Err(PinocchioError::VaultMismatch.into());
+            }
+
+            let vault_owner = if vest_schedule.has_vault_authority() {
+                let vault_authority = accounts
+                    .remaining
+                    .first()
+                    .ok_or(ProgramError::NotEnoughAccountKeys)?;
+                if vault_authority.key() != vest_schedule.vault_authority() {
+                    return Err(ProgramError::InvalidAccountData);
+                }
+                vault_authority
+            } else {
+                accounts.schedule
+            };
+
+            // Only the schedule's own ATA may become its vault
+            let (expected_vault, _) = find_program_address(
+                &[
+                    vault_owner.key().as_ref(),
+                    accounts.token_program.key().as_ref(),
+                    accounts.token_mint.key().as_ref(),
+                ],
+                &pinocchio_associated_token_account::ID,
+            );
+            if *accounts.vault.key() != expected_vault {
+                return Err(PinocchioError::VaultMismatch.into());
+            }
+
+            vault_owner
+        };
+
+        Ok(Self {
+            accounts,
+            vault_owner,
+        })
+    }
+}
+
+impl<'a> EnsureVault<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &29;
+
+    pub fn process(&self) -> Result<(), ProgramError> {
+        AssociatedToken::init_if_needed(
+            self.accounts.vault,
+            self.accounts.token_mint,
+            self.accounts.authority,
+            self.vault_owner,
+            self.accounts.system_program,
+            self.accounts.token_program,
+        )?;
+
+        let mut vest_schedule_data = self.accounts.schedule.try_borrow_mut_data()?;
+        let vest_schedule = VestSchedule::load_mut(&mut vest_schedule_data)?;
+
+        if *vest_schedule.vault() == Pubkey::default() {
+            vest_schedule.set_vault(*self.accounts.vault.key());
+        }
+
+        Ok(())
+    }
+}
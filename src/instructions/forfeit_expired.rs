@@ -0,0 +1,99 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{
+    current_unix_timestamp, PinocchioError, ProgramAccount, SignerAccount, VestParticipant,
+    VestSchedule, VEST_PARTICIPANT_SEED,
+};
+
+pub struct ForfeitExpiredAccounts<'a> {
+    pub authority: &'a AccountInfo,
+    pub schedule: &'a AccountInfo,
+    pub participant_state: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ForfeitExpiredAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, schedule, participant_state] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(authority)?;
+        ProgramAccount::check::<VestSchedule>(schedule)?;
+        ProgramAccount::check::<VestParticipant>(participant_state)?;
+
+        Ok(Self {
+            authority,
+            schedule,
+            participant_state,
+        })
+    }
+}
+
+/// Authority-only: records whatever of a participant's allocation left the claim window
+/// unclaimed as forfeited and releases it from the schedule's `total_allocated`, so it can
+/// be swept as surplus without waiting for the participant to claim, close or be revoked.
+/// Does nothing when nothing new has expired.
+pub struct ForfeitExpired<'a> {
+    pub accounts: ForfeitExpiredAccounts<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ForfeitExpired<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let accounts = ForfeitExpiredAccounts::try_from(accounts)?;
+
+        {
+            let vest_schedule_data = accounts.schedule.try_borrow_data()?;
+            let vest_schedule = VestSchedule::load(&vest_schedule_data)?;
+
+            if accounts.authority.key() != vest_schedule.authority() {
+                return Err(ProgramError::IllegalOwner);
+            }
+
+            let participant_state_data = accounts.participant_state.try_borrow_data()?;
+            let participant_state = VestParticipant::load(&participant_state_data)?;
+
+            if participant_state.schedule() != accounts.schedule.key() {
+                return Err(PinocchioError::WrongSchedule.into());
+            }
+
+            ProgramAccount::verify_address(
+                &[
+                    VEST_PARTICIPANT_SEED,
+                    participant_state.participant().as_ref(),
+                    accounts.schedule.key().as_ref(),
+                    &[participant_state.bump()],
+                ],
+                accounts.participant_state,
+            )?;
+        }
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a> ForfeitExpired<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &33;
+
+    pub fn process(&self) -> Result<(), ProgramError> {
+        let mut vest_schedule_data = self.accounts.schedule.try_borrow_mut_data()?;
+        let vest_schedule = VestSchedule::load_mut(&mut vest_schedule_data)?;
+
+        let mut participant_state_data = self.accounts.participant_state.try_borrow_mut_data()?;
+        let participant_state = VestParticipant::load_mut(&mut participant_state_data)?;
+
+        let current_timestamp = current_unix_timestamp()?;
+        let previously_forfeited = participant_state.forfeited_amount();
+        let forfeited_amount = participant_state.forfeited_at(vest_schedule, current_timestamp);
+
+        if forfeited_amount > previously_forfeited {
+            participant_state.set_forfeited_amount(forfeited_amount);
+            vest_schedule.remove_allocation(forfeited_amount - previously_forfeited)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{PinocchioError, ProgramAccount, SignerAccount, VestSchedule};
+
+pub struct FreezeClaimsAccounts<'a> {
+    pub authority: &'a AccountInfo,
+    pub schedule: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for FreezeClaimsAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, schedule] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(authority)?;
+        ProgramAccount::check::<VestSchedule>(schedule)?;
+
+        {
+            let vest_schedule_data = schedule.try_borrow_data()?;
+            let vest_schedule = VestSchedule::load(&vest_schedule_data)?;
+
+            if authority.key() != vest_schedule.authority() {
+                return Err(ProgramError::IllegalOwner);
+            }
+        }
+
+        Ok(Self {
+            authority,
+            schedule,
+        })
+    }
+}
+
+/// Authority-only. Holds every claim on the schedule, e.g. during a token migration, until
+/// `UnfreezeClaims`. Unlike a pause this never touches the vesting curve, so whatever
+/// vests meanwhile is claimable as soon as claims are unfrozen.
+pub struct FreezeClaims<'a> {
+    pub accounts: FreezeClaimsAccounts<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for FreezeClaims<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let accounts = FreezeClaimsAccounts::try_from(accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a> FreezeClaims<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &27;
+
+    pub fn process(&self) -> Result<(), ProgramError> {
+        let mut vest_schedule_data = self.accounts.schedule.try_borrow_mut_data()?;
+        let vest_schedule = VestSchedule::load_mut(&mut vest_schedule_data)?;
+
+        if vest_schedule.claims_frozen() {
+            return Err(PinocchioError::ClaimsFrozen.into());
+        }
+
+        vest_schedule.set_claims_frozen(true);
+
+        Ok(())
+    }
+}
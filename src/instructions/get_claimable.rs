@@ -0,0 +1,70 @@
+use pinocchio::{account_info::AccountInfo, cpi::set_return_data, program_error::ProgramError};
+
+use crate::{current_unix_timestamp, ProgramAccount, VestParticipant, VestSchedule};
+
+pub struct GetClaimableAccounts<'a> {
+    pub schedule: &'a AccountInfo,
+    pub participant_state: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for GetClaimableAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [schedule, participant_state] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        ProgramAccount::check::<VestSchedule>(schedule)?;
+        ProgramAccount::check::<VestParticipant>(participant_state)?;
+
+        Ok(Self {
+            schedule,
+            participant_state,
+        })
+    }
+}
+
+/// Read-only: returns what the participant could claim at the current clock as a little-endian
+/// `u64` in return data, so wallets can show it by simulating this instruction.
+pub struct GetClaimable<'a> {
+    pub accounts: GetClaimableAccounts<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for GetClaimable<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let accounts = GetClaimableAccounts::try_from(accounts)?;
+
+        {
+            let participant_state_data = accounts.participant_state.try_borrow_data()?;
+            let participant_state = VestParticipant::load(&participant_state_data)?;
+
+            if participant_state.schedule() != accounts.schedule.key() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+        }
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a> GetClaimable<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &18;
+
+    pub fn process(&self) -> Result<(), ProgramError> {
+        let vest_schedule_data = self.accounts.schedule.try_borrow_data()?;
+        let vest_schedule = VestSchedule::load(&vest_schedule_data)?;
+
+        let participant_state_data = self.accounts.participant_state.try_borrow_data()?;
+        let participant_state = VestParticipant::load(&participant_state_data)?;
+
+        let current_timestamp = current_unix_timestamp()?;
+        let claimable_amount = participant_state.claimable_now(vest_schedule, current_timestamp);
+
+        set_return_data(&claimable_amount.to_le_bytes());
+
+        Ok(())
+    }
+}
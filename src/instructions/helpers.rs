@@ -1,15 +1,26 @@
 use pinocchio::{
     account_info::AccountInfo,
-    instruction::{Seed, Signer},
+    cpi::invoke_signed,
+    instruction::{AccountMeta, Instruction, Seed, Signer},
+    log::sol_log_data,
     program_error::ProgramError,
-    pubkey::{find_program_address, Pubkey},
-    sysvars::{rent::Rent, Sysvar},
+    pubkey::{create_program_address, find_program_address, Pubkey},
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
     ProgramResult,
 };
 use pinocchio_associated_token_account::instructions::Create;
-use pinocchio_system::instructions::CreateAccount;
+use pinocchio_system::instructions::{CreateAccount, Transfer};
 
-use crate::PinocchioError;
+use crate::{
+    PinocchioError, VestSchedule, TOKEN_2022_PROGRAM_ID, VAULT_AUTHORITY_SEED, VEST_SCHEDULE_SEED,
+};
+
+/// The cluster's unix timestamp as a u64. A negative timestamp (a misconfigured validator)
+/// fails with `InvalidClock` rather than wrapping into the far future.
+#[inline(always)]
+pub fn current_unix_timestamp() -> Result<u64, ProgramError> {
+    u64::try_from(Clock::get()?.unix_timestamp).map_err(|_| PinocchioError::InvalidClock.into())
+}
 
 pub struct SignerAccount;
 
@@ -39,12 +50,21 @@ impl ProgramAccount {
     pub fn check_token_program(
         account: &pinocchio::account_info::AccountInfo,
     ) -> Result<(), pinocchio::program_error::ProgramError> {
-        if account.key() != &pinocchio_token::ID {
+        if !Self::is_token_program(account.key()) {
             return Err(PinocchioError::InvalidOwner.into());
         }
         Ok(())
     }
 
+    /// Legacy SPL Token and Token-2022 are the only supported token programs.
+    pub fn is_token_program(key: &Pubkey) -> bool {
+        key == &pinocchio_token::ID || key == &TOKEN_2022_PROGRAM_ID
+    }
+
+    fn is_owned_by_token_program(account: &AccountInfo) -> bool {
+        account.is_owned_by(&pinocchio_token::ID) || account.is_owned_by(&TOKEN_2022_PROGRAM_ID)
+    }
+
     pub fn check_ata_program(
         account: &pinocchio::account_info::AccountInfo,
     ) -> Result<(), pinocchio::program_error::ProgramError> {
@@ -66,19 +86,49 @@ impl ProgramAccount {
             return Err(PinocchioError::InvalidOwner.into());
         }
 
-        if account.data_len() != T::LEN {
+        // Discriminator first, so an account of another state type is reported as such
+        // rather than as a plain length mismatch
+        let data = account.try_borrow_data()?;
+        if data.first() != Some(&T::DISCRIMINATOR) {
+            return Err(PinocchioError::InvalidDiscriminator.into());
+        }
+
+        if data.len() != T::LEN {
             return Err(PinocchioError::InvalidAccountData.into());
         }
 
+        Ok(())
+    }
+
+    pub fn is_initialized<T: Discriminator>(account: &AccountInfo) -> Result<bool, ProgramError> {
+        if !account.is_owned_by(&crate::ID) {
+            return Ok(false);
+        }
+
         let data = account.try_borrow_data()?;
-        if data[0] != T::DISCRIMINATOR {
-            return Err(PinocchioError::InvalidDiscriminator.into());
+        Ok(data.len() == T::LEN && data.first() == Some(&T::DISCRIMINATOR))
+    }
+
+    pub fn verify(seeds: &[Seed], account: &AccountInfo, bump: u8) -> Result<(), ProgramError> {
+        if bump != Self::find_bump(seeds, account)? {
+            return Err(ProgramError::InvalidSeeds);
         }
 
         Ok(())
     }
 
-    pub fn verify(seeds: &[Seed], account: &AccountInfo, bump: u8) -> Result<(), ProgramError> {
+    /// Checks that `account` is the PDA of `seeds`, the last of which is a bump stored when the
+    /// account was created. A single hash with no bump search or allocation, so it suits hot
+    /// paths re-checking accounts whose canonical bump `verify` already enforced at creation.
+    pub fn verify_address(seeds: &[&[u8]], account: &AccountInfo) -> Result<(), ProgramError> {
+        match create_program_address(seeds, &crate::ID) {
+            Ok(address) if &address == account.key() => Ok(()),
+            _ => Err(ProgramError::InvalidSeeds),
+        }
+    }
+
+    /// Checks that `account` is the PDA of `seeds` and returns its canonical bump.
+    pub fn find_bump(seeds: &[Seed], account: &AccountInfo) -> Result<u8, ProgramError> {
         let seed_bytes: Vec<&[u8]> = seeds.iter().map(|s| s.as_ref()).collect();
 
         let (expected_pubkey, expected_bump) = find_program_address(&seed_bytes, &crate::ID);
@@ -87,11 +137,7 @@ impl ProgramAccount {
             return Err(ProgramError::InvalidAccountData);
         }
 
-        if bump != expected_bump {
-            return Err(ProgramError::InvalidSeeds);
-        }
-
-        Ok(())
+        Ok(expected_bump)
     }
 
     pub fn init<'a, T: Sized>(
@@ -118,40 +164,376 @@ impl ProgramAccount {
 
         Ok(())
     }
+
+    /// Resizes a program account to `new_len`, topping its lamports up from `payer` to the
+    /// rent-exempt minimum for the new size.
+    pub fn grow(payer: &AccountInfo, account: &AccountInfo, new_len: usize) -> ProgramResult {
+        let shortfall = Rent::get()?
+            .minimum_balance(new_len)
+            .saturating_sub(account.lamports());
+
+        if shortfall > 0 {
+            Transfer {
+                from: payer,
+                to: account,
+                lamports: shortfall,
+            }
+            .invoke()?;
+        }
+
+        account.resize(new_len)
+    }
+
+    /// Moves all lamports of a program account to `destination` and closes it, which
+    /// also zeroes its data length and owner.
+    pub fn close(account: &AccountInfo, destination: &AccountInfo) -> ProgramResult {
+        {
+            let mut destination_lamports = destination.try_borrow_mut_lamports()?;
+            *destination_lamports = destination_lamports
+                .checked_add(account.lamports())
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+        }
+
+        account.close()
+    }
 }
 
 pub struct Mint;
 
 impl Mint {
+    /// Token-2022 `AccountType` tag for mints, stored after the padded base layout when a
+    /// mint carries extensions.
+    const TOKEN_2022_ACCOUNT_TYPE: u8 = 1;
+
     pub fn check(account: &AccountInfo) -> Result<(), ProgramError> {
-        if !account.is_owned_by(&pinocchio_token::ID) {
+        if !ProgramAccount::is_owned_by_token_program(account) {
             return Err(PinocchioError::InvalidOwner.into());
         }
 
-        if account.data_len().ne(&pinocchio_token::state::Mint::LEN) {
+        if account.data_len().ne(&pinocchio_token::state::Mint::LEN)
+            && !Self::is_extended_token_2022_mint(account)
+        {
             return Err(PinocchioError::InvalidAccountData.into());
         }
 
         Ok(())
     }
+
+    // Token-2022 mints with extensions are padded to the token account length, so the
+    // type tag sits at the same offset as in extended token accounts
+    fn is_extended_token_2022_mint(account: &AccountInfo) -> bool {
+        account.is_owned_by(&TOKEN_2022_PROGRAM_ID)
+            && account.data_len() > pinocchio_token::state::TokenAccount::LEN
+            && account.try_borrow_data().is_ok_and(|data| {
+                data[pinocchio_token::state::TokenAccount::LEN] == Self::TOKEN_2022_ACCOUNT_TYPE
+            })
+    }
+
+    /// Token-2022 `ExtensionType::TransferFeeConfig`.
+    const TRANSFER_FEE_CONFIG_EXTENSION: u16 = 1;
+
+    /// Rejects mints whose transfers deliver less than the amount sent. A transfer-fee mint
+    /// would leave the vault holding less than the allocations it records.
+    pub fn check_supported_extensions(account: &AccountInfo) -> Result<(), ProgramError> {
+        if Self::has_extension(account, Self::TRANSFER_FEE_CONFIG_EXTENSION)? {
+            return Err(PinocchioError::UnsupportedMintExtension.into());
+        }
+
+        Ok(())
+    }
+
+    /// Whether a Token-2022 mint carries `extension_type` in its TLV area. Mints without
+    /// extensions have none.
+    pub fn has_extension(account: &AccountInfo, extension_type: u16) -> Result<bool, ProgramError> {
+        if !Self::is_extended_token_2022_mint(account) {
+            return Ok(false);
+        }
+
+        let data = account.try_borrow_data()?;
+        // Entries are `[type: u16, length: u16, value]`, right after the account type tag
+        let mut offset = pinocchio_token::state::TokenAccount::LEN + 1;
+        while offset + 4 <= data.len() {
+            let entry_type = u16::from_le_bytes([data[offset], data[offset + 1]]);
+            let entry_len = u16::from_le_bytes([data[offset + 2], data[offset + 3]]) as usize;
+
+            // Zeroed space after the last entry is `ExtensionType::Uninitialized`
+            if entry_type == 0 {
+                break;
+            }
+
+            if entry_type == extension_type {
+                return Ok(true);
+            }
+
+            offset += 4 + entry_len;
+        }
+
+        Ok(false)
+    }
 }
 
 pub struct Token;
 impl Token {
+    /// Token-2022 `AccountType` tag for token accounts, stored right after the base layout
+    /// when an account carries extensions.
+    const TOKEN_2022_ACCOUNT_TYPE: u8 = 2;
+
     pub fn check(account: &AccountInfo) -> Result<(), ProgramError> {
-        if !account.is_owned_by(&pinocchio_token::ID) {
+        if !ProgramAccount::is_owned_by_token_program(account) {
             return Err(PinocchioError::InvalidOwner.into());
         }
 
         if account
             .data_len()
             .ne(&pinocchio_token::state::TokenAccount::LEN)
+            && !Self::is_extended_token_2022_account(account)
         {
             return Err(PinocchioError::InvalidAccountData.into());
         }
 
         Ok(())
     }
+
+    // Token-2022 ATAs always carry the ImmutableOwner extension, so they are longer than
+    // the base layout
+    fn is_extended_token_2022_account(account: &AccountInfo) -> bool {
+        account.is_owned_by(&TOKEN_2022_PROGRAM_ID)
+            && account.data_len() > pinocchio_token::state::TokenAccount::LEN
+            && account.try_borrow_data().is_ok_and(|data| {
+                data[pinocchio_token::state::TokenAccount::LEN] == Self::TOKEN_2022_ACCOUNT_TYPE
+            })
+    }
+
+    /// Balance of a legacy or Token-2022 token account.
+    pub fn amount(account: &AccountInfo) -> Result<u64, ProgramError> {
+        Self::check(account)?;
+
+        let data = account.try_borrow_data()?;
+        let token_account =
+            unsafe { pinocchio_token::state::TokenAccount::from_bytes_unchecked(&data) };
+        Ok(token_account.amount())
+    }
+
+    /// Owner of a legacy or Token-2022 token account.
+    pub fn owner(account: &AccountInfo) -> Result<Pubkey, ProgramError> {
+        Self::check(account)?;
+
+        let data = account.try_borrow_data()?;
+        let token_account =
+            unsafe { pinocchio_token::state::TokenAccount::from_bytes_unchecked(&data) };
+        Ok(*token_account.owner())
+    }
+
+    /// Mint of a legacy or Token-2022 token account.
+    pub fn mint(account: &AccountInfo) -> Result<Pubkey, ProgramError> {
+        Self::check(account)?;
+
+        let data = account.try_borrow_data()?;
+        let token_account =
+            unsafe { pinocchio_token::state::TokenAccount::from_bytes_unchecked(&data) };
+        Ok(*token_account.mint())
+    }
+
+    /// Whether the mint's freeze authority froze a legacy or Token-2022 token account.
+    pub fn is_frozen(account: &AccountInfo) -> Result<bool, ProgramError> {
+        Self::check(account)?;
+
+        let data = account.try_borrow_data()?;
+        let token_account =
+            unsafe { pinocchio_token::state::TokenAccount::from_bytes_unchecked(&data) };
+        Ok(token_account.is_frozen())
+    }
+}
+
+/// `pinocchio_token::instructions::Transfer` always targets the legacy token program; this
+/// sends the same instruction to whichever token program the schedule was created with.
+pub struct TokenTransfer<'a> {
+    pub from: &'a AccountInfo,
+    pub to: &'a AccountInfo,
+    pub authority: &'a AccountInfo,
+    pub token_program: &'a AccountInfo,
+    pub amount: u64,
+}
+
+impl TokenTransfer<'_> {
+    const TRANSFER_DISCRIMINATOR: u8 = 3;
+
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas = [
+            AccountMeta::writable(self.from.key()),
+            AccountMeta::writable(self.to.key()),
+            AccountMeta::readonly_signer(self.authority.key()),
+        ];
+
+        let mut instruction_data = [0u8; 9];
+        instruction_data[0] = Self::TRANSFER_DISCRIMINATOR;
+        instruction_data[1..9].copy_from_slice(&self.amount.to_le_bytes());
+
+        let instruction = Instruction {
+            program_id: self.token_program.key(),
+            accounts: &account_metas,
+            data: &instruction_data,
+        };
+
+        invoke_signed(&instruction, &[self.from, self.to, self.authority], signers)
+    }
+}
+
+/// Token `CloseAccount`, sent to whichever token program owns the account.
+pub struct TokenCloseAccount<'a> {
+    pub account: &'a AccountInfo,
+    pub destination: &'a AccountInfo,
+    pub authority: &'a AccountInfo,
+    pub token_program: &'a AccountInfo,
+}
+
+impl TokenCloseAccount<'_> {
+    const CLOSE_ACCOUNT_DISCRIMINATOR: u8 = 9;
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas = [
+            AccountMeta::writable(self.account.key()),
+            AccountMeta::writable(self.destination.key()),
+            AccountMeta::readonly_signer(self.authority.key()),
+        ];
+
+        let instruction = Instruction {
+            program_id: self.token_program.key(),
+            accounts: &account_metas,
+            data: &[Self::CLOSE_ACCOUNT_DISCRIMINATOR],
+        };
+
+        invoke_signed(
+            &instruction,
+            &[self.account, self.destination, self.authority],
+            signers,
+        )
+    }
+}
+
+pub struct Vault;
+
+impl Vault {
+    pub fn required_amount(amounts: &[u64]) -> Result<u64, ProgramError> {
+        amounts.iter().try_fold(0u64, |total, amount| {
+            total
+                .checked_add(*amount)
+                .ok_or(ProgramError::ArithmeticOverflow)
+        })
+    }
+
+    pub fn check_funded(vault: &AccountInfo, amounts: &[u64]) -> Result<(), ProgramError> {
+        let required_amount = Self::required_amount(amounts)?;
+
+        if Token::amount(vault)? < required_amount {
+            return Err(PinocchioError::VaultUnderfunded.into());
+        }
+
+        Ok(())
+    }
+}
+
+/// Signing configuration for moving tokens out of a schedule's vault, copied out of the
+/// schedule so no borrow of its data is held across the transfer CPI.
+pub struct VaultSigner {
+    schedule: Pubkey,
+    seed: [u8; 8],
+    bump: [u8; 1],
+    vault_authority: Option<Pubkey>,
+}
+
+impl VaultSigner {
+    pub fn new(schedule_key: &Pubkey, schedule: &VestSchedule) -> Self {
+        if schedule.has_vault_authority() {
+            Self {
+                schedule: *schedule_key,
+                seed: schedule.seed().to_le_bytes(),
+                bump: [schedule.vault_authority_bump()],
+                vault_authority: Some(*schedule.vault_authority()),
+            }
+        } else {
+            Self {
+                schedule: *schedule_key,
+                seed: schedule.seed().to_le_bytes(),
+                bump: [schedule.bump()],
+                vault_authority: None,
+            }
+        }
+    }
+
+    /// Picks the account that owns the vault: the schedule itself, or the separate vault
+    /// authority which must then be passed among `remaining`.
+    pub fn authority<'a>(
+        &self,
+        schedule: &'a AccountInfo,
+        remaining: &'a [AccountInfo],
+    ) -> Result<&'a AccountInfo, ProgramError> {
+        match self.vault_authority {
+            Some(vault_authority) => remaining
+                .iter()
+                .find(|account| account.key() == &vault_authority)
+                .ok_or(ProgramError::NotEnoughAccountKeys),
+            None => Ok(schedule),
+        }
+    }
+
+    pub fn transfer(
+        &self,
+        vault: &AccountInfo,
+        to: &AccountInfo,
+        authority: &AccountInfo,
+        token_program: &AccountInfo,
+        amount: u64,
+    ) -> ProgramResult {
+        let seeds = self.seeds();
+
+        TokenTransfer {
+            from: vault,
+            to,
+            authority,
+            token_program,
+            amount,
+        }
+        .invoke_signed(&[Signer::from(&seeds)])
+    }
+
+    /// Closes the (empty) vault, sending its rent lamports to `destination`.
+    pub fn close(
+        &self,
+        vault: &AccountInfo,
+        destination: &AccountInfo,
+        authority: &AccountInfo,
+        token_program: &AccountInfo,
+    ) -> ProgramResult {
+        let seeds = self.seeds();
+
+        TokenCloseAccount {
+            account: vault,
+            destination,
+            authority,
+            token_program,
+        }
+        .invoke_signed(&[Signer::from(&seeds)])
+    }
+
+    fn seeds(&self) -> [Seed<'_>; 3] {
+        match self.vault_authority {
+            Some(_) => [
+                Seed::from(VAULT_AUTHORITY_SEED),
+                Seed::from(self.schedule.as_ref()),
+                Seed::from(&self.bump),
+            ],
+            None => [
+                Seed::from(VEST_SCHEDULE_SEED),
+                Seed::from(&self.seed),
+                Seed::from(&self.bump),
+            ],
+        }
+    }
 }
 
 pub struct AssociatedToken;
@@ -165,6 +547,10 @@ impl AssociatedToken {
     ) -> Result<(), ProgramError> {
         Token::check(account)?;
 
+        if !account.is_owned_by(&token_program) {
+            return Err(PinocchioError::InvalidOwner.into());
+        }
+
         let (expected_ata, _bump) = find_program_address(
             &[authority.as_ref(), token_program.as_ref(), mint.as_ref()],
             &pinocchio_associated_token_account::ID,
@@ -208,9 +594,24 @@ impl AssociatedToken {
         system_program: &AccountInfo,
         token_program: &AccountInfo,
     ) -> ProgramResult {
-        match Self::check(account, *payer.key(), *mint.key(), *token_program.key()) {
+        match Self::check(account, *owner.key(), *mint.key(), *token_program.key()) {
             Ok(_) => Ok(()),
             Err(_) => Self::init(account, mint, payer, owner, system_program, token_program),
         }
     }
 }
+
+/// Activity records for explorers and log-scraping indexers, emitted as `Program data:`
+/// lines: `["add", participant, allocated]`, with the participant as raw key bytes and the
+/// amount as a little-endian u64. Claims are covered by the success record `Claim` emits.
+pub struct ActivityLog;
+
+impl ActivityLog {
+    pub fn add(participant: &Pubkey, allocated_amount: u64) {
+        sol_log_data(&[
+            b"add",
+            participant.as_ref(),
+            &allocated_amount.to_le_bytes(),
+        ]);
+    }
+}
@@ -1,24 +1,36 @@
 use pinocchio::{
-    ProgramResult, account_info::AccountInfo, instruction::Seed, program_error::ProgramError, sysvars::{Sysvar, clock::Clock}
+    ProgramResult, account_info::AccountInfo, instruction::Seed, program_error::ProgramError, pubkey::{find_program_address, Pubkey}
 };
 
-use crate::{AssociatedToken, Mint, PinocchioError, ProgramAccount, SignerAccount, VestSchedule};
+use crate::{
+    current_unix_timestamp, AssociatedToken, Mint, PinocchioError, ProgramAccount,
+    ScheduleRegistry, SignerAccount, VestGeneration, VestSchedule, VAULT_AUTHORITY_SEED,
+    VEST_GENERATION_SEED, VEST_REGISTRY_SEED, VEST_SCHEDULE_SEED,
+};
 
 pub struct InitializeAccounts<'a> {
     pub initializer: &'a AccountInfo,
     pub vest_schedule: &'a AccountInfo,
     pub token_mint: &'a AccountInfo,
+    /// ATA of the schedule, or of the vault authority when requested. Created empty if
+    /// missing and recorded on the schedule as its vault.
     pub vault: &'a AccountInfo,
     pub system_program: &'a AccountInfo,
     pub token_program: &'a AccountInfo,
+    /// Creates the vault ATA.
     pub ata_program: &'a AccountInfo,
+    /// `["vest_gen", seed]` counter, created on the seed's first initialization.
+    pub generation_marker: &'a AccountInfo,
+    /// The vault authority PDA when requested, then optionally the initializer's
+    /// `["vest_registry", authority]` registry to record the new schedule in.
+    pub remaining: &'a [AccountInfo],
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for InitializeAccounts<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [initializer, vest_schedule, token_mint, vault, system_program, token_program, ata_program] =
+        let [initializer, vest_schedule, token_mint, vault, system_program, token_program, ata_program, generation_marker, remaining @ ..] =
             accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
@@ -29,15 +41,12 @@ impl<'a> TryFrom<&'a [AccountInfo]> for InitializeAccounts<'a> {
         ProgramAccount::check_token_program(token_program)?;
         ProgramAccount::check_ata_program(ata_program)?;
         Mint::check(token_mint)?;
-        AssociatedToken::init_if_needed(
-            vault,
-            token_mint,
-            initializer,
-            vest_schedule,
-            system_program,
-            token_program,
-        )?;
+        Mint::check_supported_extensions(token_mint)?;
 
+        // The schedule records this program and every later instruction is held to it
+        if !token_mint.is_owned_by(token_program.key()) {
+            return Err(PinocchioError::InvalidTokenProgram.into());
+        }
 
         Ok(Self {
             initializer,
@@ -47,6 +56,8 @@ impl<'a> TryFrom<&'a [AccountInfo]> for InitializeAccounts<'a> {
             system_program,
             token_program,
             ata_program,
+            generation_marker,
+            remaining,
         })
     }
 }
@@ -59,12 +70,43 @@ pub struct InitializeInstructionData {
     pub total_duration: u64,
     pub step_duration: u64,
     pub bump: u8,
+    pub cosigner: Pubkey,
+    pub cosign_threshold: u64,
+    pub claim_window: u64,
+    pub use_vault_authority: bool,
+    pub vault_authority_bump: u8,
+    pub fee_recipient: Pubkey,
+    pub claim_fee_bps: u16,
+    pub allow_add_after_start: bool,
+    pub vesting_mode: u8,
+    pub cliff_unlock_bps: u64,
+    pub revoke_destination: Pubkey,
+    pub pause_freezes_accrual: bool,
+    /// Zero leaves the number of participants unlimited.
+    pub max_participants: u64,
+    /// Zero lets any non-zero amount be claimed.
+    pub min_claim_amount: u64,
+    pub allow_escrow_destination: bool,
+    /// Zero-padded UTF-8; all zeroes for no label.
+    pub label: [u8; VestSchedule::LABEL_LEN],
+    /// Zero for no cooldown between a participant's claims.
+    pub claim_cooldown: u64,
+    pub cooldown_exempts_participant: bool,
 }
 
 impl TryFrom<&[u8]> for InitializeInstructionData {
     type Error = ProgramError;
 
     fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        let current_timestamp = current_unix_timestamp()?;
+        Self::parse(data, current_timestamp)
+    }
+}
+
+impl InitializeInstructionData {
+    /// Decodes and validates the instruction data against `current_timestamp`. Kept free of
+    /// sysvar access so the validation rules can be exercised off-chain.
+    pub fn parse(data: &[u8], current_timestamp: u64) -> Result<Self, ProgramError> {
         if data.len() != core::mem::size_of::<InitializeInstructionData>() {
             return Err(ProgramError::InvalidInstructionData);
         }
@@ -77,28 +119,116 @@ impl TryFrom<&[u8]> for InitializeInstructionData {
             u64::from_le_bytes(data[32..40].try_into().unwrap()),
             u8::from_le_bytes(data[40..41].try_into().unwrap()),
         );
+        let cosigner: Pubkey = data[41..73].try_into().unwrap();
+        let cosign_threshold = u64::from_le_bytes(data[73..81].try_into().unwrap());
+        let claim_window = u64::from_le_bytes(data[81..89].try_into().unwrap());
+        let use_vault_authority = match data[89] {
+            0 => false,
+            1 => true,
+            _ => return Err(ProgramError::InvalidInstructionData),
+        };
+        let vault_authority_bump = data[90];
+        let fee_recipient: Pubkey = data[91..123].try_into().unwrap();
+        let claim_fee_bps = u16::from_le_bytes(data[123..125].try_into().unwrap());
+        let allow_add_after_start = match data[125] {
+            0 => false,
+            1 => true,
+            _ => return Err(ProgramError::InvalidInstructionData),
+        };
+        let vesting_mode = match data[126] {
+            mode @ (VestSchedule::VESTING_MODE_STEPPED
+            | VestSchedule::VESTING_MODE_LINEAR
+            | VestSchedule::VESTING_MODE_BACKLOADED
+            | VestSchedule::VESTING_MODE_CLIFF) => mode,
+            _ => return Err(ProgramError::InvalidInstructionData),
+        };
+        // Cliff-only schedules end with the cliff, whatever total and step were passed
+        let (total_duration, step_duration) = if vesting_mode == VestSchedule::VESTING_MODE_CLIFF {
+            (cliff_duration, cliff_duration)
+        } else {
+            (total_duration, step_duration)
+        };
+        let cliff_unlock_bps = u64::from_le_bytes(data[127..135].try_into().unwrap());
+        let revoke_destination: Pubkey = data[135..167].try_into().unwrap();
+        let pause_freezes_accrual = match data[167] {
+            0 => false,
+            1 => true,
+            _ => return Err(ProgramError::InvalidInstructionData),
+        };
+        let max_participants = u64::from_le_bytes(data[168..176].try_into().unwrap());
+        let min_claim_amount = u64::from_le_bytes(data[176..184].try_into().unwrap());
+        let allow_escrow_destination = match data[184] {
+            0 => false,
+            1 => true,
+            _ => return Err(ProgramError::InvalidInstructionData),
+        };
+        let label: [u8; VestSchedule::LABEL_LEN] = data[185..201].try_into().unwrap();
+        let claim_cooldown = u64::from_le_bytes(data[201..209].try_into().unwrap());
+        let cooldown_exempts_participant = match data[209] {
+            0 => false,
+            1 => true,
+            _ => return Err(ProgramError::InvalidInstructionData),
+        };
 
         if seed == 0 {
             return Err(PinocchioError::InvalidSeed.into());
         }
 
-        let current_timestamp = Clock::get()?.unix_timestamp as u64;
         if start_timestamp < current_timestamp {
             return Err(PinocchioError::StartTimestampInPast.into());
         }
 
-        if cliff_duration >= total_duration
-            || step_duration >= total_duration
-            || cliff_duration == 0
-            || step_duration == 0
-        {
+        if vesting_mode == VestSchedule::VESTING_MODE_CLIFF {
+            // Without a cliff there would be nothing to wait for
+            if cliff_duration == 0 {
+                return Err(PinocchioError::InvalidDurations.into());
+            }
+        } else {
+            // A zero cliff is allowed: vesting then begins at the start timestamp
+            if cliff_duration >= total_duration || step_duration == 0 {
+                return Err(PinocchioError::InvalidDurations.into());
+            }
+
+            // At least one whole step must fit after the cliff; a step spanning exactly that
+            // window is a single unlock at the end
+            if step_duration > total_duration - cliff_duration {
+                return Err(PinocchioError::StepExceedsVestingPeriod.into());
+            }
+        }
+
+        // Every unlock timestamp is computed as start + offset, so the end must fit in a u64
+        if start_timestamp.checked_add(total_duration).is_none() {
             return Err(PinocchioError::InvalidDurations.into());
         }
 
-        if (total_duration - cliff_duration) % step_duration != 0 {
+        // Linear and cliff-only schedules never look at the step duration, so it need not
+        // tile the period
+        if vesting_mode != VestSchedule::VESTING_MODE_LINEAR
+            && vesting_mode != VestSchedule::VESTING_MODE_CLIFF
+            && !(total_duration - cliff_duration).is_multiple_of(step_duration)
+        {
             return Err(PinocchioError::InvalidStepDuration.into());
         }
 
+        if cosign_threshold != 0 && cosigner == Pubkey::default() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        if cliff_unlock_bps > VestSchedule::MAX_CLIFF_UNLOCK_BPS {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        if claim_fee_bps > VestSchedule::MAX_CLAIM_FEE_BPS
+            || (claim_fee_bps != 0 && fee_recipient == Pubkey::default())
+        {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        // Padding zeroes are valid UTF-8 too, so a truncated multi-byte character still fails
+        if core::str::from_utf8(&label).is_err() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
         Ok(InitializeInstructionData {
             seed,
             start_timestamp,
@@ -106,6 +236,24 @@ impl TryFrom<&[u8]> for InitializeInstructionData {
             total_duration,
             step_duration,
             bump,
+            cosigner,
+            cosign_threshold,
+            claim_window,
+            use_vault_authority,
+            vault_authority_bump,
+            fee_recipient,
+            claim_fee_bps,
+            allow_add_after_start,
+            vesting_mode,
+            cliff_unlock_bps,
+            revoke_destination,
+            pause_freezes_accrual,
+            max_participants,
+            min_claim_amount,
+            allow_escrow_destination,
+            label,
+            claim_cooldown,
+            cooldown_exempts_participant,
         })
     }
 }
@@ -113,6 +261,9 @@ impl TryFrom<&[u8]> for InitializeInstructionData {
 pub struct Initialize<'a> {
     pub accounts: InitializeAccounts<'a>,
     pub instruction_data: InitializeInstructionData,
+    pub generation_bump: u8,
+    /// Set when a schedule registry was passed.
+    pub registry_bump: Option<u8>,
 }
 
 impl<'a> TryFrom<(&[u8], &'a [AccountInfo])> for Initialize<'a> {
@@ -125,14 +276,56 @@ impl<'a> TryFrom<(&[u8], &'a [AccountInfo])> for Initialize<'a> {
         let seed_binding = instruction_data.seed.to_le_bytes();
 
         ProgramAccount::verify(
-            &[Seed::from(b"vest_schedule"), Seed::from(&seed_binding)],
+            &[Seed::from(VEST_SCHEDULE_SEED), Seed::from(&seed_binding)],
             accounts.vest_schedule,
             instruction_data.bump,
         )?;
 
+        let (generation_marker, generation_bump) =
+            find_program_address(&[VEST_GENERATION_SEED, &seed_binding], &crate::ID);
+        if accounts.generation_marker.key() != &generation_marker {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if instruction_data.use_vault_authority {
+            let vault_authority = accounts
+                .remaining
+                .first()
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+            ProgramAccount::verify(
+                &[
+                    Seed::from(VAULT_AUTHORITY_SEED),
+                    Seed::from(accounts.vest_schedule.key().as_ref()),
+                ],
+                vault_authority,
+                instruction_data.vault_authority_bump,
+            )?;
+        }
+
+        let registry = accounts
+            .remaining
+            .get(instruction_data.use_vault_authority as usize);
+
+        let registry_bump = match registry {
+            Some(registry) => {
+                let (expected_registry, registry_bump) = find_program_address(
+                    &[VEST_REGISTRY_SEED, accounts.initializer.key().as_ref()],
+                    &crate::ID,
+                );
+                if registry.key() != &expected_registry {
+                    return Err(ProgramError::InvalidAccountData);
+                }
+                Some(registry_bump)
+            }
+            None => None,
+        };
+
         Ok(Self {
             accounts,
             instruction_data,
+            generation_bump,
+            registry_bump,
         })
     }
 }
@@ -140,15 +333,40 @@ impl<'a> TryFrom<(&[u8], &'a [AccountInfo])> for Initialize<'a> {
 impl<'a> Initialize<'a> {
     pub const DISCRIMINATOR: &'a u8 = &0;
 
+    /// Wallet the vault ATA is created for: the vault authority PDA if requested, else the
+    /// schedule.
+    fn vault_owner(&self) -> &'a AccountInfo {
+        if self.instruction_data.use_vault_authority {
+            &self.accounts.remaining[0]
+        } else {
+            self.accounts.vest_schedule
+        }
+    }
+
     pub fn process(&self) -> ProgramResult {
         let seed_binding = self.instruction_data.seed.to_le_bytes();
         let binding = [self.instruction_data.bump];
         let vest_schedule_seed = [
-            Seed::from(b"vest_schedule"),
+            Seed::from(VEST_SCHEDULE_SEED),
             Seed::from(&seed_binding),
             Seed::from(&binding),
         ];
 
+        if ProgramAccount::is_initialized::<VestSchedule>(self.accounts.vest_schedule)? {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        let generation = self.next_generation()?;
+
+        AssociatedToken::init_if_needed(
+            self.accounts.vault,
+            self.accounts.token_mint,
+            self.accounts.initializer,
+            self.vault_owner(),
+            self.accounts.system_program,
+            self.accounts.token_program,
+        )?;
+
         ProgramAccount::init::<VestSchedule>(
             self.accounts.initializer,
             self.accounts.vest_schedule,
@@ -170,7 +388,121 @@ impl<'a> Initialize<'a> {
             self.instruction_data.step_duration,
             self.instruction_data.bump,
         );
+        vest_schedule.set_cosign(
+            self.instruction_data.cosigner,
+            self.instruction_data.cosign_threshold,
+        );
+        vest_schedule.set_claim_window(self.instruction_data.claim_window);
+        vest_schedule.set_token_program(*self.accounts.token_program.key());
+        vest_schedule.set_allow_add_after_start(self.instruction_data.allow_add_after_start);
+        vest_schedule.set_vesting_mode(self.instruction_data.vesting_mode);
+        vest_schedule.set_cliff_unlock_bps(self.instruction_data.cliff_unlock_bps);
+        vest_schedule.set_revoke_destination(self.instruction_data.revoke_destination);
+        vest_schedule.set_pause_freezes_accrual(self.instruction_data.pause_freezes_accrual);
+        vest_schedule.set_max_participants(self.instruction_data.max_participants);
+        vest_schedule.set_min_claim_amount(self.instruction_data.min_claim_amount);
+        vest_schedule.set_allow_escrow_destination(self.instruction_data.allow_escrow_destination);
+        vest_schedule.set_label(self.instruction_data.label);
+        vest_schedule.set_generation(generation);
+        vest_schedule.set_claim_cooldown(
+            self.instruction_data.claim_cooldown,
+            self.instruction_data.cooldown_exempts_participant,
+        );
+        vest_schedule.set_claim_fee(
+            self.instruction_data.fee_recipient,
+            self.instruction_data.claim_fee_bps,
+        );
+
+        if self.instruction_data.use_vault_authority {
+            vest_schedule.set_vault_authority(
+                *self.vault_owner().key(),
+                self.instruction_data.vault_authority_bump,
+            );
+        }
+
+        if let Some(registry_bump) = self.registry_bump {
+            self.register_schedule(registry_bump)?;
+        }
 
         Ok(())
     }
+
+    /// Appends the new schedule to the initializer's registry, creating the registry on
+    /// its first schedule. The initializer pays the rent for the extra entry.
+    fn register_schedule(&self, registry_bump: u8) -> ProgramResult {
+        let registry = &self.accounts.remaining[self.instruction_data.use_vault_authority as usize];
+        let initializer = self.accounts.initializer;
+
+        if registry.lamports() == 0 {
+            let bump_binding = [registry_bump];
+            let registry_seeds = [
+                Seed::from(VEST_REGISTRY_SEED),
+                Seed::from(initializer.key().as_ref()),
+                Seed::from(&bump_binding),
+            ];
+
+            ProgramAccount::init::<ScheduleRegistry>(
+                initializer,
+                registry,
+                &registry_seeds,
+                ScheduleRegistry::HEADER_LEN,
+            )?;
+
+            let mut registry_data = registry.try_borrow_mut_data()?;
+            ScheduleRegistry::load_mut(&mut registry_data)?
+                .set_inner(*initializer.key(), registry_bump);
+        } else if !registry.is_owned_by(&crate::ID)
+            || registry.try_borrow_data()?.first() != Some(&ScheduleRegistry::DISCRIMINATOR)
+        {
+            return Err(PinocchioError::InvalidDiscriminator.into());
+        }
+
+        let count = {
+            let registry_data = registry.try_borrow_data()?;
+            ScheduleRegistry::load(&registry_data)?.count()
+        };
+        let new_count = count
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        ProgramAccount::grow(initializer, registry, ScheduleRegistry::len_for(new_count))?;
+
+        let mut registry_data = registry.try_borrow_mut_data()?;
+        ScheduleRegistry::register(&mut registry_data, self.accounts.vest_schedule.key())
+    }
+
+    /// Creates the seed's generation marker on first use, otherwise counts one more
+    /// generation, so a schedule recreated after `CloseSchedule` is distinguishable. Returns
+    /// the new schedule's generation.
+    fn next_generation(&self) -> Result<u32, ProgramError> {
+        let marker = self.accounts.generation_marker;
+
+        if ProgramAccount::is_initialized::<VestGeneration>(marker)? {
+            let mut marker_data = marker.try_borrow_mut_data()?;
+            let generation = VestGeneration::load_mut(&mut marker_data)?;
+            generation.increment()?;
+            return Ok(generation.generation());
+        }
+
+        let seed_binding = self.instruction_data.seed.to_le_bytes();
+        let bump_binding = [self.generation_bump];
+        let marker_seeds = [
+            Seed::from(VEST_GENERATION_SEED),
+            Seed::from(&seed_binding),
+            Seed::from(&bump_binding),
+        ];
+
+        ProgramAccount::init::<VestGeneration>(
+            self.accounts.initializer,
+            marker,
+            &marker_seeds,
+            VestGeneration::LEN,
+        )?;
+
+        let mut marker_data = marker.try_borrow_mut_data()?;
+        let generation = VestGeneration::load_mut(&mut marker_data)?;
+        generation.set_inner(self.instruction_data.seed, self.generation_bump);
+
+        Ok(generation.generation())
+    }
 }
@@ -0,0 +1,128 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError,
+    pubkey::find_program_address,
+};
+
+use crate::{Config, ProgramAccount, SignerAccount, BPF_LOADER_UPGRADEABLE_ID, CONFIG_SEED};
+
+pub struct InitializeConfigAccounts<'a> {
+    pub admin: &'a AccountInfo,
+    pub config: &'a AccountInfo,
+    pub program_data: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for InitializeConfigAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [admin, config, program_data, system_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(admin)?;
+        ProgramAccount::check_system_program(system_program)?;
+
+        Ok(Self {
+            admin,
+            config,
+            program_data,
+            system_program,
+        })
+    }
+}
+
+impl InitializeConfigAccounts<'_> {
+    /// The config admin can switch claims off for every schedule, so only the program's
+    /// upgrade authority, as recorded in its ProgramData account, may claim the role.
+    fn check_upgrade_authority(&self) -> Result<(), ProgramError> {
+        let (program_data_address, _) =
+            find_program_address(&[crate::ID.as_ref()], &BPF_LOADER_UPGRADEABLE_ID);
+        if self.program_data.key() != &program_data_address
+            || !self.program_data.is_owned_by(&BPF_LOADER_UPGRADEABLE_ID)
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // UpgradeableLoaderState::ProgramData: u32 tag (3), u64 slot, Option<Pubkey> authority
+        let program_data = self.program_data.try_borrow_data()?;
+        match program_data.get(..45) {
+            Some([3, 0, 0, 0, _, _, _, _, _, _, _, _, 1, authority @ ..])
+                if authority == self.admin.key().as_ref() =>
+            {
+                Ok(())
+            }
+            _ => Err(ProgramError::IllegalOwner),
+        }
+    }
+}
+
+#[repr(C, packed)]
+pub struct InitializeConfigInstructionData {
+    pub bump: u8,
+}
+
+impl TryFrom<&[u8]> for InitializeConfigInstructionData {
+    type Error = ProgramError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        let [bump] = data else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+
+        Ok(Self { bump: *bump })
+    }
+}
+
+/// Creates the program-wide config with the signer as admin and claims enabled. The signer
+/// must be the program's upgrade authority; the role can be handed on with `SetConfigAdmin`.
+/// There is a single config per program, so this should run right after deployment.
+pub struct InitializeConfig<'a> {
+    pub accounts: InitializeConfigAccounts<'a>,
+    pub instruction_data: InitializeConfigInstructionData,
+}
+
+impl<'a> TryFrom<(&[u8], &'a [AccountInfo])> for InitializeConfig<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&[u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = InitializeConfigAccounts::try_from(accounts)?;
+        let instruction_data = InitializeConfigInstructionData::try_from(data)?;
+
+        accounts.check_upgrade_authority()?;
+
+        ProgramAccount::verify(
+            &[Seed::from(CONFIG_SEED)],
+            accounts.config,
+            instruction_data.bump,
+        )?;
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+        })
+    }
+}
+
+impl<'a> InitializeConfig<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &7;
+
+    pub fn process(&self) -> Result<(), ProgramError> {
+        let bump_binding = [self.instruction_data.bump];
+        let config_seeds = [Seed::from(CONFIG_SEED), Seed::from(&bump_binding)];
+
+        ProgramAccount::init::<Config>(
+            self.accounts.admin,
+            self.accounts.config,
+            &config_seeds,
+            Config::LEN,
+        )?;
+
+        let mut config_data = self.accounts.config.try_borrow_mut_data()?;
+        let config = Config::load_mut(&mut config_data)?;
+
+        config.set_inner(*self.accounts.admin.key(), self.instruction_data.bump);
+
+        Ok(())
+    }
+}
@@ -1,9 +1,71 @@
 pub mod add_participant;
+pub mod batch_add_participant;
 pub mod claim;
+pub mod claim_all;
+pub mod claim_to;
+pub mod claim_to_escrow;
+pub mod close_participant;
+pub mod close_schedule;
+pub mod crank_claim;
+pub mod ensure_vault;
+pub mod forfeit_expired;
+pub mod freeze_claims;
+pub mod get_claimable;
 pub mod helpers;
 pub mod initialize;
+pub mod initialize_config;
+pub mod participant_info;
+pub mod pause;
+pub mod precreate_atas;
+pub mod query_schedule;
+pub mod query_vault_health;
+pub mod reassign_participant;
+pub mod reduce_allocation;
+pub mod resume;
+pub mod revoke;
+pub mod set_claim_delegate;
+pub mod set_claimed;
+pub mod set_claims_enabled;
+pub mod set_config_admin;
+pub mod sweep_surplus;
+pub mod top_up;
+pub mod transfer_authority;
+pub mod unfreeze_claims;
+pub mod update_start;
+pub mod verify_pdas;
 
 pub use add_participant::*;
+pub use batch_add_participant::*;
 pub use claim::*;
+pub use claim_all::*;
+pub use claim_to::*;
+pub use claim_to_escrow::*;
+pub use close_participant::*;
+pub use close_schedule::*;
+pub use crank_claim::*;
+pub use ensure_vault::*;
+pub use forfeit_expired::*;
+pub use freeze_claims::*;
+pub use get_claimable::*;
 pub use helpers::*;
 pub use initialize::*;
+pub use initialize_config::*;
+pub use participant_info::*;
+pub use pause::*;
+pub use precreate_atas::*;
+pub use query_schedule::*;
+pub use query_vault_health::*;
+pub use reassign_participant::*;
+pub use reduce_allocation::*;
+pub use resume::*;
+pub use revoke::*;
+pub use set_claim_delegate::*;
+pub use set_claimed::*;
+pub use set_claims_enabled::*;
+pub use set_config_admin::*;
+pub use sweep_surplus::*;
+pub use top_up::*;
+pub use transfer_authority::*;
+pub use unfreeze_claims::*;
+pub use update_start::*;
+pub use verify_pdas::*;
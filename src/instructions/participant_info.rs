@@ -0,0 +1,54 @@
+use pinocchio::{account_info::AccountInfo, cpi::set_return_data, program_error::ProgramError};
+
+use crate::{current_unix_timestamp, GetClaimable, VestParticipant, VestSchedule};
+
+/// Read-only: `GetClaimable` with the full breakdown for dashboards. Takes the same accounts
+/// and returns `[allocated: u64, claimed: u64, claimable: u64, locked: u64]` (little-endian)
+/// as return data, where `locked` is what is neither claimed, forfeited nor claimable yet.
+pub struct ParticipantInfo<'a> {
+    pub get_claimable: GetClaimable<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ParticipantInfo<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        Ok(Self {
+            get_claimable: GetClaimable::try_from(accounts)?,
+        })
+    }
+}
+
+impl<'a> ParticipantInfo<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &21;
+
+    pub fn process(&self) -> Result<(), ProgramError> {
+        let accounts = &self.get_claimable.accounts;
+
+        let vest_schedule_data = accounts.schedule.try_borrow_data()?;
+        let vest_schedule = VestSchedule::load(&vest_schedule_data)?;
+
+        let participant_state_data = accounts.participant_state.try_borrow_data()?;
+        let participant_state = VestParticipant::load(&participant_state_data)?;
+
+        let current_timestamp = current_unix_timestamp()?;
+
+        let allocated_amount = participant_state.allocated_amount();
+        let claimed_amount = participant_state.claimed_amount();
+        let claimable_amount = participant_state.claimable_now(vest_schedule, current_timestamp);
+        let locked_amount = allocated_amount.saturating_sub(
+            claimed_amount
+                .saturating_add(participant_state.forfeited_amount())
+                .saturating_add(claimable_amount),
+        );
+
+        let mut return_data = [0u8; 32];
+        return_data[0..8].copy_from_slice(&allocated_amount.to_le_bytes());
+        return_data[8..16].copy_from_slice(&claimed_amount.to_le_bytes());
+        return_data[16..24].copy_from_slice(&claimable_amount.to_le_bytes());
+        return_data[24..32].copy_from_slice(&locked_amount.to_le_bytes());
+        set_return_data(&return_data);
+
+        Ok(())
+    }
+}
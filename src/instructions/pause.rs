@@ -0,0 +1,68 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{current_unix_timestamp, PinocchioError, ProgramAccount, SignerAccount, VestSchedule};
+
+pub struct PauseAccounts<'a> {
+    pub authority: &'a AccountInfo,
+    pub schedule: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for PauseAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, schedule] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(authority)?;
+        ProgramAccount::check::<VestSchedule>(schedule)?;
+
+        {
+            let vest_schedule_data = schedule.try_borrow_data()?;
+            let vest_schedule = VestSchedule::load(&vest_schedule_data)?;
+
+            if authority.key() != vest_schedule.authority() {
+                return Err(ProgramError::IllegalOwner);
+            }
+        }
+
+        Ok(Self {
+            authority,
+            schedule,
+        })
+    }
+}
+
+/// Authority-only. Pauses the schedule: claims are held until `Resume`, or, when the
+/// schedule freezes accrual on pause, vesting itself stops for the length of the pause.
+pub struct Pause<'a> {
+    pub accounts: PauseAccounts<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for Pause<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let accounts = PauseAccounts::try_from(accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a> Pause<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &13;
+
+    pub fn process(&self) -> Result<(), ProgramError> {
+        let mut vest_schedule_data = self.accounts.schedule.try_borrow_mut_data()?;
+        let vest_schedule = VestSchedule::load_mut(&mut vest_schedule_data)?;
+
+        if vest_schedule.is_paused() {
+            return Err(PinocchioError::SchedulePaused.into());
+        }
+
+        vest_schedule.set_paused(true, current_unix_timestamp()?);
+
+        Ok(())
+    }
+}
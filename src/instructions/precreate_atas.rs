@@ -0,0 +1,101 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{AssociatedToken, Mint, PinocchioError, ProgramAccount, SignerAccount, VestSchedule};
+
+pub struct PrecreateAtasAccounts<'a> {
+    pub authority: &'a AccountInfo,
+    pub schedule: &'a AccountInfo,
+    pub token_mint: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub token_program: &'a AccountInfo,
+    pub ata_program: &'a AccountInfo,
+    /// `(participant, participant_ata)` pairs.
+    pub participants: &'a [AccountInfo],
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for PrecreateAtasAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, schedule, token_mint, system_program, token_program, ata_program, participants @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if participants.is_empty() || participants.len() % 2 != 0 {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        SignerAccount::check(authority)?;
+        ProgramAccount::check_system_program(system_program)?;
+        ProgramAccount::check_token_program(token_program)?;
+        ProgramAccount::check_ata_program(ata_program)?;
+        ProgramAccount::check::<VestSchedule>(schedule)?;
+        Mint::check(token_mint)?;
+
+        Ok(Self {
+            authority,
+            schedule,
+            token_mint,
+            system_program,
+            token_program,
+            ata_program,
+            participants,
+        })
+    }
+}
+
+/// Authority-paid. Creates the schedule-mint ATA of every participant passed in, so later
+/// claims don't have to. ATAs that already exist are skipped.
+pub struct PrecreateAtas<'a> {
+    pub accounts: PrecreateAtasAccounts<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for PrecreateAtas<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let accounts = PrecreateAtasAccounts::try_from(accounts)?;
+
+        {
+            let vest_schedule_data = accounts.schedule.try_borrow_data()?;
+            let vest_schedule = VestSchedule::load(&vest_schedule_data)?;
+
+            if accounts.authority.key() != vest_schedule.authority() {
+                return Err(ProgramError::IllegalOwner);
+            }
+
+            if accounts.token_mint.key() != vest_schedule.token_mint() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            if accounts.token_program.key() != vest_schedule.token_program() {
+                return Err(PinocchioError::InvalidTokenProgram.into());
+            }
+        }
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a> PrecreateAtas<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &19;
+
+    pub fn process(&self) -> Result<(), ProgramError> {
+        for pair in self.accounts.participants.chunks_exact(2) {
+            let (participant, participant_ata) = (&pair[0], &pair[1]);
+
+            AssociatedToken::init_if_needed(
+                participant_ata,
+                self.accounts.token_mint,
+                self.accounts.authority,
+                participant,
+                self.accounts.system_program,
+                self.accounts.token_program,
+            )?;
+        }
+
+        Ok(())
+    }
+}
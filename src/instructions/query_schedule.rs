@@ -0,0 +1,49 @@
+use pinocchio::{account_info::AccountInfo, cpi::set_return_data, program_error::ProgramError};
+
+use crate::{ProgramAccount, VestSchedule};
+
+pub struct QueryScheduleAccounts<'a> {
+    pub schedule: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for QueryScheduleAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [schedule] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        ProgramAccount::check::<VestSchedule>(schedule)?;
+
+        Ok(Self { schedule })
+    }
+}
+
+/// Read-only: returns the schedule's `VestScheduleSnapshot` as borsh-compatible return data.
+pub struct QuerySchedule<'a> {
+    pub accounts: QueryScheduleAccounts<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for QuerySchedule<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let accounts = QueryScheduleAccounts::try_from(accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a> QuerySchedule<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &9;
+
+    pub fn process(&self) -> Result<(), ProgramError> {
+        let vest_schedule_data = self.accounts.schedule.try_borrow_data()?;
+        let vest_schedule = VestSchedule::load(&vest_schedule_data)?;
+
+        set_return_data(&vest_schedule.snapshot().to_bytes());
+
+        Ok(())
+    }
+}
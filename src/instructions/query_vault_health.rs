@@ -0,0 +1,71 @@
+use pinocchio::{account_info::AccountInfo, cpi::set_return_data, program_error::ProgramError};
+
+use crate::{PinocchioError, ProgramAccount, Token, VestSchedule};
+
+pub struct QueryVaultHealthAccounts<'a> {
+    pub schedule: &'a AccountInfo,
+    pub vault: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for QueryVaultHealthAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [schedule, vault] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        ProgramAccount::check::<VestSchedule>(schedule)?;
+
+        Ok(Self { schedule, vault })
+    }
+}
+
+/// Read-only solvency check. Returns `[vault_amount: u64, outstanding_owed: u64, buffer: i64]`
+/// (little-endian) as return data, where `outstanding_owed` is what the schedule has
+/// allocated but not yet paid out of the vault and a negative `buffer` is the vault's
+/// shortfall.
+pub struct QueryVaultHealth<'a> {
+    pub accounts: QueryVaultHealthAccounts<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for QueryVaultHealth<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let accounts = QueryVaultHealthAccounts::try_from(accounts)?;
+
+        {
+            let vest_schedule_data = accounts.schedule.try_borrow_data()?;
+            let vest_schedule = VestSchedule::load(&vest_schedule_data)?;
+
+            if accounts.vault.key() != vest_schedule.vault() {
+                return Err(PinocchioError::VaultMismatch.into());
+            }
+        }
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a> QueryVaultHealth<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &12;
+
+    pub fn process(&self) -> Result<(), ProgramError> {
+        let vault_amount = Token::amount(self.accounts.vault)?;
+
+        let vest_schedule_data = self.accounts.schedule.try_borrow_data()?;
+        let outstanding_owed = VestSchedule::load(&vest_schedule_data)?.outstanding();
+
+        let buffer = (vault_amount as i128 - outstanding_owed as i128)
+            .clamp(i64::MIN as i128, i64::MAX as i128) as i64;
+
+        let mut return_data = [0u8; 24];
+        return_data[0..8].copy_from_slice(&vault_amount.to_le_bytes());
+        return_data[8..16].copy_from_slice(&outstanding_owed.to_le_bytes());
+        return_data[16..24].copy_from_slice(&buffer.to_le_bytes());
+        set_return_data(&return_data);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,161 @@
+use pinocchio::{account_info::AccountInfo, instruction::Seed, program_error::ProgramError};
+
+use crate::{
+    PinocchioError, ProgramAccount, SignerAccount, VestParticipant, VestSchedule,
+    VEST_PARTICIPANT_SEED,
+};
+
+pub struct ReassignParticipantAccounts<'a> {
+    pub authority: &'a AccountInfo,
+    pub participant_state: &'a AccountInfo,
+    pub new_participant: &'a AccountInfo,
+    pub new_participant_state: &'a AccountInfo,
+    pub schedule: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ReassignParticipantAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, participant_state, new_participant, new_participant_state, schedule, system_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(authority)?;
+        ProgramAccount::check_system_program(system_program)?;
+        ProgramAccount::check::<VestSchedule>(schedule)?;
+        ProgramAccount::check::<VestParticipant>(participant_state)?;
+
+        Ok(Self {
+            authority,
+            participant_state,
+            new_participant,
+            new_participant_state,
+            schedule,
+            system_program,
+        })
+    }
+}
+
+/// Moves a participant's entitlement to a replacement wallet, e.g. after the original key
+/// was lost. The old participant state is closed to the authority and a new one, keyed by
+/// the new wallet, takes over its allocation, claimed and forfeited amounts, fees, revoked
+/// flag, duration overrides and last claim time. The claim delegate is not carried over,
+/// since the new wallet didn't choose it. The vault and the schedule totals are untouched.
+pub struct ReassignParticipant<'a> {
+    pub accounts: ReassignParticipantAccounts<'a>,
+    /// Canonical bump of the new participant state PDA, derived on-chain.
+    pub new_participant_bump: u8,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ReassignParticipant<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let accounts = ReassignParticipantAccounts::try_from(accounts)?;
+
+        {
+            let vest_schedule_data = accounts.schedule.try_borrow_data()?;
+            let vest_schedule = VestSchedule::load(&vest_schedule_data)?;
+
+            let participant_state_data = accounts.participant_state.try_borrow_data()?;
+            let participant_state = VestParticipant::load(&participant_state_data)?;
+
+            if accounts.authority.key() != vest_schedule.authority() {
+                return Err(ProgramError::IllegalOwner);
+            }
+
+            if participant_state.schedule() != accounts.schedule.key() {
+                return Err(PinocchioError::WrongSchedule.into());
+            }
+
+            if participant_state.participant() == accounts.new_participant.key() {
+                return Err(ProgramError::InvalidArgument);
+            }
+        }
+
+        let new_participant_bump = ProgramAccount::find_bump(
+            &[
+                Seed::from(VEST_PARTICIPANT_SEED),
+                Seed::from(accounts.new_participant.key().as_ref()),
+                Seed::from(accounts.schedule.key().as_ref()),
+            ],
+            accounts.new_participant_state,
+        )?;
+
+        Ok(Self {
+            accounts,
+            new_participant_bump,
+        })
+    }
+}
+
+impl<'a> ReassignParticipant<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &26;
+
+    pub fn process(&self) -> Result<(), ProgramError> {
+        let (
+            allocated_amount,
+            claimed_amount,
+            forfeited_amount,
+            total_fees_paid,
+            is_revoked,
+            cliff_override,
+            total_override,
+            last_claim_ts,
+        ) = {
+            let participant_state_data = self.accounts.participant_state.try_borrow_data()?;
+            let participant_state = VestParticipant::load(&participant_state_data)?;
+
+            (
+                participant_state.allocated_amount(),
+                participant_state.claimed_amount(),
+                participant_state.forfeited_amount(),
+                participant_state.total_fees_paid(),
+                participant_state.is_revoked(),
+                participant_state.cliff_override(),
+                participant_state.total_override(),
+                participant_state.last_claim_ts(),
+            )
+        }; // Borrow dropped here
+
+        let bump_binding = [self.new_participant_bump];
+        let participant_seeds = [
+            Seed::from(VEST_PARTICIPANT_SEED),
+            Seed::from(self.accounts.new_participant.key().as_ref()),
+            Seed::from(self.accounts.schedule.key().as_ref()),
+            Seed::from(&bump_binding),
+        ];
+
+        ProgramAccount::init::<VestParticipant>(
+            self.accounts.authority,
+            self.accounts.new_participant_state,
+            &participant_seeds,
+            VestParticipant::LEN,
+        )?;
+
+        {
+            let mut participant_state_data =
+                self.accounts.new_participant_state.try_borrow_mut_data()?;
+            let participant_state = VestParticipant::load_mut(&mut participant_state_data)?;
+
+            participant_state.set_inner(
+                *self.accounts.new_participant.key(),
+                *self.accounts.schedule.key(),
+                allocated_amount,
+                claimed_amount,
+                self.new_participant_bump,
+            );
+            participant_state.set_forfeited_amount(forfeited_amount);
+            participant_state.set_total_fees_paid(total_fees_paid);
+            participant_state.set_revoked(is_revoked);
+            participant_state.set_duration_overrides(cliff_override, total_override);
+            participant_state.set_last_claim_ts(last_claim_ts);
+        }
+
+        ProgramAccount::close(self.accounts.participant_state, self.accounts.authority)
+    }
+}
@@ -0,0 +1,192 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{
+    current_unix_timestamp, AssociatedToken, Mint, PinocchioError, ProgramAccount, SignerAccount,
+    VaultSigner, VestParticipant, VestSchedule,
+};
+
+pub struct ReduceAllocationAccounts<'a> {
+    pub authority: &'a AccountInfo,
+    pub authority_ata: &'a AccountInfo,
+    pub vault: &'a AccountInfo,
+    pub participant_state: &'a AccountInfo,
+    pub schedule: &'a AccountInfo,
+    pub token_mint: &'a AccountInfo,
+    pub token_program: &'a AccountInfo,
+    /// The vault authority PDA, for schedules whose vault it owns.
+    pub remaining: &'a [AccountInfo],
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ReduceAllocationAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, authority_ata, vault, participant_state, schedule, token_mint, token_program, remaining @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(authority)?;
+        ProgramAccount::check_token_program(token_program)?;
+        ProgramAccount::check::<VestSchedule>(schedule)?;
+        ProgramAccount::check::<VestParticipant>(participant_state)?;
+        Mint::check(token_mint)?;
+
+        Ok(Self {
+            authority,
+            authority_ata,
+            vault,
+            participant_state,
+            schedule,
+            token_mint,
+            token_program,
+            remaining,
+        })
+    }
+}
+
+#[repr(C, packed)]
+pub struct ReduceAllocationInstructionData {
+    pub new_allocated_amount: u64,
+}
+
+impl TryFrom<&[u8]> for ReduceAllocationInstructionData {
+    type Error = ProgramError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        if data.len() != size_of::<u64>() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let new_allocated_amount = u64::from_le_bytes(data[0..8].try_into().unwrap());
+
+        // Removing a participant entirely is what `Revoke` is for
+        if new_allocated_amount == 0 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Ok(Self {
+            new_allocated_amount,
+        })
+    }
+}
+
+/// Authority-only counterpart of `TopUp`: lowers a participant's allocation to
+/// `new_allocated_amount` and refunds the difference from the vault to the authority's ATA.
+/// Only possible until the participant's cliff ends, while nothing of it has vested.
+pub struct ReduceAllocation<'a> {
+    pub accounts: ReduceAllocationAccounts<'a>,
+    pub instruction_data: ReduceAllocationInstructionData,
+}
+
+impl<'a> TryFrom<(&[u8], &'a [AccountInfo])> for ReduceAllocation<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&[u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = ReduceAllocationAccounts::try_from(accounts)?;
+        let instruction_data = ReduceAllocationInstructionData::try_from(data)?;
+
+        {
+            let vest_schedule_data = accounts.schedule.try_borrow_data()?;
+            let vest_schedule = VestSchedule::load(&vest_schedule_data)?;
+
+            let participant_state_data = accounts.participant_state.try_borrow_data()?;
+            let participant_state = VestParticipant::load(&participant_state_data)?;
+
+            if accounts.authority.key() != vest_schedule.authority() {
+                return Err(ProgramError::IllegalOwner);
+            }
+
+            if accounts.token_mint.key() != vest_schedule.token_mint() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            if accounts.token_program.key() != vest_schedule.token_program() {
+                return Err(PinocchioError::InvalidTokenProgram.into());
+            }
+
+            if accounts.vault.key() != vest_schedule.vault() {
+                return Err(PinocchioError::VaultMismatch.into());
+            }
+
+            if participant_state.schedule() != accounts.schedule.key() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            if participant_state.is_revoked() {
+                return Err(PinocchioError::VestingRevoked.into());
+            }
+
+            let current_timestamp = current_unix_timestamp()?;
+            if participant_state
+                .terms(vest_schedule)
+                .is_cliff_completed(current_timestamp)
+            {
+                return Err(PinocchioError::CannotAddParticipantsAfterCliff.into());
+            }
+
+            if instruction_data.new_allocated_amount >= participant_state.allocated_amount() {
+                return Err(ProgramError::InvalidInstructionData);
+            }
+
+            // `SetClaimed` may have recorded tokens as distributed even before the cliff
+            if instruction_data.new_allocated_amount
+                < participant_state
+                    .claimed_amount()
+                    .saturating_add(participant_state.forfeited_amount())
+            {
+                return Err(PinocchioError::ClaimExceedsAllocation.into());
+            }
+
+            AssociatedToken::check(
+                accounts.authority_ata,
+                *accounts.authority.key(),
+                *accounts.token_mint.key(),
+                *accounts.token_program.key(),
+            )?;
+        }
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+        })
+    }
+}
+
+impl<'a> ReduceAllocation<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &23;
+
+    pub fn process(&self) -> Result<(), ProgramError> {
+        let (refund_amount, vault_signer) = {
+            let mut vest_schedule_data = self.accounts.schedule.try_borrow_mut_data()?;
+            let vest_schedule = VestSchedule::load_mut(&mut vest_schedule_data)?;
+
+            let mut participant_state_data =
+                self.accounts.participant_state.try_borrow_mut_data()?;
+            let participant_state = VestParticipant::load_mut(&mut participant_state_data)?;
+
+            let refund_amount =
+                participant_state.allocated_amount() - self.instruction_data.new_allocated_amount;
+
+            participant_state.set_allocated_amount(self.instruction_data.new_allocated_amount);
+            vest_schedule.remove_allocation(refund_amount)?;
+
+            (
+                refund_amount,
+                VaultSigner::new(self.accounts.schedule.key(), vest_schedule),
+            )
+        }; // Both borrows dropped here
+
+        let vault_authority =
+            vault_signer.authority(self.accounts.schedule, self.accounts.remaining)?;
+
+        vault_signer.transfer(
+            self.accounts.vault,
+            self.accounts.authority_ata,
+            vault_authority,
+            self.accounts.token_program,
+            refund_amount,
+        )
+    }
+}
@@ -0,0 +1,37 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{current_unix_timestamp, PauseAccounts, PinocchioError, VestSchedule};
+
+/// Authority-only. Ends a pause; for schedules that freeze accrual the paused interval is
+/// added to `total_paused`, pushing every later unlock back by that much. Takes the same
+/// accounts as `Pause`.
+pub struct Resume<'a> {
+    pub accounts: PauseAccounts<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for Resume<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let accounts = PauseAccounts::try_from(accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a> Resume<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &14;
+
+    pub fn process(&self) -> Result<(), ProgramError> {
+        let mut vest_schedule_data = self.accounts.schedule.try_borrow_mut_data()?;
+        let vest_schedule = VestSchedule::load_mut(&mut vest_schedule_data)?;
+
+        if !vest_schedule.is_paused() {
+            return Err(PinocchioError::ScheduleNotPaused.into());
+        }
+
+        vest_schedule.set_paused(false, current_unix_timestamp()?);
+
+        Ok(())
+    }
+}
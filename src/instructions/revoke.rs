@@ -0,0 +1,182 @@
+use pinocchio::{account_info::AccountInfo, instruction::Seed, program_error::ProgramError};
+
+use crate::{
+    current_unix_timestamp, AssociatedToken, Mint, PinocchioError, ProgramAccount, SignerAccount,
+    Token, VaultSigner, VestParticipant, VestSchedule, VEST_PARTICIPANT_SEED,
+};
+
+pub struct RevokeAccounts<'a> {
+    pub authority: &'a AccountInfo,
+    pub schedule: &'a AccountInfo,
+    pub participant_state: &'a AccountInfo,
+    pub vault: &'a AccountInfo,
+    pub destination: &'a AccountInfo,
+    pub token_mint: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub token_program: &'a AccountInfo,
+    pub remaining: &'a [AccountInfo],
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for RevokeAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, schedule, participant_state, vault, destination, token_mint, system_program, token_program, remaining @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(authority)?;
+        ProgramAccount::check_system_program(system_program)?;
+        ProgramAccount::check_token_program(token_program)?;
+        ProgramAccount::check::<VestSchedule>(schedule)?;
+        ProgramAccount::check::<VestParticipant>(participant_state)?;
+        Mint::check(token_mint)?;
+
+        Ok(Self {
+            authority,
+            schedule,
+            participant_state,
+            vault,
+            destination,
+            token_mint,
+            system_program,
+            token_program,
+            remaining,
+        })
+    }
+}
+
+/// Cancels a participant's unvested allocation. Whatever has vested but is still unclaimed
+/// stays claimable by the participant; the rest goes to the schedule's revoke destination,
+/// or back to the authority's ATA when none is set.
+pub struct Revoke<'a> {
+    pub accounts: RevokeAccounts<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for Revoke<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let accounts = RevokeAccounts::try_from(accounts)?;
+
+        {
+            let vest_schedule_data = accounts.schedule.try_borrow_data()?;
+            let vest_schedule = VestSchedule::load(&vest_schedule_data)?;
+
+            let participant_state_data = accounts.participant_state.try_borrow_data()?;
+            let participant_state = VestParticipant::load(&participant_state_data)?;
+
+            if accounts.authority.key() != vest_schedule.authority() {
+                return Err(ProgramError::IllegalOwner);
+            }
+
+            if accounts.token_mint.key() != vest_schedule.token_mint() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            if accounts.token_program.key() != vest_schedule.token_program() {
+                return Err(PinocchioError::InvalidTokenProgram.into());
+            }
+
+            if accounts.vault.key() != vest_schedule.vault() {
+                return Err(PinocchioError::VaultMismatch.into());
+            }
+
+            if participant_state.schedule() != accounts.schedule.key() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            if participant_state.is_revoked() {
+                return Err(PinocchioError::VestingRevoked.into());
+            }
+
+            ProgramAccount::verify(
+                &[
+                    Seed::from(VEST_PARTICIPANT_SEED),
+                    Seed::from(participant_state.participant().as_ref()),
+                    Seed::from(accounts.schedule.key().as_ref()),
+                ],
+                accounts.participant_state,
+                participant_state.bump(),
+            )?;
+
+            if vest_schedule.has_revoke_destination() {
+                if accounts.destination.key() != vest_schedule.revoke_destination() {
+                    return Err(ProgramError::InvalidAccountData);
+                }
+
+                Token::check(accounts.destination)?;
+            } else {
+                AssociatedToken::check(
+                    accounts.destination,
+                    *accounts.authority.key(),
+                    *accounts.token_mint.key(),
+                    *accounts.token_program.key(),
+                )?;
+            }
+        }
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a> Revoke<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &4;
+
+    pub fn process(&self) -> Result<(), ProgramError> {
+        let (returned_amount, vault_signer) = {
+            let mut vest_schedule_data = self.accounts.schedule.try_borrow_mut_data()?;
+            let vest_schedule = VestSchedule::load_mut(&mut vest_schedule_data)?;
+
+            let mut participant_state_data =
+                self.accounts.participant_state.try_borrow_mut_data()?;
+            let participant_state = VestParticipant::load_mut(&mut participant_state_data)?;
+
+            let current_timestamp = current_unix_timestamp()?;
+            let allocated_amount = participant_state.allocated_amount();
+            let claimed_amount = participant_state.claimed_amount();
+
+            let claimable_amount =
+                participant_state.claimable_now(vest_schedule, current_timestamp);
+            let previously_forfeited = participant_state.forfeited_amount();
+            let forfeited_amount = participant_state.forfeited_at(vest_schedule, current_timestamp);
+
+            // Freeze the allocation at what has vested so far
+            let vested_amount = claimed_amount
+                .saturating_add(forfeited_amount)
+                .saturating_add(claimable_amount);
+
+            participant_state.set_allocated_amount(vested_amount);
+            participant_state.set_forfeited_amount(forfeited_amount);
+            participant_state.set_revoked(true);
+
+            // The unvested part is no longer committed to anyone, and neither is whatever
+            // expired just now, which stays in the vault as surplus
+            let returned_amount = allocated_amount.saturating_sub(vested_amount);
+            vest_schedule
+                .remove_allocation(returned_amount + (forfeited_amount - previously_forfeited))?;
+
+            (
+                returned_amount,
+                VaultSigner::new(self.accounts.schedule.key(), vest_schedule),
+            )
+        }; // Both borrows dropped here
+
+        if returned_amount > 0 {
+            let vault_authority =
+                vault_signer.authority(self.accounts.schedule, self.accounts.remaining)?;
+
+            vault_signer.transfer(
+                self.accounts.vault,
+                self.accounts.destination,
+                vault_authority,
+                self.accounts.token_program,
+                returned_amount,
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{ProgramAccount, SignerAccount, VestParticipant};
+
+pub struct SetClaimDelegateAccounts<'a> {
+    pub participant: &'a AccountInfo,
+    pub participant_state: &'a AccountInfo,
+    pub delegate: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetClaimDelegateAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [participant, participant_state, delegate] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(participant)?;
+        ProgramAccount::check::<VestParticipant>(participant_state)?;
+
+        Ok(Self {
+            participant,
+            participant_state,
+            delegate,
+        })
+    }
+}
+
+/// Lets a participant name an operator that may `Claim` on their behalf, e.g. an
+/// institution's custody desk. Tokens still go to the participant's ATA. The delegate
+/// doesn't need to sign here; passing the participant's own key as the delegate removes it.
+pub struct SetClaimDelegate<'a> {
+    pub accounts: SetClaimDelegateAccounts<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetClaimDelegate<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let accounts = SetClaimDelegateAccounts::try_from(accounts)?;
+
+        {
+            let participant_state_data = accounts.participant_state.try_borrow_data()?;
+            let participant_state = VestParticipant::load(&participant_state_data)?;
+
+            if accounts.participant.key() != participant_state.participant() {
+                return Err(ProgramError::IllegalOwner);
+            }
+        }
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a> SetClaimDelegate<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &31;
+
+    pub fn process(&self) -> Result<(), ProgramError> {
+        let mut participant_state_data = self.accounts.participant_state.try_borrow_mut_data()?;
+        let participant_state = VestParticipant::load_mut(&mut participant_state_data)?;
+
+        let delegate = if self.accounts.delegate.key() == self.accounts.participant.key() {
+            Pubkey::default()
+        } else {
+            *self.accounts.delegate.key()
+        };
+        participant_state.set_claim_delegate(delegate);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,127 @@
+use pinocchio::{account_info::AccountInfo, instruction::Seed, program_error::ProgramError};
+
+use crate::{
+    PinocchioError, ProgramAccount, SignerAccount, VestParticipant, VestSchedule,
+    VEST_PARTICIPANT_SEED,
+};
+
+pub struct SetClaimedAccounts<'a> {
+    pub authority: &'a AccountInfo,
+    pub schedule: &'a AccountInfo,
+    pub participant_state: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetClaimedAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, schedule, participant_state] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(authority)?;
+        ProgramAccount::check::<VestSchedule>(schedule)?;
+        ProgramAccount::check::<VestParticipant>(participant_state)?;
+
+        Ok(Self {
+            authority,
+            schedule,
+            participant_state,
+        })
+    }
+}
+
+#[repr(C, packed)]
+pub struct SetClaimedInstructionData {
+    pub claimed_amount: u64,
+}
+
+impl TryFrom<&[u8]> for SetClaimedInstructionData {
+    type Error = ProgramError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        if data.len() != size_of::<u64>() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let claimed_amount = u64::from_le_bytes(data[0..8].try_into().unwrap());
+
+        Ok(Self { claimed_amount })
+    }
+}
+
+/// Authority-only reconciliation of a participant's claimed amount, e.g. after tokens were
+/// distributed off-chain. The claimed amount may only grow and never past the allocation.
+/// The increase was paid outside the vault, so it is released from the schedule's
+/// `total_allocated` and the matching vault tokens become sweepable surplus.
+pub struct SetClaimed<'a> {
+    pub accounts: SetClaimedAccounts<'a>,
+    pub instruction_data: SetClaimedInstructionData,
+}
+
+impl<'a> TryFrom<(&[u8], &'a [AccountInfo])> for SetClaimed<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&[u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = SetClaimedAccounts::try_from(accounts)?;
+        let instruction_data = SetClaimedInstructionData::try_from(data)?;
+
+        let vest_schedule_data = accounts.schedule.try_borrow_data()?;
+        let vest_schedule = VestSchedule::load(&vest_schedule_data)?;
+
+        if accounts.authority.key() != vest_schedule.authority() {
+            return Err(ProgramError::IllegalOwner);
+        }
+
+        let participant_state_data = accounts.participant_state.try_borrow_data()?;
+        let participant_state = VestParticipant::load(&participant_state_data)?;
+
+        if participant_state.schedule() != accounts.schedule.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        ProgramAccount::verify(
+            &[
+                Seed::from(VEST_PARTICIPANT_SEED),
+                Seed::from(participant_state.participant().as_ref()),
+                Seed::from(accounts.schedule.key().as_ref()),
+            ],
+            accounts.participant_state,
+            participant_state.bump(),
+        )?;
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+        })
+    }
+}
+
+impl<'a> SetClaimed<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &3;
+
+    pub fn process(&self) -> Result<(), ProgramError> {
+        let mut participant_state_data = self.accounts.participant_state.try_borrow_mut_data()?;
+        let participant_state = VestParticipant::load_mut(&mut participant_state_data)?;
+
+        let claimed_amount = self.instruction_data.claimed_amount;
+
+        if claimed_amount < participant_state.claimed_amount() {
+            return Err(PinocchioError::ClaimedAmountDecrease.into());
+        }
+
+        if claimed_amount.saturating_add(participant_state.forfeited_amount())
+            > participant_state.allocated_amount()
+        {
+            return Err(PinocchioError::ClaimExceedsAllocation.into());
+        }
+
+        let paid_off_chain = claimed_amount - participant_state.claimed_amount();
+        participant_state.set_claimed_amount(claimed_amount);
+
+        let mut vest_schedule_data = self.accounts.schedule.try_borrow_mut_data()?;
+        VestSchedule::load_mut(&mut vest_schedule_data)?.remove_allocation(paid_off_chain)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{Config, ProgramAccount, SignerAccount};
+
+pub struct SetClaimsEnabledAccounts<'a> {
+    pub admin: &'a AccountInfo,
+    pub config: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetClaimsEnabledAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [admin, config] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(admin)?;
+        ProgramAccount::check::<Config>(config)?;
+
+        Ok(Self { admin, config })
+    }
+}
+
+#[repr(C, packed)]
+pub struct SetClaimsEnabledInstructionData {
+    pub claims_enabled: bool,
+}
+
+impl TryFrom<&[u8]> for SetClaimsEnabledInstructionData {
+    type Error = ProgramError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        let claims_enabled = match data {
+            [0] => false,
+            [1] => true,
+            _ => return Err(ProgramError::InvalidInstructionData),
+        };
+
+        Ok(Self { claims_enabled })
+    }
+}
+
+/// Admin-only global kill switch: while claims are disabled every `Claim`, across all
+/// schedules, fails with `ClaimsDisabled`.
+pub struct SetClaimsEnabled<'a> {
+    pub accounts: SetClaimsEnabledAccounts<'a>,
+    pub instruction_data: SetClaimsEnabledInstructionData,
+}
+
+impl<'a> TryFrom<(&[u8], &'a [AccountInfo])> for SetClaimsEnabled<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&[u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = SetClaimsEnabledAccounts::try_from(accounts)?;
+        let instruction_data = SetClaimsEnabledInstructionData::try_from(data)?;
+
+        let config_data = accounts.config.try_borrow_data()?;
+        let config = Config::load(&config_data)?;
+
+        if accounts.admin.key() != config.admin() {
+            return Err(ProgramError::IllegalOwner);
+        }
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+        })
+    }
+}
+
+impl<'a> SetClaimsEnabled<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &8;
+
+    pub fn process(&self) -> Result<(), ProgramError> {
+        let mut config_data = self.accounts.config.try_borrow_mut_data()?;
+        let config = Config::load_mut(&mut config_data)?;
+
+        config.set_claims_enabled(self.instruction_data.claims_enabled);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{Config, ProgramAccount, SignerAccount};
+
+pub struct SetConfigAdminAccounts<'a> {
+    pub admin: &'a AccountInfo,
+    pub config: &'a AccountInfo,
+    pub new_admin: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetConfigAdminAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [admin, config, new_admin] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(admin)?;
+        ProgramAccount::check::<Config>(config)?;
+
+        Ok(Self {
+            admin,
+            config,
+            new_admin,
+        })
+    }
+}
+
+/// Hands the config admin role, and with it the global kill switch, to a new key. The new
+/// admin doesn't need to sign; from then on only it can change the config.
+pub struct SetConfigAdmin<'a> {
+    pub accounts: SetConfigAdminAccounts<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetConfigAdmin<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let accounts = SetConfigAdminAccounts::try_from(accounts)?;
+
+        {
+            let config_data = accounts.config.try_borrow_data()?;
+            let config = Config::load(&config_data)?;
+
+            if accounts.admin.key() != config.admin() {
+                return Err(ProgramError::IllegalOwner);
+            }
+        }
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a> SetConfigAdmin<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &32;
+
+    pub fn process(&self) -> Result<(), ProgramError> {
+        let mut config_data = self.accounts.config.try_borrow_mut_data()?;
+        let config = Config::load_mut(&mut config_data)?;
+
+        config.set_admin(*self.accounts.new_admin.key());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{
+    AssociatedToken, Mint, PinocchioError, ProgramAccount, SignerAccount, Token, VaultSigner,
+    VestSchedule,
+};
+
+pub struct SweepSurplusAccounts<'a> {
+    pub authority: &'a AccountInfo,
+    pub authority_ata: &'a AccountInfo,
+    pub vault: &'a AccountInfo,
+    pub schedule: &'a AccountInfo,
+    pub token_mint: &'a AccountInfo,
+    pub token_program: &'a AccountInfo,
+    /// The vault authority PDA, for schedules whose vault it owns.
+    pub remaining: &'a [AccountInfo],
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SweepSurplusAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, authority_ata, vault, schedule, token_mint, token_program, remaining @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(authority)?;
+        ProgramAccount::check_token_program(token_program)?;
+        ProgramAccount::check::<VestSchedule>(schedule)?;
+        Mint::check(token_mint)?;
+
+        Ok(Self {
+            authority,
+            authority_ata,
+            vault,
+            schedule,
+            token_mint,
+            token_program,
+            remaining,
+        })
+    }
+}
+
+/// Returns vault tokens no participant can claim, e.g. an over-deposit or an airdrop, to the
+/// authority's ATA. The surplus is the vault balance beyond `total_allocated - total_claimed`,
+/// so it never touches vested or unvested allocations. Forfeited steps and amounts recorded
+/// as paid off-chain with `SetClaimed` are released from `total_allocated`, so their tokens
+/// are part of the surplus.
+pub struct SweepSurplus<'a> {
+    pub accounts: SweepSurplusAccounts<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SweepSurplus<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let accounts = SweepSurplusAccounts::try_from(accounts)?;
+
+        {
+            let vest_schedule_data = accounts.schedule.try_borrow_data()?;
+            let vest_schedule = VestSchedule::load(&vest_schedule_data)?;
+
+            if accounts.authority.key() != vest_schedule.authority() {
+                return Err(ProgramError::IllegalOwner);
+            }
+
+            if accounts.token_mint.key() != vest_schedule.token_mint() {
+                return Err(PinocchioError::MintMismatch.into());
+            }
+
+            if accounts.token_program.key() != vest_schedule.token_program() {
+                return Err(PinocchioError::InvalidTokenProgram.into());
+            }
+
+            if accounts.vault.key() != vest_schedule.vault() {
+                return Err(PinocchioError::VaultMismatch.into());
+            }
+
+            AssociatedToken::check(
+                accounts.authority_ata,
+                *accounts.authority.key(),
+                *accounts.token_mint.key(),
+                *accounts.token_program.key(),
+            )?;
+        }
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a> SweepSurplus<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &25;
+
+    pub fn process(&self) -> Result<(), ProgramError> {
+        let (surplus, vault_signer) = {
+            let vest_schedule_data = self.accounts.schedule.try_borrow_data()?;
+            let vest_schedule = VestSchedule::load(&vest_schedule_data)?;
+
+            (
+                vest_schedule.surplus(Token::amount(self.accounts.vault)?),
+                VaultSigner::new(self.accounts.schedule.key(), vest_schedule),
+            )
+        }; // Borrow dropped here
+
+        if surplus == 0 {
+            return Err(PinocchioError::NoSurplus.into());
+        }
+
+        let vault_authority =
+            vault_signer.authority(self.accounts.schedule, self.accounts.remaining)?;
+
+        vault_signer.transfer(
+            self.accounts.vault,
+            self.accounts.authority_ata,
+            vault_authority,
+            self.accounts.token_program,
+            surplus,
+        )
+    }
+}
@@ -0,0 +1,164 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{
+    current_unix_timestamp, AddParticipant, Mint, PinocchioError, ProgramAccount, SignerAccount,
+    Token, TokenTransfer, VestParticipant, VestSchedule,
+};
+
+pub struct TopUpAccounts<'a> {
+    pub authority: &'a AccountInfo,
+    pub authority_ata: &'a AccountInfo,
+    pub vault: &'a AccountInfo,
+    pub participant_state: &'a AccountInfo,
+    pub schedule: &'a AccountInfo,
+    pub token_mint: &'a AccountInfo,
+    pub token_program: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for TopUpAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, authority_ata, vault, participant_state, schedule, token_mint, token_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(authority)?;
+        ProgramAccount::check_token_program(token_program)?;
+        ProgramAccount::check::<VestSchedule>(schedule)?;
+        ProgramAccount::check::<VestParticipant>(participant_state)?;
+        Mint::check(token_mint)?;
+
+        Ok(Self {
+            authority,
+            authority_ata,
+            vault,
+            participant_state,
+            schedule,
+            token_mint,
+            token_program,
+        })
+    }
+}
+
+#[repr(C, packed)]
+pub struct TopUpInstructionData {
+    pub amount: u64,
+}
+
+impl TryFrom<&[u8]> for TopUpInstructionData {
+    type Error = ProgramError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        if data.len() != size_of::<u64>() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let amount = u64::from_le_bytes(data[0..8].try_into().unwrap());
+
+        if amount == 0 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Ok(Self { amount })
+    }
+}
+
+/// Authority-only. Adds `amount` to an existing participant's allocation, e.g. after a
+/// raise, and deposits it into the vault. Follows the `AddParticipant` rules, so it is only
+/// possible until the cliff ends.
+pub struct TopUp<'a> {
+    pub accounts: TopUpAccounts<'a>,
+    pub instruction_data: TopUpInstructionData,
+}
+
+impl<'a> TryFrom<(&[u8], &'a [AccountInfo])> for TopUp<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&[u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = TopUpAccounts::try_from(accounts)?;
+        let instruction_data = TopUpInstructionData::try_from(data)?;
+
+        AddParticipant::check_schedule(
+            accounts.authority,
+            accounts.authority_ata,
+            accounts.vault,
+            accounts.schedule,
+            accounts.token_mint,
+            accounts.token_program,
+        )?;
+
+        {
+            let vest_schedule_data = accounts.schedule.try_borrow_data()?;
+            let vest_schedule = VestSchedule::load(&vest_schedule_data)?;
+
+            let participant_state_data = accounts.participant_state.try_borrow_data()?;
+            let participant_state = VestParticipant::load(&participant_state_data)?;
+
+            if participant_state.schedule() != accounts.schedule.key() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            if participant_state.is_revoked() {
+                return Err(PinocchioError::VestingRevoked.into());
+            }
+
+            // A participant on a shorter cliff than the schedule may already be vesting
+            let current_timestamp = current_unix_timestamp()?;
+            if participant_state
+                .terms(vest_schedule)
+                .is_cliff_completed(current_timestamp)
+            {
+                return Err(PinocchioError::CannotAddParticipantsAfterCliff.into());
+            }
+        }
+
+        if Token::amount(accounts.authority_ata)? < instruction_data.amount {
+            return Err(PinocchioError::InsufficientDeposit.into());
+        }
+
+        AddParticipant::check_not_over_allocated(
+            accounts.schedule,
+            accounts.vault,
+            instruction_data.amount,
+        )?;
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+        })
+    }
+}
+
+impl<'a> TopUp<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &22;
+
+    pub fn process(&self) -> Result<(), ProgramError> {
+        {
+            let mut participant_state_data =
+                self.accounts.participant_state.try_borrow_mut_data()?;
+            let participant_state = VestParticipant::load_mut(&mut participant_state_data)?;
+
+            let allocated_amount = participant_state
+                .allocated_amount()
+                .checked_add(self.instruction_data.amount)
+                .ok_or(PinocchioError::AllocationOverflow)?;
+            participant_state.set_allocated_amount(allocated_amount);
+
+            let mut vest_schedule_data = self.accounts.schedule.try_borrow_mut_data()?;
+            VestSchedule::load_mut(&mut vest_schedule_data)?
+                .add_allocation(self.instruction_data.amount)?;
+        }
+
+        TokenTransfer {
+            from: self.accounts.authority_ata,
+            to: self.accounts.vault,
+            authority: self.accounts.authority,
+            token_program: self.accounts.token_program,
+            amount: self.instruction_data.amount,
+        }
+        .invoke()
+    }
+}
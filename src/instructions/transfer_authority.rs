@@ -0,0 +1,66 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{ProgramAccount, SignerAccount, VestSchedule};
+
+pub struct TransferAuthorityAccounts<'a> {
+    pub authority: &'a AccountInfo,
+    pub schedule: &'a AccountInfo,
+    pub new_authority: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for TransferAuthorityAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, schedule, new_authority] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(authority)?;
+        ProgramAccount::check::<VestSchedule>(schedule)?;
+
+        Ok(Self {
+            authority,
+            schedule,
+            new_authority,
+        })
+    }
+}
+
+/// Hands a schedule over to a new authority, e.g. after a treasury reorganization. The new
+/// authority doesn't need to sign; from then on only it can manage the schedule.
+pub struct TransferAuthority<'a> {
+    pub accounts: TransferAuthorityAccounts<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for TransferAuthority<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let accounts = TransferAuthorityAccounts::try_from(accounts)?;
+
+        {
+            let vest_schedule_data = accounts.schedule.try_borrow_data()?;
+            let vest_schedule = VestSchedule::load(&vest_schedule_data)?;
+
+            if accounts.authority.key() != vest_schedule.authority() {
+                return Err(ProgramError::IllegalOwner);
+            }
+        }
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a> TransferAuthority<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &17;
+
+    pub fn process(&self) -> Result<(), ProgramError> {
+        let mut vest_schedule_data = self.accounts.schedule.try_borrow_mut_data()?;
+        let vest_schedule = VestSchedule::load_mut(&mut vest_schedule_data)?;
+
+        vest_schedule.set_authority(*self.accounts.new_authority.key());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{FreezeClaimsAccounts, PinocchioError, VestSchedule};
+
+/// Authority-only. Lets claims through again after `FreezeClaims`. Takes the same accounts
+/// as `FreezeClaims`.
+pub struct UnfreezeClaims<'a> {
+    pub accounts: FreezeClaimsAccounts<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for UnfreezeClaims<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let accounts = FreezeClaimsAccounts::try_from(accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a> UnfreezeClaims<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &28;
+
+    pub fn process(&self) -> Result<(), ProgramError> {
+        let mut vest_schedule_data = self.accounts.schedule.try_borrow_mut_data()?;
+        let vest_schedule = VestSchedule::load_mut(&mut vest_schedule_data)?;
+
+        if !vest_schedule.claims_frozen() {
+            return Err(PinocchioError::ClaimsNotFrozen.into());
+        }
+
+        vest_schedule.set_claims_frozen(false);
+
+        Ok(())
+    }
+}
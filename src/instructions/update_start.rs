@@ -0,0 +1,118 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{current_unix_timestamp, PinocchioError, ProgramAccount, SignerAccount, VestSchedule};
+
+pub struct UpdateStartAccounts<'a> {
+    pub authority: &'a AccountInfo,
+    pub schedule: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for UpdateStartAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, schedule] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        SignerAccount::check(authority)?;
+        ProgramAccount::check::<VestSchedule>(schedule)?;
+
+        Ok(Self {
+            authority,
+            schedule,
+        })
+    }
+}
+
+#[repr(C, packed)]
+pub struct UpdateStartInstructionData {
+    pub start_timestamp: u64,
+}
+
+impl TryFrom<&[u8]> for UpdateStartInstructionData {
+    type Error = ProgramError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        if data.len() != size_of::<u64>() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let start_timestamp = u64::from_le_bytes(data[0..8].try_into().unwrap());
+
+        Ok(Self { start_timestamp })
+    }
+}
+
+/// Authority-only. Moves the start of a schedule that hasn't begun yet, earlier or later,
+/// for launches that slip. Durations are kept, so the cliff and end move with the start.
+/// Rejected with `ScheduleAlreadyStarted` once vesting has begun.
+pub struct UpdateStart<'a> {
+    pub accounts: UpdateStartAccounts<'a>,
+    pub instruction_data: UpdateStartInstructionData,
+}
+
+impl<'a> TryFrom<(&[u8], &'a [AccountInfo])> for UpdateStart<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&[u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = UpdateStartAccounts::try_from(accounts)?;
+        let instruction_data = UpdateStartInstructionData::try_from(data)?;
+
+        {
+            let vest_schedule_data = accounts.schedule.try_borrow_data()?;
+            let vest_schedule = VestSchedule::load(&vest_schedule_data)?;
+
+            if accounts.authority.key() != vest_schedule.authority() {
+                return Err(ProgramError::IllegalOwner);
+            }
+
+            let current_timestamp = current_unix_timestamp()?;
+
+            if current_timestamp >= vest_schedule.start_timestamp() {
+                return Err(PinocchioError::ScheduleAlreadyStarted.into());
+            }
+
+            if instruction_data.start_timestamp < current_timestamp {
+                return Err(PinocchioError::StartTimestampInPast.into());
+            }
+
+            // Same invariants as `Initialize`: the end must fit in a u64 and the durations
+            // must still describe a schedule that vests
+            if instruction_data
+                .start_timestamp
+                .checked_add(vest_schedule.total_duration())
+                .is_none()
+            {
+                return Err(PinocchioError::InvalidDurations.into());
+            }
+
+            // Participant overrides aren't visible here, but `AddParticipant` holds their
+            // durations to `MAX_TIMESTAMP`, so a start the clock can reach keeps every
+            // participant's end in a u64 too
+            if instruction_data.start_timestamp > VestSchedule::MAX_TIMESTAMP {
+                return Err(PinocchioError::InvalidDurations.into());
+            }
+
+            vest_schedule.validate()?;
+        }
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+        })
+    }
+}
+
+impl<'a> UpdateStart<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &20;
+
+    pub fn process(&self) -> Result<(), ProgramError> {
+        let mut vest_schedule_data = self.accounts.schedule.try_borrow_mut_data()?;
+        let vest_schedule = VestSchedule::load_mut(&mut vest_schedule_data)?;
+
+        vest_schedule.set_start_timestamp(self.instruction_data.start_timestamp);
+
+        Ok(())
+    }
+}
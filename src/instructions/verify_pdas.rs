@@ -0,0 +1,91 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::set_return_data,
+    program_error::ProgramError,
+    pubkey::{create_program_address, Pubkey},
+};
+
+use crate::{
+    ProgramAccount, VestParticipant, VestSchedule, VEST_PARTICIPANT_SEED, VEST_SCHEDULE_SEED,
+};
+
+pub struct VerifyPdasAccounts<'a> {
+    pub schedule: &'a AccountInfo,
+    pub participant_state: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for VerifyPdasAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [schedule, participant_state] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        ProgramAccount::check::<VestSchedule>(schedule)?;
+        ProgramAccount::check::<VestParticipant>(participant_state)?;
+
+        Ok(Self {
+            schedule,
+            participant_state,
+        })
+    }
+}
+
+/// Read-only debugging aid for integrators. Re-derives the schedule and participant PDAs from
+/// the seeds and bumps stored in the accounts and returns `[schedule_ok, participant_ok]`
+/// (one byte each, 0 or 1) as return data instead of failing on a mismatch. The participant
+/// PDA is derived under the schedule passed in, so a participant of another schedule fails.
+pub struct VerifyPdas<'a> {
+    pub accounts: VerifyPdasAccounts<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for VerifyPdas<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let accounts = VerifyPdasAccounts::try_from(accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a> VerifyPdas<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &6;
+
+    pub fn process(&self) -> Result<(), ProgramError> {
+        let vest_schedule_data = self.accounts.schedule.try_borrow_data()?;
+        let vest_schedule = VestSchedule::load(&vest_schedule_data)?;
+
+        let participant_state_data = self.accounts.participant_state.try_borrow_data()?;
+        let participant_state = VestParticipant::load(&participant_state_data)?;
+
+        let schedule_ok = Self::matches(
+            &[
+                VEST_SCHEDULE_SEED,
+                &vest_schedule.seed().to_le_bytes(),
+                &[vest_schedule.bump()],
+            ],
+            self.accounts.schedule.key(),
+        );
+
+        let participant_ok = Self::matches(
+            &[
+                VEST_PARTICIPANT_SEED,
+                participant_state.participant().as_ref(),
+                self.accounts.schedule.key().as_ref(),
+                &[participant_state.bump()],
+            ],
+            self.accounts.participant_state.key(),
+        );
+
+        set_return_data(&[schedule_ok as u8, participant_ok as u8]);
+
+        Ok(())
+    }
+
+    // Seeds that don't produce a valid program address count as a mismatch
+    fn matches(seeds: &[&[u8]], expected: &Pubkey) -> bool {
+        create_program_address(seeds, &crate::ID).is_ok_and(|address| &address == expected)
+    }
+}
@@ -13,9 +13,24 @@ pub use schedule_state::*;
 pub mod participant_state;
 pub use participant_state::*;
 
+pub mod config_state;
+pub use config_state::*;
+
+pub mod generation_state;
+pub use generation_state::*;
+
+pub mod schedule_registry;
+pub use schedule_registry::*;
+
 pub mod error;
 pub use error::*;
 
+pub mod vesting;
+pub use vesting::*;
+
+#[cfg(feature = "client")]
+pub mod instruction_builder;
+
 pub const ID: Pubkey = [
     0x0f, 0x1e, 0x6b, 0x14, 0x21, 0xc0, 0x4a, 0x07, 0x04, 0x31, 0x26, 0x5c, 0x19, 0xc5, 0xbb, 0xee,
     0x19, 0x92, 0xba, 0xe8, 0xaf, 0xd1, 0xcd, 0x07, 0x8e, 0xf8, 0xaf, 0x70, 0x47, 0xdc, 0x11, 0xf7,
@@ -34,6 +49,67 @@ fn process_instruction(
             AddParticipant::try_from((data, accounts))?.process()
         }
         Some((Claim::DISCRIMINATOR, _data)) => Claim::try_from(accounts)?.process(),
+        Some((SetClaimed::DISCRIMINATOR, data)) => {
+            SetClaimed::try_from((data, accounts))?.process()
+        }
+        Some((Revoke::DISCRIMINATOR, _data)) => Revoke::try_from(accounts)?.process(),
+        Some((CloseParticipant::DISCRIMINATOR, _data)) => {
+            CloseParticipant::try_from(accounts)?.process()
+        }
+        Some((VerifyPdas::DISCRIMINATOR, _data)) => VerifyPdas::try_from(accounts)?.process(),
+        Some((InitializeConfig::DISCRIMINATOR, data)) => {
+            InitializeConfig::try_from((data, accounts))?.process()
+        }
+        Some((SetClaimsEnabled::DISCRIMINATOR, data)) => {
+            SetClaimsEnabled::try_from((data, accounts))?.process()
+        }
+        Some((QuerySchedule::DISCRIMINATOR, _data)) => QuerySchedule::try_from(accounts)?.process(),
+        Some((CloseSchedule::DISCRIMINATOR, _data)) => CloseSchedule::try_from(accounts)?.process(),
+        Some((BatchAddParticipant::DISCRIMINATOR, data)) => {
+            BatchAddParticipant::try_from((data, accounts))?.process()
+        }
+        Some((QueryVaultHealth::DISCRIMINATOR, _data)) => {
+            QueryVaultHealth::try_from(accounts)?.process()
+        }
+        Some((Pause::DISCRIMINATOR, _data)) => Pause::try_from(accounts)?.process(),
+        Some((Resume::DISCRIMINATOR, _data)) => Resume::try_from(accounts)?.process(),
+        Some((CrankClaim::DISCRIMINATOR, _data)) => CrankClaim::try_from(accounts)?.process(),
+        Some((ClaimTo::DISCRIMINATOR, _data)) => ClaimTo::try_from(accounts)?.process(),
+        Some((TransferAuthority::DISCRIMINATOR, _data)) => {
+            TransferAuthority::try_from(accounts)?.process()
+        }
+        Some((GetClaimable::DISCRIMINATOR, _data)) => GetClaimable::try_from(accounts)?.process(),
+        Some((PrecreateAtas::DISCRIMINATOR, _data)) => PrecreateAtas::try_from(accounts)?.process(),
+        Some((UpdateStart::DISCRIMINATOR, data)) => {
+            UpdateStart::try_from((data, accounts))?.process()
+        }
+        Some((ParticipantInfo::DISCRIMINATOR, _data)) => {
+            ParticipantInfo::try_from(accounts)?.process()
+        }
+        Some((TopUp::DISCRIMINATOR, data)) => TopUp::try_from((data, accounts))?.process(),
+        Some((ReduceAllocation::DISCRIMINATOR, data)) => {
+            ReduceAllocation::try_from((data, accounts))?.process()
+        }
+        Some((ClaimAll::DISCRIMINATOR, data)) => ClaimAll::try_from((data, accounts))?.process(),
+        Some((SweepSurplus::DISCRIMINATOR, _data)) => SweepSurplus::try_from(accounts)?.process(),
+        Some((ReassignParticipant::DISCRIMINATOR, _data)) => {
+            ReassignParticipant::try_from(accounts)?.process()
+        }
+        Some((FreezeClaims::DISCRIMINATOR, _data)) => FreezeClaims::try_from(accounts)?.process(),
+        Some((UnfreezeClaims::DISCRIMINATOR, _data)) => {
+            UnfreezeClaims::try_from(accounts)?.process()
+        }
+        Some((EnsureVault::DISCRIMINATOR, _data)) => EnsureVault::try_from(accounts)?.process(),
+        Some((ClaimToEscrow::DISCRIMINATOR, _data)) => ClaimToEscrow::try_from(accounts)?.process(),
+        Some((SetClaimDelegate::DISCRIMINATOR, _data)) => {
+            SetClaimDelegate::try_from(accounts)?.process()
+        }
+        Some((SetConfigAdmin::DISCRIMINATOR, _data)) => {
+            SetConfigAdmin::try_from(accounts)?.process()
+        }
+        Some((ForfeitExpired::DISCRIMINATOR, _data)) => {
+            ForfeitExpired::try_from(accounts)?.process()
+        }
         _ => Err(ProgramError::InvalidInstructionData),
     }
 }
@@ -3,14 +3,22 @@ use pinocchio::pubkey::Pubkey;
 #[repr(C, packed)]
 pub struct VestParticipant {
     pub discriminator: u8,
+    pub version: u8,
     pub participant: Pubkey,
     pub schedule: Pubkey,
     pub allocated_amount: u64,
     pub claimed_amount: u64,
     pub bump: u8,
+    pub forfeited_amount: u64,
+    pub total_fees_paid: u64,
+    pub revoked: u8,
+    pub cliff_override: u64,
+    pub total_override: u64,
+    pub claim_delegate: Pubkey,
+    pub last_claim_ts: u64,
 }
 
-use crate::Discriminator;
+use crate::{Claimable, Discriminator, PinocchioError, VestSchedule, ACCOUNT_VERSION};
 
 impl Discriminator for VestParticipant {
     const LEN: usize = Self::LEN;
@@ -18,9 +26,9 @@ impl Discriminator for VestParticipant {
 }
 
 impl VestParticipant {
-    pub const LEN: usize = std::mem::size_of::<Pubkey>() * 2
-        + std::mem::size_of::<u64>() * 2
-        + std::mem::size_of::<u8>() * 2;
+    pub const LEN: usize = std::mem::size_of::<Pubkey>() * 3
+        + std::mem::size_of::<u64>() * 7
+        + std::mem::size_of::<u8>() * 4;
     pub const DISCRIMINATOR: u8 = 1;
 
     #[inline(always)]
@@ -28,6 +36,7 @@ impl VestParticipant {
         if bytes.len() != VestParticipant::LEN {
             return Err(pinocchio::program_error::ProgramError::InvalidAccountData);
         }
+        Self::check_version(bytes[1])?;
         Ok(unsafe { &mut *core::mem::transmute::<*mut u8, *mut Self>(bytes.as_mut_ptr()) })
     }
 
@@ -36,8 +45,43 @@ impl VestParticipant {
         if bytes.len() != VestParticipant::LEN {
             return Err(pinocchio::program_error::ProgramError::InvalidAccountData);
         }
+        Self::check_version(bytes[1])?;
 
-        Ok(unsafe { &*core::mem::transmute::<*const u8, *const Self>(bytes.as_ptr()) })
+        let participant =
+            unsafe { &*core::mem::transmute::<*const u8, *const Self>(bytes.as_ptr()) };
+        participant.validate()?;
+
+        Ok(participant)
+    }
+
+    /// Rejects state no instruction writes: another account type's discriminator, or more
+    /// claimed than was ever allocated. `load_mut` skips this, since it also loads freshly
+    /// allocated accounts that are about to be written.
+    #[inline(always)]
+    pub fn validate(&self) -> Result<(), pinocchio::program_error::ProgramError> {
+        if self.discriminator != VestParticipant::DISCRIMINATOR {
+            return Err(PinocchioError::InvalidDiscriminator.into());
+        }
+
+        if self.claimed_amount() > self.allocated_amount() {
+            return Err(PinocchioError::ClaimExceedsAllocation.into());
+        }
+
+        Ok(())
+    }
+
+    /// Version 0 is a freshly allocated, not yet written account.
+    #[inline(always)]
+    fn check_version(version: u8) -> Result<(), pinocchio::program_error::ProgramError> {
+        match version {
+            0..=ACCOUNT_VERSION => Ok(()),
+            _ => Err(PinocchioError::UnsupportedVersion.into()),
+        }
+    }
+
+    #[inline(always)]
+    pub fn version(&self) -> u8 {
+        self.version
     }
 
     #[inline(always)]
@@ -64,10 +108,173 @@ impl VestParticipant {
         self.bump
     }
 
+    #[inline(always)]
+    pub fn forfeited_amount(&self) -> u64 {
+        self.forfeited_amount
+    }
+
+    #[inline(always)]
+    pub fn total_fees_paid(&self) -> u64 {
+        self.total_fees_paid
+    }
+
+    #[inline(always)]
+    pub fn is_revoked(&self) -> bool {
+        self.revoked != 0
+    }
+
+    /// Cliff duration replacing the schedule's for this participant, or zero to use the
+    /// schedule's.
+    #[inline(always)]
+    pub fn cliff_override(&self) -> u64 {
+        self.cliff_override
+    }
+
+    /// Total duration replacing the schedule's for this participant, or zero to use the
+    /// schedule's.
+    #[inline(always)]
+    pub fn total_override(&self) -> u64 {
+        self.total_override
+    }
+
+    /// Operator allowed to claim for this participant, or the default key for none.
+    #[inline(always)]
+    pub fn claim_delegate(&self) -> &Pubkey {
+        &self.claim_delegate
+    }
+
+    #[inline(always)]
+    pub fn has_claim_delegate(&self) -> bool {
+        self.claim_delegate != Pubkey::default()
+    }
+
+    /// Time of the participant's last successful claim, or zero if they never claimed.
+    #[inline(always)]
+    pub fn last_claim_ts(&self) -> u64 {
+        self.last_claim_ts
+    }
+
+    /// The schedule as it applies to this participant, with any duration overrides in place.
+    /// Everything that evaluates the vesting curve for a participant should go through this.
+    #[inline(always)]
+    pub fn terms(&self, schedule: &VestSchedule) -> VestSchedule {
+        schedule.with_duration_overrides(self.cliff_override, self.total_override)
+    }
+
+    /// A revoked participant's allocation is frozen at what had vested at revocation, so
+    /// whatever of it is left unclaimed stays claimable regardless of the schedule.
+    #[inline(always)]
+    pub fn claimable_now(&self, schedule: &VestSchedule, now: u64) -> u64 {
+        if self.is_revoked() {
+            return self.allocated_amount().saturating_sub(
+                self.claimed_amount()
+                    .saturating_add(self.forfeited_amount()),
+            );
+        }
+
+        self.terms(schedule).calculate_claimable_amount(
+            now,
+            self.allocated_amount(),
+            self.claimed_amount()
+                .saturating_add(self.forfeited_amount()),
+        )
+    }
+
+    /// The forfeited amount once whatever left the claim window unclaimed by `now` is
+    /// counted. A revoked allocation no longer follows the schedule, so nothing more expires.
+    #[inline(always)]
+    pub fn forfeited_at(&self, schedule: &VestSchedule, now: u64) -> u64 {
+        if self.is_revoked() {
+            return self.forfeited_amount();
+        }
+
+        let expired_amount = self
+            .terms(schedule)
+            .expired_amount(now, self.allocated_amount());
+
+        self.forfeited_amount()
+            .max(expired_amount.saturating_sub(self.claimed_amount()))
+    }
+
+    /// [`Self::claimable_now`] with the reason when it is zero. A revoked participant has
+    /// nothing more to wait for, so once the frozen allocation is claimed they are
+    /// `Completed`, even before the cliff.
+    #[inline(always)]
+    pub fn claimable(&self, schedule: &VestSchedule, now: u64) -> Claimable {
+        if self.is_revoked() {
+            return match self.claimable_now(schedule, now) {
+                0 => Claimable::Completed,
+                amount => Claimable::Amount(amount),
+            };
+        }
+
+        let terms = self.terms(schedule);
+        if !terms.is_cliff_completed(now) {
+            return Claimable::BeforeCliff;
+        }
+
+        terms.claimable(
+            now,
+            self.allocated_amount(),
+            self.claimed_amount()
+                .saturating_add(self.forfeited_amount()),
+        )
+    }
+
+    pub fn set_allocated_amount(&mut self, amount: u64) {
+        self.allocated_amount = amount;
+    }
+
     pub fn set_claimed_amount(&mut self, amount: u64) {
         self.claimed_amount = amount;
     }
 
+    pub fn set_forfeited_amount(&mut self, amount: u64) {
+        self.forfeited_amount = amount;
+    }
+
+    pub fn set_total_fees_paid(&mut self, amount: u64) {
+        self.total_fees_paid = amount;
+    }
+
+    pub fn set_revoked(&mut self, revoked: bool) {
+        self.revoked = revoked as u8;
+    }
+
+    pub fn set_claim_delegate(&mut self, claim_delegate: Pubkey) {
+        self.claim_delegate = claim_delegate;
+    }
+
+    pub fn set_last_claim_ts(&mut self, last_claim_ts: u64) {
+        self.last_claim_ts = last_claim_ts;
+    }
+
+    pub fn set_duration_overrides(&mut self, cliff_override: u64, total_override: u64) {
+        self.cliff_override = cliff_override;
+        self.total_override = total_override;
+    }
+
+    /// Copies every field out by value into an aligned struct, so callers never take a
+    /// reference into the packed layout.
+    pub fn snapshot(&self) -> VestParticipantSnapshot {
+        VestParticipantSnapshot {
+            discriminator: self.discriminator,
+            version: self.version,
+            participant: self.participant,
+            schedule: self.schedule,
+            allocated_amount: self.allocated_amount,
+            claimed_amount: self.claimed_amount,
+            bump: self.bump,
+            forfeited_amount: self.forfeited_amount,
+            total_fees_paid: self.total_fees_paid,
+            revoked: self.revoked != 0,
+            cliff_override: self.cliff_override,
+            total_override: self.total_override,
+            claim_delegate: self.claim_delegate,
+            last_claim_ts: self.last_claim_ts,
+        }
+    }
+
     pub fn set_inner(
         &mut self,
         participant: Pubkey,
@@ -77,6 +284,7 @@ impl VestParticipant {
         bump: u8,
     ) {
         self.discriminator = VestParticipant::DISCRIMINATOR;
+        self.version = ACCOUNT_VERSION;
         self.participant = participant;
         self.schedule = schedule;
         self.allocated_amount = allocated_amount;
@@ -84,3 +292,83 @@ impl VestParticipant {
         self.bump = bump;
     }
 }
+
+#[cfg(feature = "client")]
+impl VestParticipant {
+    /// Decodes fetched account data into an owned [`VestParticipantSnapshot`], so clients
+    /// never cast the bytes themselves. Applies the same checks as `load`.
+    pub fn from_bytes(
+        bytes: &[u8],
+    ) -> Result<VestParticipantSnapshot, pinocchio::program_error::ProgramError> {
+        Ok(Self::load(bytes)?.snapshot())
+    }
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+impl VestParticipant {
+    /// Participant state as `AddParticipant` would write it, built in memory for host-side
+    /// tests.
+    pub fn new_for_test(allocated_amount: u64, claimed_amount: u64) -> Self {
+        // Every field is a plain integer or byte array, so all zeroes is a valid value
+        let mut participant: Self = unsafe { core::mem::zeroed() };
+        participant.set_inner(
+            Pubkey::default(),
+            Pubkey::default(),
+            allocated_amount,
+            claimed_amount,
+            0,
+        );
+        participant
+    }
+}
+
+/// Aligned copy of a [`VestParticipant`] for clients. Fields are in account order, and
+/// `to_bytes` serializes them the way borsh would: little-endian integers and a bool as one
+/// 0/1 byte, without padding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VestParticipantSnapshot {
+    pub discriminator: u8,
+    pub version: u8,
+    pub participant: Pubkey,
+    pub schedule: Pubkey,
+    pub allocated_amount: u64,
+    pub claimed_amount: u64,
+    pub bump: u8,
+    pub forfeited_amount: u64,
+    pub total_fees_paid: u64,
+    pub revoked: bool,
+    pub cliff_override: u64,
+    pub total_override: u64,
+    pub claim_delegate: Pubkey,
+    pub last_claim_ts: u64,
+}
+
+impl VestParticipantSnapshot {
+    /// Serialized size, which matches the packed account layout byte for byte.
+    pub const LEN: usize = VestParticipant::LEN;
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut bytes = [0u8; Self::LEN];
+        let mut offset = 0;
+        let mut put = |field: &[u8]| {
+            bytes[offset..offset + field.len()].copy_from_slice(field);
+            offset += field.len();
+        };
+
+        put(&[self.discriminator, self.version]);
+        put(&self.participant);
+        put(&self.schedule);
+        put(&self.allocated_amount.to_le_bytes());
+        put(&self.claimed_amount.to_le_bytes());
+        put(&[self.bump]);
+        put(&self.forfeited_amount.to_le_bytes());
+        put(&self.total_fees_paid.to_le_bytes());
+        put(&[self.revoked as u8]);
+        put(&self.cliff_override.to_le_bytes());
+        put(&self.total_override.to_le_bytes());
+        put(&self.claim_delegate);
+        put(&self.last_claim_ts.to_le_bytes());
+
+        bytes
+    }
+}
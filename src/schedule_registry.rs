@@ -0,0 +1,113 @@
+use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
+
+/// Opt-in list of the schedules an authority created, stored in the
+/// `["vest_registry", authority]` PDA so projects vesting several mints can enumerate their
+/// pools. The header is followed by `count` schedule pubkeys; the account grows by one
+/// entry per registered schedule.
+#[repr(C, packed)]
+pub struct ScheduleRegistry {
+    pub discriminator: u8,
+    pub version: u8,
+    pub authority: Pubkey,
+    pub count: u32,
+    pub bump: u8,
+}
+
+use crate::{PinocchioError, ACCOUNT_VERSION};
+
+impl ScheduleRegistry {
+    pub const HEADER_LEN: usize = size_of::<Pubkey>() + size_of::<u32>() + size_of::<u8>() * 3;
+    pub const DISCRIMINATOR: u8 = 4;
+
+    /// Account size holding `count` schedules.
+    #[inline(always)]
+    pub fn len_for(count: u32) -> usize {
+        Self::HEADER_LEN + count as usize * size_of::<Pubkey>()
+    }
+
+    #[inline(always)]
+    pub fn load_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        if bytes.len() < Self::HEADER_LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Self::check_version(bytes[1])?;
+        Ok(unsafe { &mut *core::mem::transmute::<*mut u8, *mut Self>(bytes.as_mut_ptr()) })
+    }
+
+    #[inline(always)]
+    pub fn load(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() < Self::HEADER_LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Self::check_version(bytes[1])?;
+
+        Ok(unsafe { &*core::mem::transmute::<*const u8, *const Self>(bytes.as_ptr()) })
+    }
+
+    /// Version 0 is a freshly allocated, not yet written account.
+    #[inline(always)]
+    fn check_version(version: u8) -> Result<(), ProgramError> {
+        match version {
+            0..=ACCOUNT_VERSION => Ok(()),
+            _ => Err(PinocchioError::UnsupportedVersion.into()),
+        }
+    }
+
+    #[inline(always)]
+    pub fn authority(&self) -> &Pubkey {
+        &self.authority
+    }
+
+    /// Number of schedules registered so far.
+    #[inline(always)]
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    #[inline(always)]
+    pub fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    /// The `index`th registered schedule of the registry account data `bytes`, in
+    /// registration order.
+    pub fn schedule_at(bytes: &[u8], index: u32) -> Result<Option<Pubkey>, ProgramError> {
+        if index >= Self::load(bytes)?.count() {
+            return Ok(None);
+        }
+
+        let offset = Self::len_for(index);
+        Ok(Some(
+            bytes[offset..offset + size_of::<Pubkey>()]
+                .try_into()
+                .unwrap(),
+        ))
+    }
+
+    /// Appends `schedule` to the registry account data `bytes`, which must already have
+    /// room for exactly one more entry.
+    pub fn register(bytes: &mut [u8], schedule: &Pubkey) -> Result<(), ProgramError> {
+        let count = Self::load(bytes)?.count();
+        let new_count = count
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        if bytes.len() != Self::len_for(new_count) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let offset = Self::len_for(count);
+        bytes[offset..offset + size_of::<Pubkey>()].copy_from_slice(schedule);
+        Self::load_mut(bytes)?.count = new_count;
+
+        Ok(())
+    }
+
+    pub fn set_inner(&mut self, authority: Pubkey, bump: u8) {
+        self.discriminator = ScheduleRegistry::DISCRIMINATOR;
+        self.version = ACCOUNT_VERSION;
+        self.authority = authority;
+        self.count = 0;
+        self.bump = bump;
+    }
+}
@@ -1,8 +1,12 @@
 use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
 
+use crate::{PinocchioError, ACCOUNT_VERSION};
+
 #[repr(C, packed)]
+#[derive(Clone, Copy)]
 pub struct VestSchedule {
     discriminator: u8,
+    version: u8,
     token_mint: Pubkey,
     authority: Pubkey,
     vault: Pubkey,
@@ -12,6 +16,33 @@ pub struct VestSchedule {
     total_duration: u64,
     step_duration: u64,
     bump: u8,
+    cosigner: Pubkey,
+    cosign_threshold: u64,
+    claim_window: u64,
+    vault_authority: Pubkey,
+    vault_authority_bump: u8,
+    fee_recipient: Pubkey,
+    claim_fee_bps: u16,
+    token_program: Pubkey,
+    allow_add_after_start: u8,
+    vesting_mode: u8,
+    cliff_unlock_bps: u64,
+    revoke_destination: Pubkey,
+    total_allocated: u64,
+    pause_freezes_accrual: u8,
+    paused_at: u64,
+    total_paused: u64,
+    max_participants: u64,
+    participant_count: u64,
+    total_claimed: u64,
+    min_claim_amount: u64,
+    claims_frozen: u8,
+    allow_escrow_destination: u8,
+    label: [u8; VestSchedule::LABEL_LEN],
+    claim_cooldown: u64,
+    cooldown_exempts_participant: u8,
+    paused: u8,
+    generation: u32,
 }
 
 use crate::Discriminator;
@@ -22,14 +53,35 @@ impl Discriminator for VestSchedule {
 }
 
 impl VestSchedule {
-    pub const LEN: usize = size_of::<Pubkey>() * 3 + size_of::<u64>() * 5 + size_of::<u8>() * 2;
+    pub const LEN: usize = size_of::<Pubkey>() * 8
+        + size_of::<u64>() * 16
+        + size_of::<u16>()
+        + size_of::<u32>()
+        + size_of::<u8>() * 11
+        + Self::LABEL_LEN;
     pub const DISCRIMINATOR: u8 = 0;
+    pub const MAX_CLAIM_FEE_BPS: u16 = 10_000;
+    pub const MAX_CLIFF_UNLOCK_BPS: u64 = 10_000;
+    pub const LABEL_LEN: usize = 16;
+    /// Unlocks in whole `step_duration` increments after the cliff.
+    pub const VESTING_MODE_STEPPED: u8 = 0;
+    /// Unlocks continuously, per second, between the cliff and the end of the schedule.
+    pub const VESTING_MODE_LINEAR: u8 = 1;
+    /// Unlocks in whole steps like stepped mode, but the vested share grows with the square
+    /// of the elapsed steps, so most of the allocation unlocks near the end.
+    pub const VESTING_MODE_BACKLOADED: u8 = 2;
+    /// Unlocks the whole allocation the moment the cliff ends. The schedule ends with the
+    /// cliff: `Initialize` stores the total and step durations as the cliff duration.
+    pub const VESTING_MODE_CLIFF: u8 = 3;
+    /// Latest timestamp the cluster clock, an `i64`, can report.
+    pub const MAX_TIMESTAMP: u64 = i64::MAX as u64;
 
     #[inline(always)]
     pub fn load_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
         if bytes.len() != VestSchedule::LEN {
             return Err(ProgramError::InvalidAccountData);
         }
+        Self::check_version(bytes[1])?;
         Ok(unsafe { &mut *core::mem::transmute::<*mut u8, *mut Self>(bytes.as_mut_ptr()) })
     }
 
@@ -38,39 +90,358 @@ impl VestSchedule {
         if bytes.len() != VestSchedule::LEN {
             return Err(ProgramError::InvalidAccountData);
         }
+        Self::check_version(bytes[1])?;
 
         Ok(unsafe { &*core::mem::transmute::<*const u8, *const Self>(bytes.as_ptr()) })
     }
 
+    /// Version 0 is a freshly allocated, not yet written account.
+    #[inline(always)]
+    fn check_version(version: u8) -> Result<(), ProgramError> {
+        match version {
+            0..=ACCOUNT_VERSION => Ok(()),
+            _ => Err(PinocchioError::UnsupportedVersion.into()),
+        }
+    }
+
+    #[inline(always)]
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Copy of the schedule with its cliff and total duration replaced, for participants on
+    /// their own timeline. A zero override keeps the schedule's value. Cliff-only schedules
+    /// end with the cliff, so their total override is ignored.
+    #[inline(always)]
+    pub fn with_duration_overrides(&self, cliff_override: u64, total_override: u64) -> Self {
+        let mut terms = *self;
+        if cliff_override != 0 {
+            terms.cliff_duration = cliff_override;
+        }
+        if total_override != 0 {
+            terms.total_duration = total_override;
+        }
+        if terms.is_cliff_only() {
+            terms.total_duration = terms.cliff_duration;
+            terms.step_duration = terms.cliff_duration;
+        }
+        terms
+    }
+
+    /// Rejects durations the vesting math cannot handle. `Initialize` never writes such a
+    /// schedule, but a legacy or crafted account with no whole step after the cliff would
+    /// otherwise vest nothing and silently lock its allocations.
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        if self.is_cliff_only() {
+            if self.cliff_duration == 0 || self.total_duration != self.cliff_duration {
+                return Err(PinocchioError::InvalidDurations.into());
+            }
+
+            return Ok(());
+        }
+
+        if self.cliff_duration >= self.total_duration {
+            return Err(PinocchioError::InvalidDurations.into());
+        }
+
+        if !self.is_linear() && (self.step_duration == 0 || self.total_steps() == 0) {
+            return Err(PinocchioError::InvalidDurations.into());
+        }
+
+        Ok(())
+    }
+
+    /// Whether the schedule ends by `MAX_TIMESTAMP`. Participant timelines are held to this,
+    /// so their durations fit in an `i64` and adding one to any start `UpdateStart` accepts
+    /// can't wrap.
+    #[inline(always)]
+    pub fn ends_within_clock(&self) -> bool {
+        self.start_timestamp
+            .checked_add(self.total_duration)
+            .is_some_and(|end| end <= Self::MAX_TIMESTAMP)
+    }
+
+    /// Phase of the vesting curve at `current_timestamp`, after any frozen pause time.
+    ///
+    /// The phase ends saturate rather than wrap, so a crafted schedule whose end doesn't fit
+    /// in a u64 stays locked instead of wrapping into the past and vesting early.
+    #[inline(always)]
+    pub fn status(&self, current_timestamp: u64) -> VestStatus {
+        let current_timestamp = self.accrual_timestamp(current_timestamp);
+
+        if current_timestamp < self.start_timestamp {
+            VestStatus::NotStarted
+        } else if current_timestamp < self.start_timestamp.saturating_add(self.cliff_duration) {
+            VestStatus::Cliff
+        } else if current_timestamp < self.start_timestamp.saturating_add(self.total_duration) {
+            VestStatus::Stepping
+        } else {
+            VestStatus::Completed
+        }
+    }
+
+    /// Step amounts are rounded down, so mid-schedule the vested amount can trail the exact
+    /// share by under one token per step. Nothing is stranded: the last step and completion
+    /// both vest the full allocation, releasing the rounding remainder.
+    #[inline(always)]
+    pub fn vested_amount(&self, current_timestamp: u64, total_allocated_amount: u64) -> u64 {
+        match self.status(current_timestamp) {
+            VestStatus::NotStarted | VestStatus::Cliff => return 0,
+            VestStatus::Completed => return total_allocated_amount,
+            VestStatus::Stepping => {}
+        }
+
+        let current_timestamp = self.accrual_timestamp(current_timestamp);
+        let elapsed_time = current_timestamp.saturating_sub(self.start_timestamp);
+
+        if self.is_linear() {
+            let vesting_elapsed = elapsed_time.saturating_sub(self.cliff_duration);
+            let vesting_period = self.total_duration - self.cliff_duration;
+            let cliff_unlock = self.cliff_unlock_amount(total_allocated_amount);
+
+            return Self::clamp_to_allocation(
+                cliff_unlock as u128
+                    + ((total_allocated_amount - cliff_unlock) as u128)
+                        .saturating_mul(vesting_elapsed as u128)
+                        .saturating_div(vesting_period as u128),
+                total_allocated_amount,
+            );
+        }
+
+        // A schedule without a whole step (zero step duration, written out of band) has no
+        // curve to follow: it vests only the cliff unlock until completion instead of dividing
+        // by zero
+        if self.total_steps() == 0 {
+            return self.cliff_unlock_amount(total_allocated_amount);
+        }
+
+        let steps_elapsed = elapsed_time.saturating_sub(self.cliff_duration) / self.step_duration;
+
+        self.vested_at_step(steps_elapsed, total_allocated_amount)
+    }
+
+    /// Number of steps between the cliff and the end of the schedule. `Initialize` makes the
+    /// step duration non-zero and no longer than that period; for a schedule that breaks this
+    /// (e.g. created out of band) this is zero rather than a division panic.
+    #[inline(always)]
+    pub fn total_steps(&self) -> u64 {
+        self.total_duration
+            .saturating_sub(self.cliff_duration)
+            .checked_div(self.step_duration)
+            .unwrap_or(0)
+    }
+
+    /// Part of `total_allocated_amount` unlocked at once when the cliff ends, rounded down.
+    /// Never more than the allocation, even for a crafted schedule above 10,000 bps.
+    #[inline(always)]
+    pub fn cliff_unlock_amount(&self, total_allocated_amount: u64) -> u64 {
+        Self::clamp_to_allocation(
+            (total_allocated_amount as u128) * (self.cliff_unlock_bps as u128)
+                / Self::MAX_CLIFF_UNLOCK_BPS as u128,
+            total_allocated_amount,
+        )
+    }
+
+    /// Narrows a vested amount computed in u128 back to u64. Every curve keeps its products
+    /// in u128 and divides before narrowing, so any u64 allocation is supported and there is
+    /// no allocation cap; a result past the allocation, which only rounding bugs or crafted
+    /// fields could produce, vests the allocation instead of wrapping.
+    #[inline(always)]
+    fn clamp_to_allocation(vested_amount: u128, total_allocated_amount: u64) -> u64 {
+        vested_amount.min(total_allocated_amount as u128) as u64
+    }
+
+    /// Amount of `total_allocated_amount` vested once the first `step` steps have unlocked.
+    /// Step 0 is the end of the cliff, where only the cliff unlock has vested.
+    ///
+    /// The total is rounded down as a whole rather than per step, so allocations smaller than
+    /// the step count (e.g. 3 units of a 0-decimal mint over 9 steps) carry the fraction over:
+    /// steps that don't complete a unit vest nothing, and the unit becomes claimable at the
+    /// first step that does.
+    ///
+    /// Backloaded schedules vest `(step / total_steps)^2` of the stepped amount, scaling by
+    /// the step ratio twice in u128 so no intermediate product overflows; the last step
+    /// still vests the full allocation.
+    #[inline(always)]
+    pub fn vested_at_step(&self, step: u64, total_allocated_amount: u64) -> u64 {
+        let cliff_unlock = self.cliff_unlock_amount(total_allocated_amount);
+        if self.total_steps() == 0 {
+            return cliff_unlock;
+        }
+
+        if self.is_backloaded() {
+            let step = step.min(self.total_steps()) as u128;
+            let total_steps = self.total_steps() as u128;
+            let stepped_amount = (total_allocated_amount - cliff_unlock) as u128;
+
+            return Self::clamp_to_allocation(
+                cliff_unlock as u128 + stepped_amount * step / total_steps * step / total_steps,
+                total_allocated_amount,
+            );
+        }
+
+        Self::clamp_to_allocation(
+            cliff_unlock as u128
+                + ((total_allocated_amount - cliff_unlock) as u128)
+                    .saturating_mul(step.min(self.total_steps()) as u128)
+                    .saturating_div(self.total_steps() as u128),
+            total_allocated_amount,
+        )
+    }
+
+    /// Number of whole steps of `total_allocated_amount` that `amount` covers, i.e. the
+    /// largest `step` with `vested_at_step(step) <= amount`. The cliff unlock is not a step.
+    #[inline(always)]
+    pub fn steps_covered(&self, amount: u64, total_allocated_amount: u64) -> u64 {
+        let cliff_unlock = self.cliff_unlock_amount(total_allocated_amount);
+        let stepped_amount = (total_allocated_amount - cliff_unlock) as u128;
+        let total_steps = self.total_steps() as u128;
+        if amount < cliff_unlock || stepped_amount == 0 || total_steps == 0 {
+            return 0;
+        }
+
+        // The backloaded curve has no closed-form inverse, but it is monotonic in the step
+        if self.is_backloaded() {
+            let (mut covered, mut uncovered) = (0, self.total_steps() + 1);
+            while uncovered - covered > 1 {
+                let step = covered + (uncovered - covered) / 2;
+                if self.vested_at_step(step, total_allocated_amount) <= amount {
+                    covered = step;
+                } else {
+                    uncovered = step;
+                }
+            }
+            return covered;
+        }
+
+        let covered = (((amount - cliff_unlock) as u128 + 1) * total_steps - 1) / stepped_amount;
+
+        covered.min(total_steps) as u64
+    }
+
+    /// Part of the vested amount that unlocked more than `claim_window` ago and can no
+    /// longer be claimed. Always zero when the schedule has no claim window.
+    #[inline(always)]
+    pub fn expired_amount(&self, current_timestamp: u64, total_allocated_amount: u64) -> u64 {
+        if self.claim_window == 0 {
+            return 0;
+        }
+
+        self.vested_amount(
+            current_timestamp.saturating_sub(self.claim_window),
+            total_allocated_amount,
+        )
+    }
+
+    /// `consumed_amount` is everything already taken out of the allocation, claimed or
+    /// forfeited.
     #[inline(always)]
     pub fn calculate_claimable_amount(
         &self,
         current_timestamp: u64,
         total_allocated_amount: u64,
-        claimed_amount: u64,
+        consumed_amount: u64,
     ) -> u64 {
-        if current_timestamp < self.start_timestamp + self.cliff_duration {
-            return 0;
+        let vested_amount = self.vested_amount(current_timestamp, total_allocated_amount);
+        let expired_amount = self.expired_amount(current_timestamp, total_allocated_amount);
+
+        vested_amount.saturating_sub(consumed_amount.max(expired_amount))
+    }
+
+    /// [`Self::calculate_claimable_amount`], but saying why nothing is claimable, so claims
+    /// can tell a participant to wait for the cliff, come back later, or stop trying, and
+    /// apart from all of those, that the claim window closed on what they had.
+    #[inline(always)]
+    pub fn claimable(
+        &self,
+        current_timestamp: u64,
+        total_allocated_amount: u64,
+        consumed_amount: u64,
+    ) -> Claimable {
+        if !self.is_cliff_completed(current_timestamp) {
+            return Claimable::BeforeCliff;
         }
 
-        if current_timestamp >= self.start_timestamp + self.total_duration {
-            return total_allocated_amount.saturating_sub(claimed_amount);
+        match self.calculate_claimable_amount(
+            current_timestamp,
+            total_allocated_amount,
+            consumed_amount,
+        ) {
+            0 if self.expired_amount(current_timestamp, total_allocated_amount)
+                > consumed_amount =>
+            {
+                Claimable::Expired
+            }
+            0 if self.is_completed(current_timestamp) => Claimable::Completed,
+            0 => Claimable::NothingNewYet,
+            amount => Claimable::Amount(amount),
         }
+    }
 
-        let elapsed_time = current_timestamp.saturating_sub(self.start_timestamp);
-        let steps_elapsed = elapsed_time.saturating_sub(self.cliff_duration) / self.step_duration;
-        let total_steps = (self.total_duration - self.cliff_duration) / self.step_duration;
+    #[inline(always)]
+    pub fn is_cliff_completed(&self, current_timestamp: u64) -> bool {
+        matches!(
+            self.status(current_timestamp),
+            VestStatus::Stepping | VestStatus::Completed
+        )
+    }
+
+    #[inline(always)]
+    pub fn is_completed(&self, current_timestamp: u64) -> bool {
+        matches!(self.status(current_timestamp), VestStatus::Completed)
+    }
+
+    /// Wall-clock time at which more of the allocation next vests: the end of the cliff, the
+    /// next step boundary, or the next second for linear schedules. Zero once vesting has
+    /// completed, and while a pause that freezes accrual is in progress, since nothing
+    /// unlocks until it is resumed.
+    #[inline(always)]
+    pub fn next_unlock_timestamp(&self, current_timestamp: u64) -> u64 {
+        if self.pause_freezes_accrual() && self.is_paused() {
+            return 0;
+        }
+
+        let accrual_timestamp = self.accrual_timestamp(current_timestamp);
+        let cliff_end = self.start_timestamp.saturating_add(self.cliff_duration);
+        let vesting_end = self.start_timestamp.saturating_add(self.total_duration);
 
-        let vested_amount = (total_allocated_amount as u128)
-            .saturating_mul(steps_elapsed as u128)
-            .saturating_div(total_steps as u128) as u64;
+        let next_unlock = match self.status(current_timestamp) {
+            VestStatus::NotStarted | VestStatus::Cliff => cliff_end,
+            VestStatus::Completed => return 0,
+            VestStatus::Stepping if self.is_linear() => accrual_timestamp + 1,
+            VestStatus::Stepping if self.total_steps() == 0 => vesting_end,
+            VestStatus::Stepping => {
+                let steps_elapsed = (accrual_timestamp - cliff_end) / self.step_duration;
+                cliff_end
+                    .saturating_add((steps_elapsed + 1).saturating_mul(self.step_duration))
+                    .min(vesting_end)
+            }
+        };
 
-        vested_amount.saturating_sub(claimed_amount)
+        // Back from the curve's clock to the wall clock
+        if self.pause_freezes_accrual() {
+            next_unlock.saturating_add(self.total_paused)
+        } else {
+            next_unlock
+        }
     }
 
+    /// Timestamp the vesting curve is evaluated at. A schedule that freezes accrual while
+    /// paused runs behind the wall clock by the time spent paused, including a pause that
+    /// is still in progress; otherwise this is `current_timestamp` itself.
     #[inline(always)]
-    pub fn is_cliff_completed(&self, current_timestamp: u64) -> bool {
-        current_timestamp >= self.start_timestamp + self.cliff_duration
+    pub fn accrual_timestamp(&self, current_timestamp: u64) -> u64 {
+        if !self.pause_freezes_accrual() {
+            return current_timestamp;
+        }
+
+        let current_timestamp = if self.is_paused() {
+            current_timestamp.min(self.paused_at)
+        } else {
+            current_timestamp
+        };
+
+        current_timestamp.saturating_sub(self.total_paused)
     }
 
     #[inline(always)]
@@ -83,11 +454,21 @@ impl VestSchedule {
         &self.authority
     }
 
+    #[inline(always)]
+    pub fn set_authority(&mut self, authority: Pubkey) {
+        self.authority = authority;
+    }
+
     #[inline(always)]
     pub fn vault(&self) -> &Pubkey {
         &self.vault
     }
 
+    #[inline(always)]
+    pub fn set_vault(&mut self, vault: Pubkey) {
+        self.vault = vault;
+    }
+
     #[inline(always)]
     pub fn seed(&self) -> u64 {
         self.seed
@@ -98,6 +479,12 @@ impl VestSchedule {
         self.start_timestamp
     }
 
+    /// Only valid before the schedule has started; `UpdateStart` enforces this.
+    #[inline(always)]
+    pub fn set_start_timestamp(&mut self, start_timestamp: u64) {
+        self.start_timestamp = start_timestamp;
+    }
+
     #[inline(always)]
     pub fn cliff_duration(&self) -> u64 {
         self.cliff_duration
@@ -118,6 +505,412 @@ impl VestSchedule {
         self.bump
     }
 
+    #[inline(always)]
+    pub fn cosigner(&self) -> &Pubkey {
+        &self.cosigner
+    }
+
+    #[inline(always)]
+    pub fn cosign_threshold(&self) -> u64 {
+        self.cosign_threshold
+    }
+
+    #[inline(always)]
+    pub fn requires_cosign(&self, claimable_amount: u64) -> bool {
+        self.cosign_threshold != 0 && claimable_amount >= self.cosign_threshold
+    }
+
+    #[inline(always)]
+    pub fn set_cosign(&mut self, cosigner: Pubkey, cosign_threshold: u64) {
+        self.cosigner = cosigner;
+        self.cosign_threshold = cosign_threshold;
+    }
+
+    #[inline(always)]
+    pub fn claim_window(&self) -> u64 {
+        self.claim_window
+    }
+
+    #[inline(always)]
+    pub fn set_claim_window(&mut self, claim_window: u64) {
+        self.claim_window = claim_window;
+    }
+
+    /// A zeroed vault authority means the schedule PDA itself owns the vault.
+    #[inline(always)]
+    pub fn has_vault_authority(&self) -> bool {
+        self.vault_authority != Pubkey::default()
+    }
+
+    #[inline(always)]
+    pub fn vault_authority(&self) -> &Pubkey {
+        &self.vault_authority
+    }
+
+    #[inline(always)]
+    pub fn vault_authority_bump(&self) -> u8 {
+        self.vault_authority_bump
+    }
+
+    /// Owner of the vault token account: the vault authority PDA when one is configured,
+    /// otherwise the schedule account at `schedule`.
+    #[inline(always)]
+    pub fn vault_owner<'a>(&'a self, schedule: &'a Pubkey) -> &'a Pubkey {
+        if self.has_vault_authority() {
+            &self.vault_authority
+        } else {
+            schedule
+        }
+    }
+
+    #[inline(always)]
+    pub fn set_vault_authority(&mut self, vault_authority: Pubkey, vault_authority_bump: u8) {
+        self.vault_authority = vault_authority;
+        self.vault_authority_bump = vault_authority_bump;
+    }
+
+    #[inline(always)]
+    pub fn fee_recipient(&self) -> &Pubkey {
+        &self.fee_recipient
+    }
+
+    #[inline(always)]
+    pub fn claim_fee_bps(&self) -> u16 {
+        self.claim_fee_bps
+    }
+
+    /// Fee withheld from a claim of `claimable_amount`, rounded down.
+    #[inline(always)]
+    pub fn claim_fee(&self, claimable_amount: u64) -> u64 {
+        ((claimable_amount as u128) * (self.claim_fee_bps as u128)
+            / (Self::MAX_CLAIM_FEE_BPS as u128)) as u64
+    }
+
+    #[inline(always)]
+    pub fn set_claim_fee(&mut self, fee_recipient: Pubkey, claim_fee_bps: u16) {
+        self.fee_recipient = fee_recipient;
+        self.claim_fee_bps = claim_fee_bps;
+    }
+
+    #[inline(always)]
+    pub fn token_program(&self) -> &Pubkey {
+        &self.token_program
+    }
+
+    #[inline(always)]
+    pub fn set_token_program(&mut self, token_program: Pubkey) {
+        self.token_program = token_program;
+    }
+
+    /// Whether participants may still be added between the start and the end of the cliff.
+    #[inline(always)]
+    pub fn allow_add_after_start(&self) -> bool {
+        self.allow_add_after_start != 0
+    }
+
+    #[inline(always)]
+    pub fn set_allow_add_after_start(&mut self, allow: bool) {
+        self.allow_add_after_start = allow as u8;
+    }
+
+    #[inline(always)]
+    pub fn vesting_mode(&self) -> u8 {
+        self.vesting_mode
+    }
+
+    #[inline(always)]
+    pub fn is_linear(&self) -> bool {
+        self.vesting_mode == Self::VESTING_MODE_LINEAR
+    }
+
+    #[inline(always)]
+    pub fn is_backloaded(&self) -> bool {
+        self.vesting_mode == Self::VESTING_MODE_BACKLOADED
+    }
+
+    #[inline(always)]
+    pub fn is_cliff_only(&self) -> bool {
+        self.vesting_mode == Self::VESTING_MODE_CLIFF
+    }
+
+    #[inline(always)]
+    pub fn set_vesting_mode(&mut self, vesting_mode: u8) {
+        self.vesting_mode = vesting_mode;
+    }
+
+    #[inline(always)]
+    pub fn cliff_unlock_bps(&self) -> u64 {
+        self.cliff_unlock_bps
+    }
+
+    #[inline(always)]
+    pub fn set_cliff_unlock_bps(&mut self, cliff_unlock_bps: u64) {
+        self.cliff_unlock_bps = cliff_unlock_bps;
+    }
+
+    /// Token account that receives revoked, unvested tokens. All zeroes means the
+    /// authority's ATA.
+    #[inline(always)]
+    pub fn revoke_destination(&self) -> &Pubkey {
+        &self.revoke_destination
+    }
+
+    #[inline(always)]
+    pub fn has_revoke_destination(&self) -> bool {
+        self.revoke_destination != Pubkey::default()
+    }
+
+    #[inline(always)]
+    pub fn set_revoke_destination(&mut self, revoke_destination: Pubkey) {
+        self.revoke_destination = revoke_destination;
+    }
+
+    /// Sum of all participant allocations still committed to the schedule.
+    #[inline(always)]
+    pub fn total_allocated(&self) -> u64 {
+        self.total_allocated
+    }
+
+    /// Commits `amount` more to participants, failing with `AllocationOverflow` on wrap.
+    #[inline(always)]
+    pub fn add_allocation(&mut self, amount: u64) -> Result<(), ProgramError> {
+        self.total_allocated = self
+            .total_allocated
+            .checked_add(amount)
+            .ok_or(PinocchioError::AllocationOverflow)?;
+        Ok(())
+    }
+
+    /// Releases `amount` returned from participants. Only allocations not yet paid out of the
+    /// vault can be released, so dropping below `total_claimed` means the accounting is
+    /// already off; it fails with `AllocationOverflow` instead of clamping.
+    #[inline(always)]
+    pub fn remove_allocation(&mut self, amount: u64) -> Result<(), ProgramError> {
+        self.total_allocated = self
+            .total_allocated
+            .checked_sub(amount)
+            .filter(|remaining| *remaining >= self.total_claimed)
+            .ok_or(PinocchioError::AllocationOverflow)?;
+        Ok(())
+    }
+
+    /// Sum of every claim paid out of the vault, fees included. Claimed amounts recorded with
+    /// `SetClaimed` were paid elsewhere and are not part of it.
+    #[inline(always)]
+    pub fn total_claimed(&self) -> u64 {
+        self.total_claimed
+    }
+
+    #[inline(always)]
+    pub fn add_claimed(&mut self, amount: u64) -> Result<(), ProgramError> {
+        self.total_claimed = self
+            .total_claimed
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Allocations not yet paid out of the vault: what the vault must still hold for
+    /// participants.
+    #[inline(always)]
+    pub fn outstanding(&self) -> u64 {
+        self.total_allocated.saturating_sub(self.total_claimed)
+    }
+
+    /// Part of `vault_balance` no participant can ever claim: everything beyond the
+    /// outstanding allocations.
+    #[inline(always)]
+    pub fn surplus(&self, vault_balance: u64) -> u64 {
+        vault_balance.saturating_sub(self.outstanding())
+    }
+
+    /// Smallest amount a claim may release before the participant's vesting completes, so
+    /// dust isn't cranked out step by step. Zero means no threshold.
+    #[inline(always)]
+    pub fn min_claim_amount(&self) -> u64 {
+        self.min_claim_amount
+    }
+
+    #[inline(always)]
+    pub fn set_min_claim_amount(&mut self, min_claim_amount: u64) {
+        self.min_claim_amount = min_claim_amount;
+    }
+
+    /// Whether claims are held by `FreezeClaims`. Vesting keeps accruing either way.
+    #[inline(always)]
+    pub fn claims_frozen(&self) -> bool {
+        self.claims_frozen != 0
+    }
+
+    #[inline(always)]
+    pub fn set_claims_frozen(&mut self, frozen: bool) {
+        self.claims_frozen = frozen as u8;
+    }
+
+    /// Whether `ClaimToEscrow` may pay into any token account of the mint, not just an ATA.
+    #[inline(always)]
+    pub fn allow_escrow_destination(&self) -> bool {
+        self.allow_escrow_destination != 0
+    }
+
+    #[inline(always)]
+    pub fn set_allow_escrow_destination(&mut self, allow: bool) {
+        self.allow_escrow_destination = allow as u8;
+    }
+
+    /// Human-readable name for dashboards, e.g. `SEED-ROUND`: UTF-8, zero-padded on the right.
+    #[inline(always)]
+    pub fn label(&self) -> &[u8; Self::LABEL_LEN] {
+        &self.label
+    }
+
+    #[inline(always)]
+    pub fn set_label(&mut self, label: [u8; Self::LABEL_LEN]) {
+        self.label = label;
+    }
+
+    /// Which schedule of its seed this is, copied from the `["vest_gen", seed]` marker at
+    /// Initialize: 1 for the first, counting up each time the seed is reused after a close.
+    #[inline(always)]
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+
+    #[inline(always)]
+    pub fn set_generation(&mut self, generation: u32) {
+        self.generation = generation;
+    }
+
+    /// Minimum number of seconds between two claims of the same participant, so a keeper
+    /// can't crank dust out every slot. Zero means no cooldown.
+    #[inline(always)]
+    pub fn claim_cooldown(&self) -> u64 {
+        self.claim_cooldown
+    }
+
+    /// Whether claims the participant signs themselves skip the cooldown, which then only
+    /// holds back crank and delegate claims.
+    #[inline(always)]
+    pub fn cooldown_exempts_participant(&self) -> bool {
+        self.cooldown_exempts_participant != 0
+    }
+
+    #[inline(always)]
+    pub fn set_claim_cooldown(&mut self, claim_cooldown: u64, exempts_participant: bool) {
+        self.claim_cooldown = claim_cooldown;
+        self.cooldown_exempts_participant = exempts_participant as u8;
+    }
+
+    /// Whether a claim at `current_timestamp` comes too soon after `last_claim_ts`, zero
+    /// meaning no earlier claim. Claims signed by the participant are let through when the
+    /// schedule exempts them.
+    #[inline(always)]
+    pub fn is_cooling_down(
+        &self,
+        last_claim_ts: u64,
+        current_timestamp: u64,
+        signed_by_participant: bool,
+    ) -> bool {
+        if signed_by_participant && self.cooldown_exempts_participant() {
+            return false;
+        }
+
+        last_claim_ts != 0 && current_timestamp.saturating_sub(last_claim_ts) < self.claim_cooldown
+    }
+
+    /// Whether a pause stops the vesting curve (legal hold) instead of only blocking claims.
+    #[inline(always)]
+    pub fn pause_freezes_accrual(&self) -> bool {
+        self.pause_freezes_accrual != 0
+    }
+
+    #[inline(always)]
+    pub fn set_pause_freezes_accrual(&mut self, freezes: bool) {
+        self.pause_freezes_accrual = freezes as u8;
+    }
+
+    /// When the current pause started. Zero while the schedule is not paused, but a pause
+    /// can start at timestamp zero too, so [`Self::is_paused`] is the flag to check.
+    #[inline(always)]
+    pub fn paused_at(&self) -> u64 {
+        self.paused_at
+    }
+
+    #[inline(always)]
+    pub fn is_paused(&self) -> bool {
+        self.paused != 0
+    }
+
+    /// Total time spent in completed pauses that froze accrual.
+    #[inline(always)]
+    pub fn total_paused(&self) -> u64 {
+        self.total_paused
+    }
+
+    /// Single entry point for pause state. Pausing sets the `paused` flag and records
+    /// `current_timestamp` as `paused_at`; resuming clears both and, when accrual was frozen,
+    /// adds the pause's length to
+    /// `total_paused`. Setting the state the schedule is already in changes nothing.
+    #[inline(always)]
+    pub fn set_paused(&mut self, paused: bool, current_timestamp: u64) {
+        if paused == self.is_paused() {
+            return;
+        }
+
+        self.paused = paused as u8;
+
+        if paused {
+            self.paused_at = current_timestamp;
+            return;
+        }
+
+        if self.pause_freezes_accrual() {
+            self.total_paused = self
+                .total_paused
+                .saturating_add(current_timestamp.saturating_sub(self.paused_at));
+        }
+        self.paused_at = 0;
+    }
+
+    /// Cap on participant states created against the schedule. Zero means unlimited.
+    #[inline(always)]
+    pub fn max_participants(&self) -> u64 {
+        self.max_participants
+    }
+
+    #[inline(always)]
+    pub fn set_max_participants(&mut self, max_participants: u64) {
+        self.max_participants = max_participants;
+    }
+
+    /// Participant states created so far. Closing or revoking a participant doesn't lower it.
+    #[inline(always)]
+    pub fn participant_count(&self) -> u64 {
+        self.participant_count
+    }
+
+    /// Counts `count` new participants, failing with `ParticipantCapReached` if that would
+    /// take the schedule past `max_participants`.
+    #[inline(always)]
+    pub fn add_participants(&mut self, count: u64) -> Result<(), ProgramError> {
+        let participant_count = self
+            .participant_count
+            .checked_add(count)
+            .ok_or(PinocchioError::ParticipantCapReached)?;
+
+        if self.max_participants != 0 && participant_count > self.max_participants {
+            return Err(PinocchioError::ParticipantCapReached.into());
+        }
+
+        self.participant_count = participant_count;
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn has_started(&self, current_timestamp: u64) -> bool {
+        current_timestamp >= self.start_timestamp
+    }
+
     #[inline(always)]
     pub fn set_inner(
         &mut self,
@@ -132,6 +925,7 @@ impl VestSchedule {
         bump: u8,
     ) {
         self.discriminator = VestSchedule::DISCRIMINATOR;
+        self.version = ACCOUNT_VERSION;
         self.token_mint = token_mint;
         self.authority = authority;
         self.vault = vault;
@@ -142,4 +936,216 @@ impl VestSchedule {
         self.step_duration = step_duration;
         self.bump = bump;
     }
+
+    /// Copies every field out by value into an aligned struct, so callers never take a
+    /// reference into the packed layout.
+    pub fn snapshot(&self) -> VestScheduleSnapshot {
+        VestScheduleSnapshot {
+            discriminator: self.discriminator,
+            version: self.version,
+            token_mint: self.token_mint,
+            authority: self.authority,
+            vault: self.vault,
+            seed: self.seed,
+            start_timestamp: self.start_timestamp,
+            cliff_duration: self.cliff_duration,
+            total_duration: self.total_duration,
+            step_duration: self.step_duration,
+            bump: self.bump,
+            cosigner: self.cosigner,
+            cosign_threshold: self.cosign_threshold,
+            claim_window: self.claim_window,
+            vault_authority: self.vault_authority,
+            vault_authority_bump: self.vault_authority_bump,
+            fee_recipient: self.fee_recipient,
+            claim_fee_bps: self.claim_fee_bps,
+            token_program: self.token_program,
+            allow_add_after_start: self.allow_add_after_start != 0,
+            vesting_mode: self.vesting_mode,
+            cliff_unlock_bps: self.cliff_unlock_bps,
+            revoke_destination: self.revoke_destination,
+            total_allocated: self.total_allocated,
+            pause_freezes_accrual: self.pause_freezes_accrual != 0,
+            paused_at: self.paused_at,
+            total_paused: self.total_paused,
+            max_participants: self.max_participants,
+            participant_count: self.participant_count,
+            total_claimed: self.total_claimed,
+            min_claim_amount: self.min_claim_amount,
+            claims_frozen: self.claims_frozen != 0,
+            allow_escrow_destination: self.allow_escrow_destination != 0,
+            label: self.label,
+            claim_cooldown: self.claim_cooldown,
+            cooldown_exempts_participant: self.cooldown_exempts_participant != 0,
+            paused: self.paused != 0,
+            generation: self.generation,
+        }
+    }
+}
+
+#[cfg(feature = "client")]
+impl VestSchedule {
+    /// Decodes fetched account data into an owned [`VestScheduleSnapshot`], so clients never
+    /// cast the bytes themselves. Rejects data of the wrong length, version or account type.
+    pub fn from_bytes(bytes: &[u8]) -> Result<VestScheduleSnapshot, ProgramError> {
+        let schedule = Self::load(bytes)?;
+
+        if schedule.discriminator != Self::DISCRIMINATOR {
+            return Err(PinocchioError::InvalidDiscriminator.into());
+        }
+
+        Ok(schedule.snapshot())
+    }
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+impl VestSchedule {
+    /// Stepped schedule as `Initialize` would write it, built in memory for host-side tests.
+    pub fn new_for_test(
+        start_timestamp: u64,
+        cliff_duration: u64,
+        total_duration: u64,
+        step_duration: u64,
+    ) -> Self {
+        // Every field is a plain integer or byte array, so all zeroes is a valid value
+        let mut schedule: Self = unsafe { core::mem::zeroed() };
+        schedule.set_inner(
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            1,
+            start_timestamp,
+            cliff_duration,
+            total_duration,
+            step_duration,
+            0,
+        );
+        schedule
+    }
+}
+
+/// Where a schedule is on its vesting curve. `Stepping` covers linear schedules too.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VestStatus {
+    /// Before `start_timestamp`.
+    NotStarted,
+    /// From the start until the cliff ends; nothing is vested.
+    Cliff,
+    /// From the end of the cliff until the end of the vesting period.
+    Stepping,
+    /// Everything is vested.
+    Completed,
+}
+
+/// Outcome of evaluating a claim at some timestamp, from [`VestSchedule::claimable`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Claimable {
+    /// The cliff hasn't ended, so nothing has vested yet.
+    BeforeCliff,
+    /// Everything vested so far is claimed or forfeited; more vests later.
+    NothingNewYet,
+    /// Vesting has completed and nothing is left to claim.
+    Completed,
+    /// What vested left the claim window unclaimed and is not recorded as forfeited yet.
+    Expired,
+    /// This much can be claimed now.
+    Amount(u64),
+}
+
+/// Aligned copy of a [`VestSchedule`] for clients. Fields are in account order, and
+/// `to_bytes` serializes them the way borsh would: little-endian integers and a bool as one
+/// 0/1 byte, without padding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VestScheduleSnapshot {
+    pub discriminator: u8,
+    pub version: u8,
+    pub token_mint: Pubkey,
+    pub authority: Pubkey,
+    pub vault: Pubkey,
+    pub seed: u64,
+    pub start_timestamp: u64,
+    pub cliff_duration: u64,
+    pub total_duration: u64,
+    pub step_duration: u64,
+    pub bump: u8,
+    pub cosigner: Pubkey,
+    pub cosign_threshold: u64,
+    pub claim_window: u64,
+    pub vault_authority: Pubkey,
+    pub vault_authority_bump: u8,
+    pub fee_recipient: Pubkey,
+    pub claim_fee_bps: u16,
+    pub token_program: Pubkey,
+    pub allow_add_after_start: bool,
+    pub vesting_mode: u8,
+    pub cliff_unlock_bps: u64,
+    pub revoke_destination: Pubkey,
+    pub total_allocated: u64,
+    pub pause_freezes_accrual: bool,
+    pub paused_at: u64,
+    pub total_paused: u64,
+    pub max_participants: u64,
+    pub participant_count: u64,
+    pub total_claimed: u64,
+    pub min_claim_amount: u64,
+    pub claims_frozen: bool,
+    pub allow_escrow_destination: bool,
+    pub label: [u8; VestSchedule::LABEL_LEN],
+    pub claim_cooldown: u64,
+    pub cooldown_exempts_participant: bool,
+    pub paused: bool,
+    pub generation: u32,
+}
+
+impl VestScheduleSnapshot {
+    /// Serialized size, which matches the packed account layout byte for byte.
+    pub const LEN: usize = VestSchedule::LEN;
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut bytes = [0u8; Self::LEN];
+        let mut offset = 0;
+        let mut put = |field: &[u8]| {
+            bytes[offset..offset + field.len()].copy_from_slice(field);
+            offset += field.len();
+        };
+
+        put(&[self.discriminator, self.version]);
+        put(&self.token_mint);
+        put(&self.authority);
+        put(&self.vault);
+        put(&self.seed.to_le_bytes());
+        put(&self.start_timestamp.to_le_bytes());
+        put(&self.cliff_duration.to_le_bytes());
+        put(&self.total_duration.to_le_bytes());
+        put(&self.step_duration.to_le_bytes());
+        put(&[self.bump]);
+        put(&self.cosigner);
+        put(&self.cosign_threshold.to_le_bytes());
+        put(&self.claim_window.to_le_bytes());
+        put(&self.vault_authority);
+        put(&[self.vault_authority_bump]);
+        put(&self.fee_recipient);
+        put(&self.claim_fee_bps.to_le_bytes());
+        put(&self.token_program);
+        put(&[self.allow_add_after_start as u8, self.vesting_mode]);
+        put(&self.cliff_unlock_bps.to_le_bytes());
+        put(&self.revoke_destination);
+        put(&self.total_allocated.to_le_bytes());
+        put(&[self.pause_freezes_accrual as u8]);
+        put(&self.paused_at.to_le_bytes());
+        put(&self.total_paused.to_le_bytes());
+        put(&self.max_participants.to_le_bytes());
+        put(&self.participant_count.to_le_bytes());
+        put(&self.total_claimed.to_le_bytes());
+        put(&self.min_claim_amount.to_le_bytes());
+        put(&[self.claims_frozen as u8]);
+        put(&[self.allow_escrow_destination as u8]);
+        put(&self.label);
+        put(&self.claim_cooldown.to_le_bytes());
+        put(&[self.cooldown_exempts_participant as u8]);
+        put(&[self.paused as u8]);
+        put(&self.generation.to_le_bytes());
+
+        bytes
+    }
 }
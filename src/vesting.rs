@@ -0,0 +1,24 @@
+use pinocchio::pubkey::Pubkey;
+
+pub const VEST_SCHEDULE_SEED: &[u8] = b"vest_schedule";
+pub const VEST_PARTICIPANT_SEED: &[u8] = b"vest_participant";
+pub const VAULT_AUTHORITY_SEED: &[u8] = b"vault";
+pub const CONFIG_SEED: &[u8] = b"config";
+pub const VEST_GENERATION_SEED: &[u8] = b"vest_gen";
+pub const VEST_REGISTRY_SEED: &[u8] = b"vest_registry";
+
+/// SPL Token-2022 program (`TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`).
+pub const TOKEN_2022_PROGRAM_ID: Pubkey = [
+    0x06, 0xdd, 0xf6, 0xe1, 0xee, 0x75, 0x8f, 0xde, 0x18, 0x42, 0x5d, 0xbc, 0xe4, 0x6c, 0xcd, 0xda,
+    0xb6, 0x1a, 0xfc, 0x4d, 0x83, 0xb9, 0x0d, 0x27, 0xfe, 0xbd, 0xf9, 0x28, 0xd8, 0xa1, 0x8b, 0xfc,
+];
+
+/// Upgradeable BPF loader (`BPFLoaderUpgradeab1e11111111111111111111111`), owner of the
+/// ProgramData account that records the program's upgrade authority.
+pub const BPF_LOADER_UPGRADEABLE_ID: Pubkey = [
+    0x02, 0xa8, 0xf6, 0x91, 0x4e, 0x88, 0xa1, 0xb0, 0xe2, 0x10, 0x15, 0x3e, 0xf7, 0x63, 0xae, 0x2b,
+    0x00, 0xc2, 0xb9, 0x3d, 0x16, 0xc1, 0x24, 0xd2, 0xc0, 0x53, 0x7a, 0x10, 0x04, 0x80, 0x00, 0x00,
+];
+
+/// Layout version written into every state account at creation.
+pub const ACCOUNT_VERSION: u8 = 1;
@@ -1,32 +1,41 @@
+mod common;
+
 #[cfg(test)]
 mod tests {
+    use crate::common::*;
+    use base64::{engine::general_purpose::STANDARD, Engine};
     use litesvm::LiteSVM;
     use pinocchio_system::ID;
     use solana_sdk::{
-        account::Account,
-        clock::Clock,
-        instruction::{AccountMeta, Instruction},
+        instruction::{AccountMeta, Instruction, InstructionError},
         pubkey::Pubkey,
         signature::{Keypair, Signer},
-        transaction::Transaction,
+        transaction::{Transaction, TransactionError},
     };
-    use spl_token::solana_program::program_option::COption;
     use spl_token::solana_program::program_pack::Pack;
-    use spl_token::state::{Account as TokenAccount, AccountState, Mint};
+    use spl_token::state::Account as TokenAccount;
     use spl_token::ID as TOKEN_PROGRAM_ID;
-
-    const PROGRAM_ID: Pubkey = Pubkey::new_from_array([
-        0x0f, 0x1e, 0x6b, 0x14, 0x21, 0xc0, 0x4a, 0x07, 0x04, 0x31, 0x26, 0x5c, 0x19, 0xc5, 0xbb,
-        0xee, 0x19, 0x92, 0xba, 0xe8, 0xaf, 0xd1, 0xcd, 0x07, 0x8e, 0xf8, 0xaf, 0x70, 0x47, 0xdc,
-        0x11, 0xf7,
-    ]);
-
-    // January 1, 2025 00:00:00 UTC
-    const JAN_1_2025: i64 = 1735689600;
-    const ONE_DAY: u64 = 86_400;
-
-    // VestSchedule::LEN = discriminator(1) + 3*Pubkey(96) + 5*u64(40) + bump(1) = 138
-    const VEST_SCHEDULE_LEN: usize = 138;
+    use token_vesting::instruction_builder::{vest_participant_pda, vest_schedule_pda};
+    use token_vesting::ACCOUNT_VERSION;
+
+    // PinocchioError::InvalidDurations
+    const INVALID_DURATIONS_ERROR: u32 = 7;
+    // PinocchioError::ScheduleAlreadyStarted
+    const SCHEDULE_ALREADY_STARTED_ERROR: u32 = 18;
+    // PinocchioError::UnsupportedMintExtension
+    const UNSUPPORTED_MINT_EXTENSION_ERROR: u32 = 30;
+    // PinocchioError::OverAllocated
+    const OVER_ALLOCATED_ERROR: u32 = 31;
+    // PinocchioError::ParticipantMismatch
+    const PARTICIPANT_MISMATCH_ERROR: u32 = 35;
+    // PinocchioError::MintMismatch
+    const MINT_MISMATCH_ERROR: u32 = 29;
+    // PinocchioError::InsufficientDeposit
+    const INSUFFICIENT_DEPOSIT_ERROR: u32 = 33;
+    // PinocchioError::ParticipantCapReached
+    const PARTICIPANT_CAP_REACHED_ERROR: u32 = 37;
+
+    const TOKEN_2022_PROGRAM_ID: Pubkey = Pubkey::new_from_array(token_vesting::TOKEN_2022_PROGRAM_ID);
 
     fn create_add_participant_instruction_data(
         allocated_amount: u64,
@@ -38,165 +47,16 @@ mod tests {
         data
     }
 
-    fn derive_participant_pda(participant: &Pubkey, schedule: &Pubkey) -> (Pubkey, u8) {
-        Pubkey::find_program_address(
-            &[b"vest_participant", participant.as_ref(), schedule.as_ref()],
-            &PROGRAM_ID,
-        )
-    }
-
-    // Updated: PDA now uses only seed
-    fn derive_vest_schedule_pda(seed: u64) -> (Pubkey, u8) {
-        Pubkey::find_program_address(
-            &[b"vest_schedule", &seed.to_le_bytes()],
-            &PROGRAM_ID,
-        )
-    }
-
-    fn derive_ata(owner: &Pubkey, mint: &Pubkey) -> Pubkey {
-        spl_associated_token_account::get_associated_token_address(owner, mint)
-    }
-
-    fn setup_svm() -> LiteSVM {
-        let mut svm = LiteSVM::new().with_builtins().with_sigverify(false);
-
-        svm.add_program_from_file(PROGRAM_ID, "target/deploy/token_vesting.so")
-            .expect("Failed to load program");
-
-        // Warp to Jan 1, 2025
-        warp_to_timestamp(&mut svm, JAN_1_2025);
-
-        svm
-    }
-
-    fn warp_to_timestamp(svm: &mut LiteSVM, unix_timestamp: i64) {
-        let current_clock = svm.get_sysvar::<Clock>();
-        svm.set_sysvar(&Clock {
-            unix_timestamp,
-            ..current_clock
-        });
-    }
-
-    fn print_transaction_logs(
-        result: &Result<
-            litesvm::types::TransactionMetadata,
-            litesvm::types::FailedTransactionMetadata,
-        >,
-    ) {
-        match result {
-            Ok(meta) => {
-                println!("\n=== Transaction Succeeded ===");
-                for log in &meta.logs {
-                    println!("  {}", log);
-                }
-            }
-            Err(err) => {
-                println!("\n=== Transaction Failed ===");
-                println!("Error: {:?}", err.err);
-                for log in &err.meta.logs {
-                    println!("  {}", log);
-                }
-            }
-        }
-    }
-
-    fn create_mock_token_mint(svm: &mut LiteSVM, authority: &Pubkey) -> Pubkey {
-        let mint_keypair = Keypair::new();
-        let mint_pubkey = mint_keypair.pubkey();
-
-        let mint_data = Mint {
-            mint_authority: COption::Some(*authority),
-            supply: 1_000_000_000,
-            decimals: 6,
-            is_initialized: true,
-            freeze_authority: COption::None,
-        };
-
-        let mut data = vec![0u8; Mint::LEN];
-        Mint::pack(mint_data, &mut data).unwrap();
-
-        svm.set_account(mint_pubkey, Account {
-            lamports: 10_000_000,
-            data,
-            owner: TOKEN_PROGRAM_ID,
-            executable: false,
-            rent_epoch: 0,
-        }.into());
-
-        mint_pubkey
-    }
-
-    // Updated: VestSchedule now has discriminator and vault field (138 bytes)
-    fn create_vest_schedule(
-        svm: &mut LiteSVM,
-        authority: &Pubkey,
-        token_mint: &Pubkey,
-        seed: u64,
-        start_timestamp: u64,
-        cliff_duration: u64,
-        total_duration: u64,
-        step_duration: u64,
-    ) -> Pubkey {
-        let (schedule_pda, bump) = derive_vest_schedule_pda(seed);
-        let vault = derive_ata(&schedule_pda, token_mint);
-
-        // VestSchedule: 138 bytes
-        let mut schedule_data = Vec::with_capacity(VEST_SCHEDULE_LEN);
-        schedule_data.push(0u8); // Discriminator
-        schedule_data.extend_from_slice(token_mint.as_ref()); // Token mint: Pubkey (32)
-        schedule_data.extend_from_slice(authority.as_ref()); // Authority: Pubkey (32)
-        schedule_data.extend_from_slice(vault.as_ref()); // Vault: Pubkey (32)
-        schedule_data.extend_from_slice(&seed.to_le_bytes()); // Seed: u64 (8)
-        schedule_data.extend_from_slice(&start_timestamp.to_le_bytes()); // Start timestamp: u64 (8)
-        schedule_data.extend_from_slice(&cliff_duration.to_le_bytes()); // Cliff duration: u64 (8)
-        schedule_data.extend_from_slice(&total_duration.to_le_bytes()); // Total duration: u64 (8)
-        schedule_data.extend_from_slice(&step_duration.to_le_bytes()); // Step duration: u64 (8)
-        schedule_data.push(bump); // Bump: u8 (1)
-
-        assert_eq!(schedule_data.len(), VEST_SCHEDULE_LEN);
-
-        svm.set_account(schedule_pda, Account {
-            lamports: 10_000_000,
-            data: schedule_data,
-            owner: PROGRAM_ID,
-            executable: false,
-            rent_epoch: 0,
-        }.into());
-
-        schedule_pda
-    }
-
-    fn create_ata_with_balance(
-        svm: &mut LiteSVM,
-        owner: &Pubkey,
-        mint: &Pubkey,
-        amount: u64,
-    ) -> Pubkey {
-        let ata = derive_ata(owner, mint);
-
-        let token_account = TokenAccount {
-            mint: *mint,
-            owner: *owner,
-            amount,
-            delegate: COption::None,
-            state: AccountState::Initialized,
-            is_native: COption::None,
-            delegated_amount: 0,
-            close_authority: COption::None,
-        };
-
-        let mut data = vec![0u8; TokenAccount::LEN];
-        TokenAccount::pack(token_account, &mut data).unwrap();
-
-        svm.set_account(ata, Account {
-            lamports: 10_000_000,
-            data,
-            owner: TOKEN_PROGRAM_ID,
-            executable: false,
-            rent_epoch: 0,
-        }.into());
-
-        ata
+    fn create_add_participant_instruction_data_with_overrides(
+        allocated_amount: u64,
+        participant_bump: u8,
+        cliff_override: u64,
+        total_override: u64,
+    ) -> Vec<u8> {
+        let mut data = create_add_participant_instruction_data(allocated_amount, participant_bump);
+        data.extend_from_slice(&cliff_override.to_le_bytes());
+        data.extend_from_slice(&total_override.to_le_bytes());
+        data
     }
 
     fn build_add_participant_instruction(
@@ -257,7 +117,7 @@ mod tests {
 
         let authority_ata = create_ata_with_balance(&mut svm, &authority.pubkey(), &token_mint, 1_000_000);
 
-        let (participant_state, participant_bump) = derive_participant_pda(&participant.pubkey(), &schedule);
+        let (participant_state, participant_bump) = vest_participant_pda(&participant.pubkey(), &schedule);
 
         // Vault is owned by schedule, not participant_state
         let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, 0);
@@ -293,6 +153,11 @@ mod tests {
 
         let account = participant_account.unwrap();
         assert_eq!(account.owner, PROGRAM_ID, "Should be owned by program");
+        assert_eq!(
+            read_participant_state(&svm, &participant_state).version,
+            ACCOUNT_VERSION,
+            "Should carry the current layout version"
+        );
 
         // Verify token transfer happened
         let vault_account = svm.get_account(&vault).unwrap();
@@ -308,6 +173,68 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_add_participant_logs_activity_line() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+        let start_timestamp = (JAN_1_2025 + ONE_DAY as i64) as u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            12346,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        let authority_ata = create_ata_with_balance(&mut svm, &authority.pubkey(), &token_mint, 1_000_000);
+        let (participant_state, participant_bump) = vest_participant_pda(&participant.pubkey(), &schedule);
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, 0);
+
+        let instruction = build_add_participant_instruction(
+            &authority.pubkey(),
+            &authority_ata,
+            &vault,
+            &participant.pubkey(),
+            &participant_state,
+            &schedule,
+            &token_mint,
+            create_add_participant_instruction_data(100_000, participant_bump),
+        );
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&authority.pubkey()),
+            &[&authority],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        let meta = result.expect("Transaction should succeed");
+
+        // "Program data: " followed by base64 of ["add", participant, allocated]
+        let expected = format!(
+            "Program data: {} {} {}",
+            STANDARD.encode(b"add"),
+            STANDARD.encode(participant.pubkey()),
+            STANDARD.encode(100_000u64.to_le_bytes())
+        );
+        assert!(
+            meta.logs.iter().any(|log| log == &expected),
+            "Missing activity record {expected:?}"
+        );
+    }
+
     #[test]
     fn test_add_participant_after_cliff() {
         let mut svm = setup_svm();
@@ -339,7 +266,7 @@ mod tests {
 
         let authority_ata = create_ata_with_balance(&mut svm, &authority.pubkey(), &token_mint, 1_000_000);
 
-        let (participant_state, participant_bump) = derive_participant_pda(&participant.pubkey(), &schedule);
+        let (participant_state, participant_bump) = vest_participant_pda(&participant.pubkey(), &schedule);
         let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, 0);
 
         let allocated_amount = 100_000u64;
@@ -396,7 +323,7 @@ mod tests {
         // Only 50k tokens
         let authority_ata = create_ata_with_balance(&mut svm, &authority.pubkey(), &token_mint, 50_000);
 
-        let (participant_state, participant_bump) = derive_participant_pda(&participant.pubkey(), &schedule);
+        let (participant_state, participant_bump) = vest_participant_pda(&participant.pubkey(), &schedule);
         let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, 0);
 
         // Trying to allocate 100k
@@ -423,7 +350,10 @@ mod tests {
 
         let result = svm.send_transaction(transaction);
         print_transaction_logs(&result);
-        assert!(result.is_err(), "Should fail with insufficient funds");
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::Custom(INSUFFICIENT_DEPOSIT_ERROR))
+        );
     }
 
     #[test]
@@ -456,7 +386,7 @@ mod tests {
         // But we use wrong_authority
         let wrong_authority_ata = create_ata_with_balance(&mut svm, &wrong_authority.pubkey(), &token_mint, 1_000_000);
 
-        let (participant_state, participant_bump) = derive_participant_pda(&participant.pubkey(), &schedule);
+        let (participant_state, participant_bump) = vest_participant_pda(&participant.pubkey(), &schedule);
         let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, 0);
 
         let allocated_amount = 100_000u64;
@@ -512,7 +442,7 @@ mod tests {
 
         let authority_ata = create_ata_with_balance(&mut svm, &authority.pubkey(), &token_mint, 1_000_000);
 
-        let (participant_state, participant_bump) = derive_participant_pda(&participant.pubkey(), &schedule);
+        let (participant_state, participant_bump) = vest_participant_pda(&participant.pubkey(), &schedule);
         let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, 0);
 
         // Zero allocation
@@ -572,7 +502,7 @@ mod tests {
         // But we pass wrong_token_mint
         let authority_ata = create_ata_with_balance(&mut svm, &authority.pubkey(), &wrong_token_mint, 1_000_000);
 
-        let (participant_state, participant_bump) = derive_participant_pda(&participant.pubkey(), &schedule);
+        let (participant_state, participant_bump) = vest_participant_pda(&participant.pubkey(), &schedule);
         let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, 0);
 
         let allocated_amount = 100_000u64;
@@ -598,7 +528,10 @@ mod tests {
 
         let result = svm.send_transaction(transaction);
         print_transaction_logs(&result);
-        assert!(result.is_err(), "Should fail with wrong token mint");
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::Custom(MINT_MISMATCH_ERROR))
+        );
     }
 
     #[test]
@@ -628,7 +561,7 @@ mod tests {
 
         let authority_ata = create_ata_with_balance(&mut svm, &authority.pubkey(), &token_mint, 1_000_000);
 
-        let (participant_state, participant_bump) = derive_participant_pda(&participant.pubkey(), &schedule);
+        let (participant_state, participant_bump) = vest_participant_pda(&participant.pubkey(), &schedule);
 
         // Derive vault but DON'T create it
         let vault = derive_ata(&schedule, &token_mint);
@@ -686,7 +619,7 @@ mod tests {
 
         let authority_ata = create_ata_with_balance(&mut svm, &authority.pubkey(), &token_mint, 1_000_000);
 
-        let (participant_state, participant_bump) = derive_participant_pda(&participant.pubkey(), &schedule);
+        let (participant_state, participant_bump) = vest_participant_pda(&participant.pubkey(), &schedule);
         let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, 0);
 
         let allocated_amount = 100_000u64;
@@ -755,7 +688,7 @@ mod tests {
 
         let authority_ata = create_ata_with_balance(&mut svm, &authority.pubkey(), &token_mint, 1_000_000);
 
-        let (participant_state, correct_bump) = derive_participant_pda(&participant.pubkey(), &schedule);
+        let (participant_state, correct_bump) = vest_participant_pda(&participant.pubkey(), &schedule);
         let wrong_bump = correct_bump.wrapping_add(1);
         let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, 0);
 
@@ -813,7 +746,7 @@ mod tests {
 
         let authority_ata = create_ata_with_balance(&mut svm, &authority.pubkey(), &token_mint, 1_000_000);
 
-        let (participant_state, participant_bump) = derive_participant_pda(&participant.pubkey(), &schedule);
+        let (participant_state, participant_bump) = vest_participant_pda(&participant.pubkey(), &schedule);
         let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, 0);
 
         let allocated_amount = 100_000u64;
@@ -847,4 +780,719 @@ mod tests {
         print_transaction_logs(&result);
         assert!(result.is_err(), "Should fail when authority is not signer");
     }
-}
\ No newline at end of file
+
+    fn add_participant_after_start(
+        seed: u64,
+        allow_add_after_start: bool,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        // Started an hour ago, cliff still a day away
+        let start_timestamp = (JAN_1_2025 - 3_600) as u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            seed,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        update_vest_schedule(&mut svm, &schedule, |schedule| {
+            schedule.allow_add_after_start = allow_add_after_start
+        });
+
+        let authority_ata = create_ata_with_balance(&mut svm, &authority.pubkey(), &token_mint, 1_000_000);
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, 0);
+
+        let (participant_state, participant_bump) = vest_participant_pda(&participant.pubkey(), &schedule);
+
+        let instruction = build_add_participant_instruction(
+            &authority.pubkey(),
+            &authority_ata,
+            &vault,
+            &participant.pubkey(),
+            &participant_state,
+            &schedule,
+            &token_mint,
+            create_add_participant_instruction_data(100_000, participant_bump),
+        );
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&authority.pubkey()),
+            &[&authority],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        result
+    }
+
+    #[test]
+    fn test_add_participant_after_start_allowed() {
+        let result = add_participant_after_start(12346, true);
+        assert!(result.is_ok(), "Adding after start but before cliff should be allowed by default");
+    }
+
+    #[test]
+    fn test_add_participant_after_start_disallowed() {
+        let result = add_participant_after_start(12347, false);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::Custom(SCHEDULE_ALREADY_STARTED_ERROR)),
+            "Adding after start should be rejected when the schedule disallows it"
+        );
+    }
+
+    fn build_initialize_instruction(
+        initializer: &Pubkey,
+        vest_schedule: &Pubkey,
+        token_mint: &Pubkey,
+        vault: &Pubkey,
+        seed: u64,
+        start_timestamp: u64,
+        bump: u8,
+    ) -> Instruction {
+        let mut data = vec![0u8]; // Discriminator for Initialize
+        data.extend_from_slice(&seed.to_le_bytes());
+        data.extend_from_slice(&start_timestamp.to_le_bytes());
+        data.extend_from_slice(&ONE_DAY.to_le_bytes()); // Cliff
+        data.extend_from_slice(&(ONE_DAY * 10).to_le_bytes()); // Total
+        data.extend_from_slice(&ONE_DAY.to_le_bytes()); // Step
+        data.push(bump);
+        data.extend_from_slice(&[0u8; 32]); // Cosigner
+        data.extend_from_slice(&0u64.to_le_bytes()); // Cosign threshold
+        data.extend_from_slice(&0u64.to_le_bytes()); // Claim window
+        data.push(0); // Use vault authority
+        data.push(0); // Vault authority bump
+        data.extend_from_slice(&[0u8; 32]); // Fee recipient
+        data.extend_from_slice(&0u16.to_le_bytes()); // Claim fee bps
+        data.push(1); // Allow add after start
+        data.push(0); // Vesting mode: stepped
+        data.extend_from_slice(&0u64.to_le_bytes()); // Cliff unlock bps
+        data.extend_from_slice(&[0u8; 32]); // Revoke destination
+        data.push(0); // Pause freezes accrual
+        data.extend_from_slice(&0u64.to_le_bytes()); // Max participants: unlimited
+        data.extend_from_slice(&0u64.to_le_bytes()); // Min claim amount: none
+        data.push(0); // Allow escrow destination: no
+        data.extend_from_slice(&[0u8; 16]); // Label: none
+        data.extend_from_slice(&0u64.to_le_bytes()); // Claim cooldown: none
+        data.push(0); // Cooldown exempts participant: no
+
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(*initializer, true),
+                AccountMeta::new(*vest_schedule, false),
+                AccountMeta::new_readonly(*token_mint, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new_readonly(ID.into(), false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+                AccountMeta::new(
+                    Pubkey::find_program_address(&[b"vest_gen", &seed.to_le_bytes()], &PROGRAM_ID).0,
+                    false,
+                ),
+            ],
+            data,
+        }
+    }
+
+    #[test]
+    fn test_add_participant_right_after_initialize() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+        let seed = 54321u64;
+        let start_timestamp = (JAN_1_2025 + ONE_DAY as i64) as u64;
+
+        let (schedule, bump) = vest_schedule_pda(seed);
+        let vault = derive_ata(&schedule, &token_mint);
+        assert!(svm.get_account(&vault).is_none(), "Vault must not be created out of band");
+
+        let instruction = build_initialize_instruction(
+            &authority.pubkey(),
+            &schedule,
+            &token_mint,
+            &vault,
+            seed,
+            start_timestamp,
+            bump,
+        );
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&authority.pubkey()),
+            &[&authority],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "Initialization should succeed");
+
+        // Initialize created the schedule-owned vault and recorded it
+        let vault_token_account = TokenAccount::unpack(&svm.get_account(&vault).unwrap().data).unwrap();
+        assert_eq!(vault_token_account.owner, schedule);
+        assert_eq!(vault_token_account.mint, token_mint);
+        assert_eq!(read_vest_schedule(&svm, &schedule).vault, vault.to_bytes());
+
+        let authority_ata = create_ata_with_balance(&mut svm, &authority.pubkey(), &token_mint, 1_000_000);
+        let (participant_state, participant_bump) = vest_participant_pda(&participant.pubkey(), &schedule);
+
+        let allocated_amount = 100_000u64;
+        let instruction = build_add_participant_instruction(
+            &authority.pubkey(),
+            &authority_ata,
+            &vault,
+            &participant.pubkey(),
+            &participant_state,
+            &schedule,
+            &token_mint,
+            create_add_participant_instruction_data(allocated_amount, participant_bump),
+        );
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&authority.pubkey()),
+            &[&authority],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "Adding a participant right after initialize should succeed");
+
+        let vault_token_account = TokenAccount::unpack(&svm.get_account(&vault).unwrap().data).unwrap();
+        assert_eq!(vault_token_account.amount, allocated_amount);
+    }
+
+    #[test]
+    fn test_add_participant_tracks_total_allocated() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+        let start_timestamp = (JAN_1_2025 + ONE_DAY as i64) as u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            12347,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        let authority_ata = create_ata_with_balance(&mut svm, &authority.pubkey(), &token_mint, 1_000_000);
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, 0);
+
+        for allocated_amount in [100_000u64, 250_000] {
+            let participant = Keypair::new();
+            let (participant_state, participant_bump) = vest_participant_pda(&participant.pubkey(), &schedule);
+
+            let instruction = build_add_participant_instruction(
+                &authority.pubkey(),
+                &authority_ata,
+                &vault,
+                &participant.pubkey(),
+                &participant_state,
+                &schedule,
+                &token_mint,
+                create_add_participant_instruction_data(allocated_amount, participant_bump),
+            );
+
+            let transaction = Transaction::new_signed_with_payer(
+                &[instruction],
+                Some(&authority.pubkey()),
+                &[&authority],
+                svm.latest_blockhash(),
+            );
+
+            let result = svm.send_transaction(transaction);
+            print_transaction_logs(&result);
+            assert!(result.is_ok(), "Add participant should succeed");
+        }
+
+        assert_eq!(read_vest_schedule(&svm, &schedule).total_allocated, 350_000);
+    }
+
+    #[test]
+    fn test_add_participant_transfer_fee_mint_rejected() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+        // Token-2022 mint carrying a TransferFeeConfig extension
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+        let mut mint_account = svm.get_account(&token_mint).unwrap();
+        mint_account.owner = TOKEN_2022_PROGRAM_ID;
+        mint_account.data.resize(TokenAccount::LEN, 0); // Padding up to the token account length
+        mint_account.data.push(1); // AccountType::Mint
+        mint_account.data.extend_from_slice(&1u16.to_le_bytes()); // ExtensionType::TransferFeeConfig
+        mint_account.data.extend_from_slice(&108u16.to_le_bytes()); // Extension length
+        mint_account.data.extend_from_slice(&[0u8; 108]);
+        svm.set_account(token_mint, mint_account).unwrap();
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            12348,
+            (JAN_1_2025 + ONE_DAY as i64) as u64,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+        update_vest_schedule(&mut svm, &schedule, |schedule| {
+            schedule.token_program = TOKEN_2022_PROGRAM_ID.to_bytes()
+        });
+
+        let authority_ata = create_ata_with_balance(&mut svm, &authority.pubkey(), &token_mint, 1_000_000);
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, 0);
+        let (participant_state, participant_bump) = vest_participant_pda(&participant.pubkey(), &schedule);
+
+        let mut instruction = build_add_participant_instruction(
+            &authority.pubkey(),
+            &authority_ata,
+            &vault,
+            &participant.pubkey(),
+            &participant_state,
+            &schedule,
+            &token_mint,
+            create_add_participant_instruction_data(100_000, participant_bump),
+        );
+        instruction.accounts[8] = AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false);
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&authority.pubkey()),
+            &[&authority],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::Custom(UNSUPPORTED_MINT_EXTENSION_ERROR))
+        );
+
+        // Nothing was recorded that the vault doesn't hold
+        assert!(svm.get_account(&participant_state).is_none());
+        assert_eq!(read_vest_schedule(&svm, &schedule).total_allocated, 0);
+        let vault_account = svm.get_account(&vault).unwrap();
+        assert_eq!(TokenAccount::unpack(&vault_account.data).unwrap().amount, 0);
+    }
+
+    // Adds a 100,000 allocation to a schedule that already records 500,000 allocated, of
+    // which `total_claimed` was paid out, with `vault_balance` in the vault beforehand
+    fn add_participant_to_committed_schedule(
+        seed: u64,
+        vault_balance: u64,
+        total_claimed: u64,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata> {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            seed,
+            (JAN_1_2025 + ONE_DAY as i64) as u64,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+        update_vest_schedule(&mut svm, &schedule, |schedule| {
+            schedule.total_allocated = 500_000;
+            schedule.total_claimed = total_claimed;
+        });
+
+        let authority_ata = create_ata_with_balance(&mut svm, &authority.pubkey(), &token_mint, 1_000_000);
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, vault_balance);
+        let (participant_state, participant_bump) = vest_participant_pda(&participant.pubkey(), &schedule);
+
+        let instruction = build_add_participant_instruction(
+            &authority.pubkey(),
+            &authority_ata,
+            &vault,
+            &participant.pubkey(),
+            &participant_state,
+            &schedule,
+            &token_mint,
+            create_add_participant_instruction_data(100_000, participant_bump),
+        );
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&authority.pubkey()),
+            &[&authority],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        result
+    }
+
+    #[test]
+    fn test_add_participant_allocations_exactly_match_vault() {
+        let result = add_participant_to_committed_schedule(12349, 500_000, 0);
+        assert!(result.is_ok(), "Allocations exactly covered by the vault should be accepted");
+    }
+
+    #[test]
+    fn test_add_participant_over_allocated_by_one_rejected() {
+        let result = add_participant_to_committed_schedule(12350, 499_999, 0);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::Custom(OVER_ALLOCATED_ERROR))
+        );
+    }
+
+    #[test]
+    fn test_add_participant_after_claims_counts_only_outstanding() {
+        // 200,000 of the 500,000 was already claimed out of the vault
+        let result = add_participant_to_committed_schedule(12357, 300_000, 200_000);
+        assert!(
+            result.is_ok(),
+            "Claimed tokens left the vault and must not count against it"
+        );
+    }
+
+    #[test]
+    fn test_add_participant_after_claims_over_allocated_by_one_rejected() {
+        let result = add_participant_to_committed_schedule(12358, 299_999, 200_000);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::Custom(OVER_ALLOCATED_ERROR))
+        );
+    }
+
+    // Adds a participant with the given duration overrides to a 1 day cliff / 10 day schedule
+    // and returns the participant state account
+    fn add_participant_with_overrides(
+        seed: u64,
+        cliff_override: u64,
+        total_override: u64,
+    ) -> (
+        Pubkey,
+        Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>,
+        LiteSVM,
+    ) {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            seed,
+            (JAN_1_2025 + ONE_DAY as i64) as u64,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        let authority_ata = create_ata_with_balance(&mut svm, &authority.pubkey(), &token_mint, 1_000_000);
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, 0);
+        let (participant_state, participant_bump) = vest_participant_pda(&participant.pubkey(), &schedule);
+
+        let instruction = build_add_participant_instruction(
+            &authority.pubkey(),
+            &authority_ata,
+            &vault,
+            &participant.pubkey(),
+            &participant_state,
+            &schedule,
+            &token_mint,
+            create_add_participant_instruction_data_with_overrides(
+                100_000,
+                participant_bump,
+                cliff_override,
+                total_override,
+            ),
+        );
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&authority.pubkey()),
+            &[&authority],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        (participant_state, result, svm)
+    }
+
+    #[test]
+    fn test_add_participant_stores_duration_overrides() {
+        let (participant_state, result, svm) = add_participant_with_overrides(12351, ONE_DAY * 3, ONE_DAY * 20);
+        assert!(result.is_ok(), "Valid overrides should be accepted");
+
+        let participant_state = read_participant_state(&svm, &participant_state);
+        assert_eq!(participant_state.cliff_override, ONE_DAY * 3);
+        assert_eq!(participant_state.total_override, ONE_DAY * 20);
+    }
+
+    #[test]
+    fn test_add_participant_cliff_override_past_total_rejected() {
+        // A cliff override at or past the schedule's total duration leaves nothing to vest
+        let (_, result, _) = add_participant_with_overrides(12352, ONE_DAY * 10, 0);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::Custom(INVALID_DURATIONS_ERROR))
+        );
+    }
+
+    #[test]
+    fn test_add_participant_total_override_past_clock_rejected() {
+        // Ends after the latest timestamp the clock can report
+        let (_, result, _) = add_participant_with_overrides(12359, 0, i64::MAX as u64);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(INVALID_DURATIONS_ERROR)
+            )
+        );
+    }
+
+    #[test]
+    fn test_add_participant_without_bump() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            12353,
+            (JAN_1_2025 + ONE_DAY as i64) as u64,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        let authority_ata = create_ata_with_balance(&mut svm, &authority.pubkey(), &token_mint, 1_000_000);
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, 0);
+        let (participant_state, participant_bump) = vest_participant_pda(&participant.pubkey(), &schedule);
+
+        // Only the discriminator and the allocation; the program derives the bump itself
+        let mut instruction_data = vec![1u8];
+        instruction_data.extend_from_slice(&100_000u64.to_le_bytes());
+
+        let instruction = build_add_participant_instruction(
+            &authority.pubkey(),
+            &authority_ata,
+            &vault,
+            &participant.pubkey(),
+            &participant_state,
+            &schedule,
+            &token_mint,
+            instruction_data,
+        );
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&authority.pubkey()),
+            &[&authority],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "Add without a bump should succeed");
+
+        // The canonical bump is stored
+        assert_eq!(
+            read_participant_state(&svm, &participant_state).bump,
+            participant_bump
+        );
+    }
+
+    #[test]
+    fn test_add_participant_state_derived_for_other_participant_rejected() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        let other_participant = Keypair::new();
+
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            12354,
+            (JAN_1_2025 + ONE_DAY as i64) as u64,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        let authority_ata =
+            create_ata_with_balance(&mut svm, &authority.pubkey(), &token_mint, 1_000_000);
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, 0);
+
+        // The state PDA of another participant on the same schedule
+        let (other_state, other_bump) =
+            vest_participant_pda(&other_participant.pubkey(), &schedule);
+        let (own_state, _) = vest_participant_pda(&participant.pubkey(), &schedule);
+
+        let instruction = build_add_participant_instruction(
+            &authority.pubkey(),
+            &authority_ata,
+            &vault,
+            &participant.pubkey(),
+            &other_state,
+            &schedule,
+            &token_mint,
+            create_add_participant_instruction_data(100_000, other_bump),
+        );
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&authority.pubkey()),
+            &[&authority],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(PARTICIPANT_MISMATCH_ERROR)
+            )
+        );
+
+        assert!(svm.get_account(&other_state).is_none());
+        assert!(svm.get_account(&own_state).is_none());
+    }
+
+    // Adds three participants of 100_000 each to a fresh schedule capped at `max_participants`,
+    // returning each add's result and the schedule's final participant count
+    fn add_three_participants(seed: u64, max_participants: u64) -> (Vec<bool>, u64) {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            seed,
+            (JAN_1_2025 + ONE_DAY as i64) as u64,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        update_vest_schedule(&mut svm, &schedule, |schedule| {
+            schedule.max_participants = max_participants
+        });
+
+        let authority_ata = create_ata_with_balance(&mut svm, &authority.pubkey(), &token_mint, 1_000_000);
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, 0);
+
+        let results = (0..3)
+            .map(|_| {
+                let participant = Pubkey::new_unique();
+                let (participant_state, participant_bump) = vest_participant_pda(&participant, &schedule);
+
+                let instruction = build_add_participant_instruction(
+                    &authority.pubkey(),
+                    &authority_ata,
+                    &vault,
+                    &participant,
+                    &participant_state,
+                    &schedule,
+                    &token_mint,
+                    create_add_participant_instruction_data(100_000, participant_bump),
+                );
+
+                let transaction = Transaction::new_signed_with_payer(
+                    &[instruction],
+                    Some(&authority.pubkey()),
+                    &[&authority],
+                    svm.latest_blockhash(),
+                );
+
+                let result = svm.send_transaction(transaction);
+                print_transaction_logs(&result);
+                if let Err(err) = &result {
+                    assert_eq!(
+                        err.err,
+                        TransactionError::InstructionError(
+                            0,
+                            InstructionError::Custom(PARTICIPANT_CAP_REACHED_ERROR)
+                        )
+                    );
+                }
+                result.is_ok()
+            })
+            .collect();
+
+        (results, read_vest_schedule(&svm, &schedule).participant_count)
+    }
+
+    #[test]
+    fn test_add_participant_unlimited_when_no_cap() {
+        let (results, participant_count) = add_three_participants(12355, 0);
+        assert_eq!(results, vec![true, true, true]);
+        assert_eq!(participant_count, 3);
+    }
+
+    #[test]
+    fn test_add_participant_beyond_cap_rejected() {
+        let (results, participant_count) = add_three_participants(12356, 2);
+        assert_eq!(results, vec![true, true, false]);
+        assert_eq!(participant_count, 2);
+    }
+}
@@ -0,0 +1,221 @@
+mod common;
+
+#[cfg(test)]
+mod batch_add_participant_tests {
+    use crate::common::*;
+    use litesvm::LiteSVM;
+    use pinocchio_system::ID;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction, InstructionError},
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::{Transaction, TransactionError},
+    };
+    use spl_token::solana_program::program_pack::Pack;
+    use spl_token::state::Account as TokenAccount;
+    use spl_token::ID as TOKEN_PROGRAM_ID;
+    use token_vesting::instruction_builder::vest_participant_pda;
+
+    const BATCH_ADD_PARTICIPANT_DISCRIMINATOR: u8 = 11;
+    const MAX_BATCH_SIZE: usize = 10;
+
+    // PinocchioError::InsufficientDeposit
+    const INSUFFICIENT_DEPOSIT_ERROR: u32 = 33;
+
+    fn create_batch_instruction_data(entries: &[(Pubkey, u64, u8)]) -> Vec<u8> {
+        let mut data = vec![BATCH_ADD_PARTICIPANT_DISCRIMINATOR];
+        for (participant, allocated_amount, participant_bump) in entries {
+            data.extend_from_slice(participant.as_ref());
+            data.extend_from_slice(&allocated_amount.to_le_bytes());
+            data.push(*participant_bump);
+        }
+        data
+    }
+
+    struct Fixture {
+        authority: Keypair,
+        authority_ata: Pubkey,
+        vault: Pubkey,
+        schedule: Pubkey,
+        token_mint: Pubkey,
+    }
+
+    fn setup_batch(svm: &mut LiteSVM, seed: u64) -> Fixture {
+        let authority = Keypair::new();
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(svm, &authority.pubkey());
+
+        // Schedule starts tomorrow (before cliff)
+        let start_timestamp = (JAN_1_2025 + ONE_DAY as i64) as u64;
+        let schedule = create_vest_schedule(
+            svm,
+            &authority.pubkey(),
+            &token_mint,
+            seed,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        let authority_ata =
+            create_ata_with_balance(svm, &authority.pubkey(), &token_mint, 1_000_000);
+        let vault = create_ata_with_balance(svm, &schedule, &token_mint, 0);
+
+        Fixture {
+            authority,
+            authority_ata,
+            vault,
+            schedule,
+            token_mint,
+        }
+    }
+
+    fn send_batch(
+        svm: &mut LiteSVM,
+        fixture: &Fixture,
+        participant_states: &[Pubkey],
+        instruction_data: Vec<u8>,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let mut accounts = vec![
+            AccountMeta::new(fixture.authority.pubkey(), true),
+            AccountMeta::new(fixture.authority_ata, false),
+            AccountMeta::new(fixture.vault, false),
+            AccountMeta::new(fixture.schedule, false),
+            AccountMeta::new_readonly(fixture.token_mint, false),
+            AccountMeta::new_readonly(ID.into(), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ];
+        accounts.extend(
+            participant_states
+                .iter()
+                .map(|participant_state| AccountMeta::new(*participant_state, false)),
+        );
+
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts,
+            data: instruction_data,
+        };
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&fixture.authority.pubkey()),
+            &[&fixture.authority],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        result
+    }
+
+    #[test]
+    fn test_batch_add_three_participants() {
+        let mut svm = setup_svm();
+        let fixture = setup_batch(&mut svm, 7001);
+
+        let allocations = [100_000u64, 200_000, 300_000];
+        let mut entries = Vec::new();
+        let mut participant_states = Vec::new();
+        for allocated_amount in allocations {
+            let participant = Pubkey::new_unique();
+            let (participant_state, participant_bump) =
+                vest_participant_pda(&participant, &fixture.schedule);
+            entries.push((participant, allocated_amount, participant_bump));
+            participant_states.push(participant_state);
+        }
+
+        let result = send_batch(
+            &mut svm,
+            &fixture,
+            &participant_states,
+            create_batch_instruction_data(&entries),
+        );
+        assert!(result.is_ok(), "Batch of three should succeed");
+
+        for ((participant, allocated_amount, participant_bump), participant_state) in
+            entries.iter().zip(&participant_states)
+        {
+            assert_eq!(
+                svm.get_account(participant_state).unwrap().owner,
+                PROGRAM_ID
+            );
+            let state = read_participant_state(&svm, participant_state);
+            assert_eq!(state.participant, participant.to_bytes());
+            assert_eq!(state.schedule, fixture.schedule.to_bytes());
+            assert_eq!(state.allocated_amount, *allocated_amount);
+            assert_eq!(state.claimed_amount, 0);
+            assert_eq!(state.bump, *participant_bump);
+        }
+
+        let vault = TokenAccount::unpack(&svm.get_account(&fixture.vault).unwrap().data).unwrap();
+        assert_eq!(
+            vault.amount, 600_000,
+            "Vault should hold the sum of the allocations"
+        );
+
+        let schedule = read_vest_schedule(&svm, &fixture.schedule);
+        assert_eq!(schedule.total_allocated, 600_000, "total_allocated");
+
+        let authority_ata =
+            TokenAccount::unpack(&svm.get_account(&fixture.authority_ata).unwrap().data).unwrap();
+        assert_eq!(authority_ata.amount, 400_000);
+    }
+
+    #[test]
+    fn test_batch_add_over_cap_rejected() {
+        let mut svm = setup_svm();
+        let fixture = setup_batch(&mut svm, 7002);
+
+        let entries: Vec<(Pubkey, u64, u8)> = (0..MAX_BATCH_SIZE + 1)
+            .map(|_| (Pubkey::new_unique(), 1_000, 255))
+            .collect();
+
+        // The size check runs before the accounts are matched up, so none are passed
+        let result = send_batch(
+            &mut svm,
+            &fixture,
+            &[],
+            create_batch_instruction_data(&entries),
+        );
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::InvalidInstructionData)
+        );
+    }
+
+    #[test]
+    fn test_batch_add_total_exceeds_balance_rejected() {
+        let mut svm = setup_svm();
+        let fixture = setup_batch(&mut svm, 7003);
+
+        // Each allocation fits on its own, their sum does not
+        let mut entries = Vec::new();
+        let mut participant_states = Vec::new();
+        for _ in 0..2 {
+            let participant = Pubkey::new_unique();
+            let (participant_state, participant_bump) =
+                vest_participant_pda(&participant, &fixture.schedule);
+            entries.push((participant, 600_000u64, participant_bump));
+            participant_states.push(participant_state);
+        }
+
+        let result = send_batch(
+            &mut svm,
+            &fixture,
+            &participant_states,
+            create_batch_instruction_data(&entries),
+        );
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(INSUFFICIENT_DEPOSIT_ERROR)
+            )
+        );
+        assert!(svm.get_account(&participant_states[0]).is_none());
+    }
+}
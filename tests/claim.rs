@@ -1,268 +1,141 @@
+mod common;
+
 #[cfg(test)]
 mod claim_tests {
+    use crate::common::*;
+    use base64::{engine::general_purpose::STANDARD, Engine};
     use litesvm::LiteSVM;
     use pinocchio_system::ID;
+    use solana_compute_budget_interface::ComputeBudgetInstruction;
     use solana_sdk::{
-        account::Account,
-        clock::Clock,
-        instruction::{AccountMeta, Instruction},
+        instruction::{AccountMeta, Instruction, InstructionError},
         pubkey::Pubkey,
         signature::{Keypair, Signer},
-        transaction::Transaction,
+        transaction::{Transaction, TransactionError},
     };
     use spl_associated_token_account::ID as ATA_PROGRAM_ID;
-    use spl_token::solana_program::program_option::COption;
     use spl_token::solana_program::program_pack::Pack;
-    use spl_token::state::{Account as TokenAccount, AccountState, Mint};
+    use spl_token::state::{Account as TokenAccount, AccountState};
     use spl_token::ID as TOKEN_PROGRAM_ID;
-
-    const PROGRAM_ID: Pubkey = Pubkey::new_from_array([
-        0x0f, 0x1e, 0x6b, 0x14, 0x21, 0xc0, 0x4a, 0x07, 0x04, 0x31, 0x26, 0x5c, 0x19, 0xc5, 0xbb,
-        0xee, 0x19, 0x92, 0xba, 0xe8, 0xaf, 0xd1, 0xcd, 0x07, 0x8e, 0xf8, 0xaf, 0x70, 0x47, 0xdc,
-        0x11, 0xf7,
-    ]);
-
-    // January 1, 2025 00:00:00 UTC
-    const JAN_1_2025: i64 = 1735689600;
-    const ONE_DAY: u64 = 86_400;
+    use token_vesting::instruction_builder::{
+        claim_ix, config_pda, vest_participant_pda,
+    };
 
     const CLAIM_DISCRIMINATOR: u8 = 2;
-
-    // VestSchedule::LEN = discriminator(1) + 3*Pubkey(96) + 5*u64(40) + bump(1) = 138
-    const VEST_SCHEDULE_LEN: usize = 138;
-    // VestParticipant::LEN = discriminator(1) + 2*Pubkey(64) + 2*u64(16) + bump(1) = 82
-    const VEST_PARTICIPANT_LEN: usize = 82;
+    const SWEEP_SURPLUS_DISCRIMINATOR: u8 = 25;
+
+    const TOKEN_2022_PROGRAM_ID: Pubkey = Pubkey::new_from_array(token_vesting::TOKEN_2022_PROGRAM_ID);
+
+    // Compute units a worst-case claim (cosigner, vault authority and fee) may use. The
+    // budget test requests exactly this limit, so a claim that outgrows it fails the
+    // transaction rather than passing an after-the-fact comparison.
+    const CLAIM_COMPUTE_BUDGET: u32 = 50_000;
+    // Compute units a plain claim may use. It skips the co-signer, vault authority and fee
+    // work, and ClaimAll repeats it per group, so it gets half the worst-case budget.
+    const PLAIN_CLAIM_COMPUTE_BUDGET: u32 = 25_000;
+
+    // Log prefix of the per-step events: "Program data: " followed by base64("step")
+    const STEP_EVENT_PREFIX: &str = "Program data: c3RlcA== ";
+
+    // PinocchioError::NoClaimableAmount
+    const NO_CLAIMABLE_AMOUNT_ERROR: u32 = 5;
+    // PinocchioError::InvalidDurations
+    const INVALID_DURATIONS_ERROR: u32 = 7;
+    // PinocchioError::InvalidDiscriminator
+    const INVALID_DISCRIMINATOR_ERROR: u32 = 12;
+    // PinocchioError::CosignerRequired
+    const COSIGNER_REQUIRED_ERROR: u32 = 13;
+    // PinocchioError::VaultUnderfunded
+    const VAULT_UNDERFUNDED_ERROR: u32 = 14;
+    // PinocchioError::InvalidTokenProgram
+    const INVALID_TOKEN_PROGRAM_ERROR: u32 = 17;
+    // PinocchioError::CliffNotReached
+    const CLIFF_NOT_REACHED_ERROR: u32 = 19;
+    // PinocchioError::VaultMismatch
+    const VAULT_MISMATCH_ERROR: u32 = 21;
+    // PinocchioError::MintMismatch
+    const MINT_MISMATCH_ERROR: u32 = 29;
+    // PinocchioError::WrongSchedule
+    const WRONG_SCHEDULE_ERROR: u32 = 32;
+    // PinocchioError::DestinationFrozen
+    const DESTINATION_FROZEN_ERROR: u32 = 36;
+    // PinocchioError::InvalidClock
+    const INVALID_CLOCK_ERROR: u32 = 41;
+    // PinocchioError::ClaimCooldown
+    const CLAIM_COOLDOWN_ERROR: u32 = 43;
+    // PinocchioError::FullyClaimed
+    const FULLY_CLAIMED_ERROR: u32 = 44;
+    // PinocchioError::ClaimWindowExpired
+    const CLAIM_WINDOW_EXPIRED_ERROR: u32 = 45;
 
     fn create_claim_instruction_data() -> Vec<u8> {
         vec![CLAIM_DISCRIMINATOR]
     }
 
-    fn derive_participant_pda(participant: &Pubkey, schedule: &Pubkey) -> (Pubkey, u8) {
-        Pubkey::find_program_address(
-            &[b"vest_participant", participant.as_ref(), schedule.as_ref()],
-            &PROGRAM_ID,
+    fn derive_ata_with_program(owner: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+        spl_associated_token_account::get_associated_token_address_with_program_id(
+            owner,
+            mint,
+            token_program,
         )
     }
 
-    // Updated: PDA now uses only seed
-    fn derive_vest_schedule_pda(seed: u64) -> (Pubkey, u8) {
-        Pubkey::find_program_address(&[b"vest_schedule", &seed.to_le_bytes()], &PROGRAM_ID)
-    }
-
-    fn derive_ata(owner: &Pubkey, mint: &Pubkey) -> Pubkey {
-        spl_associated_token_account::get_associated_token_address(owner, mint)
-    }
-
-    fn setup_svm() -> LiteSVM {
-        let mut svm = LiteSVM::new().with_builtins().with_sigverify(false);
-        svm.add_program_from_file(PROGRAM_ID, "target/deploy/token_vesting.so")
-            .expect("Failed to load program");
-
-        warp_to_timestamp(&mut svm, JAN_1_2025);
-
-        svm
-    }
-
-    fn warp_to_timestamp(svm: &mut LiteSVM, unix_timestamp: i64) {
-        let current_clock = svm.get_sysvar::<Clock>();
-        svm.set_sysvar(&Clock {
-            unix_timestamp,
-            ..current_clock
+    fn set_schedule_cosign(
+        svm: &mut LiteSVM,
+        schedule: &Pubkey,
+        cosigner: &Pubkey,
+        cosign_threshold: u64,
+    ) {
+        update_vest_schedule(svm, schedule, |s| {
+            s.cosigner = cosigner.to_bytes();
+            s.cosign_threshold = cosign_threshold;
         });
     }
 
-    fn print_transaction_logs(
-        result: &Result<
-            litesvm::types::TransactionMetadata,
-            litesvm::types::FailedTransactionMetadata,
-        >,
-    ) {
-        match result {
-            Ok(meta) => {
-                println!("\n=== Transaction Succeeded ===");
-                for log in &meta.logs {
-                    println!("  {}", log);
-                }
-            }
-            Err(err) => {
-                println!("\n=== Transaction Failed ===");
-                println!("Error: {:?}", err.err);
-                for log in &err.meta.logs {
-                    println!("  {}", log);
-                }
-            }
-        }
+    fn set_schedule_claim_window(svm: &mut LiteSVM, schedule: &Pubkey, claim_window: u64) {
+        update_vest_schedule(svm, schedule, |s| s.claim_window = claim_window);
     }
 
-    fn create_mock_token_mint(svm: &mut LiteSVM, authority: &Pubkey) -> Pubkey {
-        let mint_keypair = Keypair::new();
-        let mint_pubkey = mint_keypair.pubkey();
-
-        let mint_data = Mint {
-            mint_authority: COption::Some(*authority),
-            supply: 1_000_000_000,
-            decimals: 6,
-            is_initialized: true,
-            freeze_authority: COption::None,
-        };
-
-        let mut data = vec![0u8; Mint::LEN];
-        Mint::pack(mint_data, &mut data).unwrap();
-
-        svm.set_account(
-            mint_pubkey,
-            Account {
-                lamports: 10_000_000,
-                data,
-                owner: TOKEN_PROGRAM_ID,
-                executable: false,
-                rent_epoch: 0,
-            }
-            .into(),
-        );
-
-        mint_pubkey
+    fn set_schedule_total_allocated(svm: &mut LiteSVM, schedule: &Pubkey, total_allocated: u64) {
+        update_vest_schedule(svm, schedule, |s| s.total_allocated = total_allocated);
     }
 
-    // Updated: VestSchedule now has discriminator and vault field (138 bytes)
-    fn create_vest_schedule(
-        svm: &mut LiteSVM,
-        authority: &Pubkey,
-        token_mint: &Pubkey,
-        seed: u64,
-        start_timestamp: u64,
-        cliff_duration: u64,
-        total_duration: u64,
-        step_duration: u64,
-    ) -> Pubkey {
-        let (schedule_pda, bump) = derive_vest_schedule_pda(seed);
-        let vault = derive_ata(&schedule_pda, token_mint);
-
-        let mut schedule_data = Vec::with_capacity(VEST_SCHEDULE_LEN);
-        schedule_data.push(0u8); // Discriminator
-        schedule_data.extend_from_slice(token_mint.as_ref()); // Token mint: Pubkey (32)
-        schedule_data.extend_from_slice(authority.as_ref()); // Authority: Pubkey (32)
-        schedule_data.extend_from_slice(vault.as_ref()); // Vault: Pubkey (32)
-        schedule_data.extend_from_slice(&seed.to_le_bytes()); // Seed: u64 (8)
-        schedule_data.extend_from_slice(&start_timestamp.to_le_bytes()); // Start timestamp: u64 (8)
-        schedule_data.extend_from_slice(&cliff_duration.to_le_bytes()); // Cliff duration: u64 (8)
-        schedule_data.extend_from_slice(&total_duration.to_le_bytes()); // Total duration: u64 (8)
-        schedule_data.extend_from_slice(&step_duration.to_le_bytes()); // Step duration: u64 (8)
-        schedule_data.push(bump); // Bump: u8 (1)
-
-        assert_eq!(schedule_data.len(), VEST_SCHEDULE_LEN);
-
-        svm.set_account(
-            schedule_pda,
-            Account {
-                lamports: 10_000_000,
-                data: schedule_data,
-                owner: PROGRAM_ID,
-                executable: false,
-                rent_epoch: 0,
-            }
-            .into(),
-        );
+    // Hands the vault over to the ["vault", schedule] PDA and returns it
+    fn set_schedule_vault_authority(svm: &mut LiteSVM, schedule: &Pubkey) -> Pubkey {
+        let (vault_authority, bump) =
+            Pubkey::find_program_address(&[b"vault", schedule.as_ref()], &PROGRAM_ID);
+
+        // The recorded vault moves to the vault authority's ATA as well
+        update_vest_schedule(svm, schedule, |s| {
+            let token_mint = Pubkey::new_from_array(s.token_mint);
+            s.vault_authority = vault_authority.to_bytes();
+            s.vault_authority_bump = bump;
+            s.vault = derive_ata(&vault_authority, &token_mint).to_bytes();
+        });
 
-        schedule_pda
+        vault_authority
     }
 
-    // Updated: VestParticipant now has discriminator (82 bytes)
-    fn create_participant_state(
+    fn set_schedule_claim_fee(
         svm: &mut LiteSVM,
-        participant: &Pubkey,
         schedule: &Pubkey,
-        allocated_amount: u64,
-        claimed_amount: u64,
-    ) -> Pubkey {
-        let (participant_state, bump) = derive_participant_pda(participant, schedule);
-
-        let mut data = Vec::with_capacity(VEST_PARTICIPANT_LEN);
-        data.push(1u8); // Discriminator
-        data.extend_from_slice(participant.as_ref()); // Participant: Pubkey (32)
-        data.extend_from_slice(schedule.as_ref()); // Schedule: Pubkey (32)
-        data.extend_from_slice(&allocated_amount.to_le_bytes()); // Allocated: u64 (8)
-        data.extend_from_slice(&claimed_amount.to_le_bytes()); // Claimed: u64 (8)
-        data.push(bump); // Bump: u8 (1)
-
-        assert_eq!(data.len(), VEST_PARTICIPANT_LEN);
-
-        svm.set_account(
-            participant_state,
-            Account {
-                lamports: 10_000_000,
-                data,
-                owner: PROGRAM_ID,
-                executable: false,
-                rent_epoch: 0,
-            }
-            .into(),
-        );
-
-        participant_state
+        fee_recipient: &Pubkey,
+        claim_fee_bps: u16,
+    ) {
+        update_vest_schedule(svm, schedule, |s| {
+            s.fee_recipient = fee_recipient.to_bytes();
+            s.claim_fee_bps = claim_fee_bps;
+        });
     }
 
-    fn create_ata_with_balance(
-        svm: &mut LiteSVM,
-        owner: &Pubkey,
-        mint: &Pubkey,
-        amount: u64,
-    ) -> Pubkey {
-        let ata = derive_ata(owner, mint);
-
-        let token_account = TokenAccount {
-            mint: *mint,
-            owner: *owner,
-            amount,
-            delegate: COption::None,
-            state: AccountState::Initialized,
-            is_native: COption::None,
-            delegated_amount: 0,
-            close_authority: COption::None,
-        };
-
-        let mut data = vec![0u8; TokenAccount::LEN];
-        TokenAccount::pack(token_account, &mut data).unwrap();
-
-        svm.set_account(
-            ata,
-            Account {
-                lamports: 10_000_000,
-                data,
-                owner: TOKEN_PROGRAM_ID,
-                executable: false,
-                rent_epoch: 0,
-            }
-            .into(),
-        );
-
-        ata
+    fn read_total_fees_paid(svm: &LiteSVM, participant_state: &Pubkey) -> u64 {
+        read_participant_state(svm, participant_state).total_fees_paid
     }
 
-    // Updated: 9 accounts now
-    fn build_claim_instruction(
-        participant: &Pubkey,
-        participant_state: &Pubkey,
-        participant_ata: &Pubkey,
-        vest_schedule: &Pubkey,
-        vault: &Pubkey,
-        token_mint: &Pubkey,
-    ) -> Instruction {
-        Instruction {
-            program_id: PROGRAM_ID,
-            accounts: vec![
-                AccountMeta::new(*participant, true),
-                AccountMeta::new(*participant_state, false),
-                AccountMeta::new(*participant_ata, false),
-                AccountMeta::new(*vest_schedule, false),
-                AccountMeta::new(*vault, false),
-                AccountMeta::new_readonly(*token_mint, false),
-                AccountMeta::new_readonly(ID.into(), false),
-                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
-                AccountMeta::new_readonly(ATA_PROGRAM_ID, false),
-            ],
-            data: create_claim_instruction_data(),
-        }
+    // Returns (claimed_amount, forfeited_amount)
+    fn read_participant_amounts(svm: &LiteSVM, participant_state: &Pubkey) -> (u64, u64) {
+        let state = read_participant_state(svm, participant_state);
+        (state.claimed_amount, state.forfeited_amount)
     }
 
     // ==================== SUCCESS CASES ====================
@@ -292,7 +165,7 @@ mod claim_tests {
             ONE_DAY,
         );
 
-        let participant_state = create_participant_state(
+        create_participant_state(
             &mut svm,
             &participant.pubkey(),
             &schedule,
@@ -305,13 +178,12 @@ mod claim_tests {
         let participant_ata =
             create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
 
-        let instruction = build_claim_instruction(
+        let instruction = claim_ix(
             &participant.pubkey(),
-            &participant_state,
-            &participant_ata,
             &schedule,
             &vault,
             &token_mint,
+            &TOKEN_PROGRAM_ID,
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -362,7 +234,7 @@ mod claim_tests {
             ONE_DAY,
         );
 
-        let participant_state = create_participant_state(
+        create_participant_state(
             &mut svm,
             &participant.pubkey(),
             &schedule,
@@ -374,13 +246,12 @@ mod claim_tests {
         let participant_ata =
             create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
 
-        let instruction = build_claim_instruction(
+        let instruction = claim_ix(
             &participant.pubkey(),
-            &participant_state,
-            &participant_ata,
             &schedule,
             &vault,
             &token_mint,
+            &TOKEN_PROGRAM_ID,
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -426,7 +297,7 @@ mod claim_tests {
             ONE_DAY,
         );
 
-        let participant_state = create_participant_state(
+        create_participant_state(
             &mut svm,
             &participant.pubkey(),
             &schedule,
@@ -435,16 +306,14 @@ mod claim_tests {
         );
 
         let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, allocated);
-        let participant_ata =
-            create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+        create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
 
-        let instruction = build_claim_instruction(
+        let instruction = claim_ix(
             &participant.pubkey(),
-            &participant_state,
-            &participant_ata,
             &schedule,
             &vault,
             &token_mint,
+            &TOKEN_PROGRAM_ID,
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -484,7 +353,7 @@ mod claim_tests {
             ONE_DAY,
         );
 
-        let participant_state = create_participant_state(
+        create_participant_state(
             &mut svm,
             &participant.pubkey(),
             &schedule,
@@ -497,13 +366,12 @@ mod claim_tests {
             create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
 
         // First claim
-        let instruction = build_claim_instruction(
+        let instruction = claim_ix(
             &participant.pubkey(),
-            &participant_state,
-            &participant_ata,
             &schedule,
             &vault,
             &token_mint,
+            &TOKEN_PROGRAM_ID,
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -521,13 +389,12 @@ mod claim_tests {
         warp_to_timestamp(&mut svm, JAN_1_2025 + (ONE_DAY * 2) as i64);
 
         // Second claim
-        let instruction2 = build_claim_instruction(
+        let instruction2 = claim_ix(
             &participant.pubkey(),
-            &participant_state,
-            &participant_ata,
             &schedule,
             &vault,
             &token_mint,
+            &TOKEN_PROGRAM_ID,
         );
 
         let tx2 = Transaction::new_signed_with_payer(
@@ -575,7 +442,7 @@ mod claim_tests {
             ONE_DAY,
         );
 
-        let participant_state = create_participant_state(
+        create_participant_state(
             &mut svm,
             &participant.pubkey(),
             &schedule,
@@ -584,16 +451,14 @@ mod claim_tests {
         );
 
         let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, allocated);
-        let participant_ata =
-            create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+        create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
 
-        let instruction = build_claim_instruction(
+        let instruction = claim_ix(
             &participant.pubkey(),
-            &participant_state,
-            &participant_ata,
             &schedule,
             &vault,
             &token_mint,
+            &TOKEN_PROGRAM_ID,
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -605,7 +470,7 @@ mod claim_tests {
 
         let result = svm.send_transaction(tx);
         print_transaction_logs(&result);
-        assert!(result.is_err(), "Should fail before cliff");
+        assert_custom_error(&result, CLIFF_NOT_REACHED_ERROR);
     }
 
     #[test]
@@ -633,7 +498,7 @@ mod claim_tests {
             ONE_DAY,
         );
 
-        let participant_state = create_participant_state(
+        create_participant_state(
             &mut svm,
             &participant.pubkey(),
             &schedule,
@@ -642,16 +507,14 @@ mod claim_tests {
         );
 
         let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, allocated);
-        let participant_ata =
-            create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+        create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
 
-        let instruction = build_claim_instruction(
+        let instruction = claim_ix(
             &participant.pubkey(),
-            &participant_state,
-            &participant_ata,
             &schedule,
             &vault,
             &token_mint,
+            &TOKEN_PROGRAM_ID,
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -692,7 +555,7 @@ mod claim_tests {
             ONE_DAY,
         );
 
-        let participant_state = create_participant_state(
+        create_participant_state(
             &mut svm,
             &participant.pubkey(),
             &schedule,
@@ -706,16 +569,14 @@ mod claim_tests {
             &token_mint,
             allocated - already_claimed,
         );
-        let participant_ata =
-            create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, already_claimed);
+        create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, already_claimed);
 
-        let instruction = build_claim_instruction(
+        let instruction = claim_ix(
             &participant.pubkey(),
-            &participant_state,
-            &participant_ata,
             &schedule,
             &vault,
             &token_mint,
+            &TOKEN_PROGRAM_ID,
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -727,7 +588,7 @@ mod claim_tests {
 
         let result = svm.send_transaction(tx);
         print_transaction_logs(&result);
-        assert!(result.is_err(), "Should fail when nothing new to claim");
+        assert_custom_error(&result, NO_CLAIMABLE_AMOUNT_ERROR);
     }
 
     #[test]
@@ -755,7 +616,7 @@ mod claim_tests {
             ONE_DAY,
         );
 
-        let participant_state = create_participant_state(
+        create_participant_state(
             &mut svm,
             &participant.pubkey(),
             &schedule,
@@ -765,16 +626,14 @@ mod claim_tests {
 
         // Vault only has 1 token
         let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, 1);
-        let participant_ata =
-            create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+        create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
 
-        let instruction = build_claim_instruction(
+        let instruction = claim_ix(
             &participant.pubkey(),
-            &participant_state,
-            &participant_ata,
             &schedule,
             &vault,
             &token_mint,
+            &TOKEN_PROGRAM_ID,
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -826,15 +685,16 @@ mod claim_tests {
         let participant_ata =
             create_ata_with_balance(&mut svm, &real_participant.pubkey(), &token_mint, 0);
 
-        // Attacker tries to claim
-        let instruction = build_claim_instruction(
+        // Attacker tries to claim with the real participant's accounts
+        let mut instruction = claim_ix(
             &attacker.pubkey(),
-            &participant_state,
-            &participant_ata,
             &schedule,
             &vault,
             &token_mint,
+            &TOKEN_PROGRAM_ID,
         );
+        instruction.accounts[1].pubkey = participant_state;
+        instruction.accounts[2].pubkey = participant_ata;
 
         let tx = Transaction::new_signed_with_payer(
             &[instruction],
@@ -892,18 +752,17 @@ mod claim_tests {
         );
 
         let vault = create_ata_with_balance(&mut svm, &schedule_1, &token_mint, 1_000_000);
-        let participant_ata =
-            create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+        create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
 
         // Try to use schedule_2
-        let instruction = build_claim_instruction(
+        let mut instruction = claim_ix(
             &participant.pubkey(),
-            &participant_state,
-            &participant_ata,
             &schedule_2,
             &vault,
             &token_mint,
+            &TOKEN_PROGRAM_ID,
         );
+        instruction.accounts[1].pubkey = participant_state;
 
         let tx = Transaction::new_signed_with_payer(
             &[instruction],
@@ -914,7 +773,7 @@ mod claim_tests {
 
         let result = svm.send_transaction(tx);
         print_transaction_logs(&result);
-        assert!(result.is_err(), "Should fail with wrong schedule");
+        assert_custom_error(&result, WRONG_SCHEDULE_ERROR);
     }
 
     #[test]
@@ -942,7 +801,7 @@ mod claim_tests {
             ONE_DAY,
         );
 
-        let participant_state = create_participant_state(
+        create_participant_state(
             &mut svm,
             &participant.pubkey(),
             &schedule,
@@ -955,14 +814,14 @@ mod claim_tests {
             create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
 
         // Pass wrong mint
-        let instruction = build_claim_instruction(
+        let mut instruction = claim_ix(
             &participant.pubkey(),
-            &participant_state,
-            &participant_ata,
             &schedule,
             &vault,
             &wrong_mint,
+            &TOKEN_PROGRAM_ID,
         );
+        instruction.accounts[2].pubkey = participant_ata;
 
         let tx = Transaction::new_signed_with_payer(
             &[instruction],
@@ -973,7 +832,7 @@ mod claim_tests {
 
         let result = svm.send_transaction(tx);
         print_transaction_logs(&result);
-        assert!(result.is_err(), "Should fail with wrong token mint");
+        assert_custom_error(&result, MINT_MISMATCH_ERROR);
     }
 
     #[test]
@@ -1026,6 +885,7 @@ mod claim_tests {
                 AccountMeta::new_readonly(ID.into(), false),
                 AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
                 AccountMeta::new_readonly(ATA_PROGRAM_ID, false),
+                AccountMeta::new_readonly(config_pda().0, false),
             ],
             data: create_claim_instruction_data(),
         };
@@ -1067,7 +927,7 @@ mod claim_tests {
             ONE_DAY,
         );
 
-        let participant_state = create_participant_state(
+        create_participant_state(
             &mut svm,
             &participant.pubkey(),
             &schedule,
@@ -1076,16 +936,14 @@ mod claim_tests {
         );
 
         let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, allocated);
-        let participant_ata =
-            create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+        create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
 
-        let instruction = build_claim_instruction(
+        let instruction = claim_ix(
             &participant.pubkey(),
-            &participant_state,
-            &participant_ata,
             &schedule,
             &vault,
             &token_mint,
+            &TOKEN_PROGRAM_ID,
         );
 
         // Two claims in one TX
@@ -1126,7 +984,7 @@ mod claim_tests {
             ONE_DAY,
         );
 
-        let participant_state = create_participant_state(
+        create_participant_state(
             &mut svm,
             &participant.pubkey(),
             &schedule,
@@ -1137,16 +995,14 @@ mod claim_tests {
         // Wrong vault (owned by random, not schedule)
         let wrong_vault =
             create_ata_with_balance(&mut svm, &random_owner.pubkey(), &token_mint, allocated);
-        let participant_ata =
-            create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+        create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
 
-        let instruction = build_claim_instruction(
+        let instruction = claim_ix(
             &participant.pubkey(),
-            &participant_state,
-            &participant_ata,
             &schedule,
             &wrong_vault,
             &token_mint,
+            &TOKEN_PROGRAM_ID,
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -1168,7 +1024,7 @@ mod claim_tests {
         let participant = Keypair::new();
         svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
 
-        // Only 5 accounts instead of 9
+        // Only 5 accounts instead of 10
         let instruction = Instruction {
             program_id: PROGRAM_ID,
             accounts: vec![
@@ -1192,4 +1048,2338 @@ mod claim_tests {
         print_transaction_logs(&result);
         assert!(result.is_err(), "Should fail with insufficient accounts");
     }
-}
\ No newline at end of file
+
+    // ==================== CO-SIGN ====================
+
+    #[test]
+    fn test_claim_below_cosign_threshold_single_signer() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        let cosigner = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        // Fully vested
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 30) as i64) as u64;
+        let allocated = 1_000_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            19,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+        // Threshold above the whole allocation, co-signer never needed
+        set_schedule_cosign(&mut svm, &schedule, &cosigner.pubkey(), allocated + 1);
+
+        create_participant_state(&mut svm, &participant.pubkey(), &schedule, allocated, 0);
+
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, allocated);
+        let participant_ata =
+            create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+
+        let instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "Claim below threshold should not need co-signer");
+
+        let ata_account = svm.get_account(&participant_ata).unwrap();
+        let token_data = TokenAccount::unpack(&ata_account.data).unwrap();
+        assert_eq!(token_data.amount, allocated);
+    }
+
+    #[test]
+    fn test_claim_above_cosign_threshold_with_cosigner() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        let cosigner = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        // Fully vested
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 30) as i64) as u64;
+        let allocated = 1_000_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            20,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+        set_schedule_cosign(&mut svm, &schedule, &cosigner.pubkey(), 500_000);
+
+        create_participant_state(&mut svm, &participant.pubkey(), &schedule, allocated, 0);
+
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, allocated);
+        let participant_ata =
+            create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+
+        let mut instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+        instruction
+            .accounts
+            .push(AccountMeta::new_readonly(cosigner.pubkey(), true));
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&participant.pubkey()),
+            &[&participant, &cosigner],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "Co-signed claim above threshold should succeed");
+
+        let ata_account = svm.get_account(&participant_ata).unwrap();
+        let token_data = TokenAccount::unpack(&ata_account.data).unwrap();
+        assert_eq!(token_data.amount, allocated);
+    }
+
+    #[test]
+    fn test_claim_above_cosign_threshold_without_cosigner() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        let cosigner = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        // Fully vested
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 30) as i64) as u64;
+        let allocated = 1_000_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            21,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+        set_schedule_cosign(&mut svm, &schedule, &cosigner.pubkey(), 500_000);
+
+        create_participant_state(&mut svm, &participant.pubkey(), &schedule, allocated, 0);
+
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, allocated);
+        let participant_ata =
+            create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+
+        // Co-signer passed but not signing
+        let mut instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+        instruction
+            .accounts
+            .push(AccountMeta::new_readonly(cosigner.pubkey(), false));
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert_custom_error(&result, COSIGNER_REQUIRED_ERROR);
+
+        let ata_account = svm.get_account(&participant_ata).unwrap();
+        let token_data = TokenAccount::unpack(&ata_account.data).unwrap();
+        assert_eq!(token_data.amount, 0, "Nothing should be transferred");
+    }
+
+    #[test]
+    fn test_claim_participant_state_as_schedule() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        // Fully vested
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 30) as i64) as u64;
+        let allocated = 1_000_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            22,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        let participant_state =
+            create_participant_state(&mut svm, &participant.pubkey(), &schedule, allocated, 0);
+
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, allocated);
+        create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+
+        // A valid participant account passed in the vest_schedule slot
+        let mut instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+        instruction.accounts[3].pubkey = participant_state;
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert_custom_error(&result, INVALID_DISCRIMINATOR_ERROR);
+    }
+
+    // ==================== CLAIM WINDOW ====================
+
+    #[test]
+    fn test_claim_window_forfeits_stale_steps() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        // Started 6 days ago, 5 of 9 steps unlocked
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 6) as i64) as u64;
+        let allocated = 900_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            23,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+        // Steps must be claimed within 2 days of unlocking
+        set_schedule_claim_window(&mut svm, &schedule, ONE_DAY * 2);
+        set_schedule_total_allocated(&mut svm, &schedule, allocated);
+
+        let participant_state =
+            create_participant_state(&mut svm, &participant.pubkey(), &schedule, allocated, 0);
+
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, allocated);
+        let participant_ata =
+            create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+
+        let instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction.clone()],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "Claim of recent steps should succeed");
+
+        // Steps 1-3 unlocked more than 2 days ago and are forfeited, steps 4-5 are claimed
+        let ata_account = svm.get_account(&participant_ata).unwrap();
+        let token_data = TokenAccount::unpack(&ata_account.data).unwrap();
+        assert_eq!(token_data.amount, 200_000);
+        assert_eq!(
+            read_participant_amounts(&svm, &participant_state),
+            (200_000, 300_000)
+        );
+
+        // One day later only the newly unlocked step is claimable
+        warp_to_timestamp(&mut svm, JAN_1_2025 + ONE_DAY as i64);
+        svm.expire_blockhash();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "Claim of the next step should succeed");
+
+        let ata_account = svm.get_account(&participant_ata).unwrap();
+        let token_data = TokenAccount::unpack(&ata_account.data).unwrap();
+        assert_eq!(token_data.amount, 300_000);
+        assert_eq!(
+            read_participant_amounts(&svm, &participant_state),
+            (300_000, 300_000)
+        );
+    }
+
+    #[test]
+    fn test_claim_window_everything_expired() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        // Fully vested 20 days ago
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 30) as i64) as u64;
+        let allocated = 900_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            24,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+        set_schedule_claim_window(&mut svm, &schedule, ONE_DAY * 2);
+
+        create_participant_state(&mut svm, &participant.pubkey(), &schedule, allocated, 0);
+
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, allocated);
+        create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+
+        let instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert_custom_error(&result, CLAIM_WINDOW_EXPIRED_ERROR);
+    }
+
+    // ==================== VAULT FUNDING ====================
+
+    #[test]
+    fn test_claim_vault_exactly_funded() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        // Started 3 days ago, 2 of 9 steps unlocked
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let allocated = 900_000u64;
+        let claimable = 200_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            25,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        create_participant_state(&mut svm, &participant.pubkey(), &schedule, allocated, 0);
+
+        // Vault holds exactly the claimable amount
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, claimable);
+        create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+
+        let instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "Exactly funded vault should cover the claim");
+
+        let vault_account = svm.get_account(&vault).unwrap();
+        let vault_data = TokenAccount::unpack(&vault_account.data).unwrap();
+        assert_eq!(vault_data.amount, 0);
+    }
+
+    #[test]
+    fn test_claim_vault_underfunded_by_one() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        // Started 3 days ago, 2 of 9 steps unlocked
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let allocated = 900_000u64;
+        let claimable = 200_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            26,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        create_participant_state(&mut svm, &participant.pubkey(), &schedule, allocated, 0);
+
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, claimable - 1);
+        create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+
+        let instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert_custom_error(&result, VAULT_UNDERFUNDED_ERROR);
+    }
+
+    #[test]
+    fn test_claim_with_separate_vault_authority() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        // Fully vested
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 20) as i64) as u64;
+        let allocated = 900_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            27,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+        let vault_authority = set_schedule_vault_authority(&mut svm, &schedule);
+
+        create_participant_state(&mut svm, &participant.pubkey(), &schedule, allocated, 0);
+
+        let vault = create_ata_with_balance(&mut svm, &vault_authority, &token_mint, allocated);
+        let participant_ata =
+            create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+
+        let mut instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+        instruction
+            .accounts
+            .push(AccountMeta::new_readonly(vault_authority, false));
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "Claim should be signed by the vault authority PDA");
+
+        let participant_ata_account = svm.get_account(&participant_ata).unwrap();
+        let participant_ata_data = TokenAccount::unpack(&participant_ata_account.data).unwrap();
+        assert_eq!(participant_ata_data.amount, allocated);
+
+        let vault_account = svm.get_account(&vault).unwrap();
+        let vault_data = TokenAccount::unpack(&vault_account.data).unwrap();
+        assert_eq!(vault_data.amount, 0);
+    }
+
+    #[test]
+    fn test_claim_separate_vault_authority_not_passed() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 20) as i64) as u64;
+        let allocated = 900_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            28,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+        let vault_authority = set_schedule_vault_authority(&mut svm, &schedule);
+
+        create_participant_state(&mut svm, &participant.pubkey(), &schedule, allocated, 0);
+
+        let vault = create_ata_with_balance(&mut svm, &vault_authority, &token_mint, allocated);
+        create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+
+        let instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys)
+        );
+    }
+
+    #[test]
+    fn test_claim_fee_accumulates_total_fees_paid() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        let fee_wallet = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        // Started 3 days ago, 2 of 9 steps unlocked
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let allocated = 900_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            29,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        let participant_state =
+            create_participant_state(&mut svm, &participant.pubkey(), &schedule, allocated, 0);
+
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, allocated);
+        let participant_ata =
+            create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+        let fee_account = create_ata_with_balance(&mut svm, &fee_wallet.pubkey(), &token_mint, 0);
+
+        // 2.5% of every claim
+        set_schedule_claim_fee(&mut svm, &schedule, &fee_account, 250);
+
+        let build = || {
+            let mut instruction = claim_ix(
+                &participant.pubkey(),
+                &schedule,
+                &vault,
+                &token_mint,
+                &TOKEN_PROGRAM_ID,
+            );
+            instruction.accounts.push(AccountMeta::new(fee_account, false));
+            instruction
+        };
+
+        // First claim: 200_000 unlocked, 5_000 fee
+        let tx = Transaction::new_signed_with_payer(
+            &[build()],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "First fee-bearing claim should succeed");
+        assert_eq!(read_total_fees_paid(&svm, &participant_state), 5_000);
+
+        // Second claim two days later: another 200_000 unlocked, 5_000 fee
+        warp_to_timestamp(&mut svm, JAN_1_2025 + (ONE_DAY * 2) as i64);
+        svm.expire_blockhash();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[build()],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "Second fee-bearing claim should succeed");
+
+        assert_eq!(read_total_fees_paid(&svm, &participant_state), 10_000);
+        assert_eq!(read_participant_amounts(&svm, &participant_state).0, 400_000);
+
+        let participant_ata_account = svm.get_account(&participant_ata).unwrap();
+        let participant_ata_data = TokenAccount::unpack(&participant_ata_account.data).unwrap();
+        assert_eq!(participant_ata_data.amount, 390_000);
+
+        let fee_account_data =
+            TokenAccount::unpack(&svm.get_account(&fee_account).unwrap().data).unwrap();
+        assert_eq!(fee_account_data.amount, 10_000);
+    }
+
+    #[test]
+    fn test_claim_stays_within_compute_and_stack_budget() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        let cosigner = Keypair::new();
+        let fee_wallet = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        // Fully vested, with every optional claim feature turned on
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 20) as i64) as u64;
+        let allocated = 900_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            30,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+        set_schedule_cosign(&mut svm, &schedule, &cosigner.pubkey(), 1);
+        set_schedule_claim_window(&mut svm, &schedule, ONE_DAY * 30);
+        let vault_authority = set_schedule_vault_authority(&mut svm, &schedule);
+
+        create_participant_state(&mut svm, &participant.pubkey(), &schedule, allocated, 0);
+
+        let vault = create_ata_with_balance(&mut svm, &vault_authority, &token_mint, allocated);
+        create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+        let fee_account = create_ata_with_balance(&mut svm, &fee_wallet.pubkey(), &token_mint, 0);
+        set_schedule_claim_fee(&mut svm, &schedule, &fee_account, 100);
+
+        let mut instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+        instruction.accounts.extend([
+            AccountMeta::new_readonly(cosigner.pubkey(), true),
+            AccountMeta::new_readonly(vault_authority, false),
+            AccountMeta::new(fee_account, false),
+        ]);
+
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                ComputeBudgetInstruction::set_compute_unit_limit(CLAIM_COMPUTE_BUDGET),
+                instruction,
+            ],
+            Some(&participant.pubkey()),
+            &[&participant, &cosigner],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert!(
+            result.is_ok(),
+            "Worst-case claim should fit in {} CU",
+            CLAIM_COMPUTE_BUDGET
+        );
+        let meta = result.unwrap();
+
+        for log in &meta.logs {
+            let log = log.to_lowercase();
+            assert!(
+                !log.contains("access violation") && !log.contains("stack"),
+                "Unexpected stack related log: {}",
+                log
+            );
+        }
+    }
+
+    fn claim_with_token_program(
+        seed: u64,
+        stored_token_program: &Pubkey,
+        passed_token_program: &Pubkey,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 20) as i64) as u64;
+        let allocated = 900_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            seed,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+        update_vest_schedule(&mut svm, &schedule, |s| {
+            s.token_program = stored_token_program.to_bytes()
+        });
+
+        create_participant_state(&mut svm, &participant.pubkey(), &schedule, allocated, 0);
+
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, allocated);
+        create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+
+        let mut instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+        instruction.accounts[7] = AccountMeta::new_readonly(*passed_token_program, false);
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        result
+    }
+
+    #[test]
+    fn test_claim_legacy_schedule_rejects_token_2022_program() {
+        let result = claim_with_token_program(31, &TOKEN_PROGRAM_ID, &TOKEN_2022_PROGRAM_ID);
+        assert_custom_error(&result, INVALID_TOKEN_PROGRAM_ERROR);
+    }
+
+    #[test]
+    fn test_claim_token_2022_schedule_rejects_legacy_program() {
+        let result = claim_with_token_program(32, &TOKEN_2022_PROGRAM_ID, &TOKEN_PROGRAM_ID);
+        assert_custom_error(&result, INVALID_TOKEN_PROGRAM_ERROR);
+    }
+
+    #[test]
+    fn test_claim_token_2022_creates_ata_with_token_2022_program() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+        let mut mint_account = svm.get_account(&token_mint).unwrap();
+        mint_account.owner = TOKEN_2022_PROGRAM_ID;
+        svm.set_account(token_mint, mint_account).unwrap();
+
+        // Started 3 days ago, 2 of 9 steps unlocked
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let allocated = 900_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            37,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        let vault = create_ata_with_balance_for_program(
+            &mut svm,
+            &schedule,
+            &token_mint,
+            allocated,
+            &TOKEN_2022_PROGRAM_ID,
+        );
+        update_vest_schedule(&mut svm, &schedule, |s| {
+            s.vault = vault.to_bytes();
+            s.token_program = TOKEN_2022_PROGRAM_ID.to_bytes();
+        });
+
+        create_participant_state(&mut svm, &participant.pubkey(), &schedule, allocated, 0);
+
+        let participant_ata =
+            derive_ata_with_program(&participant.pubkey(), &token_mint, &TOKEN_2022_PROGRAM_ID);
+        assert_ne!(participant_ata, derive_ata(&participant.pubkey(), &token_mint));
+
+        let mut instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+        instruction.accounts[7] = AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false);
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction.clone()],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "Token-2022 claim should succeed");
+
+        let ata_account = svm.get_account(&participant_ata).unwrap();
+        assert_eq!(ata_account.owner, TOKEN_2022_PROGRAM_ID);
+        let balance = TokenAccount::unpack(&ata_account.data[..TokenAccount::LEN])
+            .unwrap()
+            .amount;
+        assert_eq!(balance, 200_000);
+
+        // The created ATA carries Token-2022 extensions and must still verify on later claims
+        warp_to_timestamp(&mut svm, JAN_1_2025 + ONE_DAY as i64);
+        svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "Second Token-2022 claim should succeed");
+
+        let ata_account = svm.get_account(&participant_ata).unwrap();
+        let balance = TokenAccount::unpack(&ata_account.data[..TokenAccount::LEN])
+            .unwrap()
+            .amount;
+        assert_eq!(balance, 300_000);
+    }
+
+    #[test]
+    fn test_claim_logs_success_record() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        // Started 3 days ago, 2 of 9 steps unlocked
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let allocated = 900_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            33,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        create_participant_state(&mut svm, &participant.pubkey(), &schedule, allocated, 0);
+
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, allocated);
+        create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+
+        let instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        let meta = result.expect("Claim should succeed");
+
+        let records: Vec<&String> = meta
+            .logs
+            .iter()
+            .filter(|log| log.starts_with("Program data: "))
+            .filter(|log| !log.starts_with(STEP_EVENT_PREFIX))
+            .collect();
+        assert_eq!(records.len(), 1, "Exactly one success record per claim");
+
+        let fields: Vec<Vec<u8>> = records[0]
+            .trim_start_matches("Program data: ")
+            .split_whitespace()
+            .map(|field| STANDARD.decode(field).unwrap())
+            .collect();
+        assert_eq!(fields.len(), 5, "[schedule_seed, participant, amount, new_claimed, remaining]");
+
+        let read_u64 = |bytes: &Vec<u8>| u64::from_le_bytes(bytes.as_slice().try_into().unwrap());
+
+        assert_eq!(read_u64(&fields[0]), 33);
+        assert_eq!(fields[1], participant.pubkey().to_bytes().to_vec());
+        assert_eq!(read_u64(&fields[2]), 200_000);
+
+        let new_claimed = read_u64(&fields[3]);
+        assert_eq!(new_claimed, 200_000);
+        assert_eq!(read_u64(&fields[4]), allocated - new_claimed);
+    }
+
+    #[test]
+    fn test_claim_zero_decimal_mint_waits_for_whole_unit() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint_with_decimals(&mut svm, &authority.pubkey(), 0);
+
+        // Started 3 days ago, 2 of 9 steps unlocked: 3 * 2 / 9 rounds down to 0 units
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let allocated = 3u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            40,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        create_participant_state(&mut svm, &participant.pubkey(), &schedule, allocated, 0);
+
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, allocated);
+        let participant_ata =
+            create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+
+        let instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction.clone()],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert_custom_error(&result, NO_CLAIMABLE_AMOUNT_ERROR);
+
+        // The third step completes the first whole unit
+        warp_to_timestamp(&mut svm, JAN_1_2025 + ONE_DAY as i64);
+        svm.expire_blockhash();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "Claim should succeed once a whole unit has vested");
+
+        let ata_account = svm.get_account(&participant_ata).unwrap();
+        let token_data = TokenAccount::unpack(&ata_account.data).unwrap();
+        assert_eq!(token_data.amount, 1);
+    }
+
+    #[test]
+    fn test_claim_returns_next_unlock_timestamp() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        // Started 3 days ago, 2 of 9 steps unlocked; the third unlocks tomorrow
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let allocated = 900_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            41,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        create_participant_state(&mut svm, &participant.pubkey(), &schedule, allocated, 0);
+
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, allocated);
+        create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+
+        let instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        let meta = result.expect("Claim should succeed");
+
+        assert_eq!(meta.return_data.program_id, PROGRAM_ID);
+        let next_unlock = u64::from_le_bytes(meta.return_data.data[..8].try_into().unwrap());
+        assert_eq!(next_unlock, start_timestamp + ONE_DAY * 4);
+        assert_eq!(next_unlock, (JAN_1_2025 + ONE_DAY as i64) as u64);
+    }
+
+    #[test]
+    fn test_claim_vault_not_recorded_in_schedule() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 20) as i64) as u64;
+        let allocated = 900_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            34,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        // The schedule records some other vault than its derived ATA
+        update_vest_schedule(&mut svm, &schedule, |s| {
+            s.vault = Pubkey::new_unique().to_bytes()
+        });
+
+        create_participant_state(&mut svm, &participant.pubkey(), &schedule, allocated, 0);
+
+        // Correctly derived ATA for (schedule, mint), but not the recorded one
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, allocated);
+        create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+
+        let instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert_custom_error(&result, VAULT_MISMATCH_ERROR);
+    }
+
+    #[test]
+    fn test_claim_logs_one_event_per_unlocked_step() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        // Started 5 days ago, 4 of 9 steps unlocked, the first one already claimed
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 5) as i64) as u64;
+        let allocated = 900_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            35,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        create_participant_state(
+            &mut svm,
+            &participant.pubkey(),
+            &schedule,
+            allocated,
+            100_000,
+        );
+
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, allocated - 100_000);
+        let participant_ata =
+            create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+
+        let instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        let meta = result.expect("Claim should succeed");
+
+        let read_u64 = |bytes: &Vec<u8>| u64::from_le_bytes(bytes.as_slice().try_into().unwrap());
+
+        let events: Vec<Vec<Vec<u8>>> = meta
+            .logs
+            .iter()
+            .filter(|log| log.starts_with(STEP_EVENT_PREFIX))
+            .map(|log| {
+                log.trim_start_matches("Program data: ")
+                    .split_whitespace()
+                    .map(|field| STANDARD.decode(field).unwrap())
+                    .collect()
+            })
+            .collect();
+        assert_eq!(events.len(), 3, "Steps 2, 3 and 4 were crossed by this claim");
+
+        for (event, expected_step) in events.iter().zip(2u64..) {
+            assert_eq!(event.len(), 5, "[\"step\", schedule_seed, participant, step_index, step_amount]");
+            assert_eq!(event[0], b"step".to_vec());
+            assert_eq!(read_u64(&event[1]), 35);
+            assert_eq!(event[2], participant.pubkey().to_bytes().to_vec());
+            assert_eq!(read_u64(&event[3]), expected_step);
+            assert_eq!(read_u64(&event[4]), 100_000);
+        }
+
+        let token_data = TokenAccount::unpack(&svm.get_account(&participant_ata).unwrap().data).unwrap();
+        assert_eq!(token_data.amount, 300_000);
+    }
+
+    #[test]
+    fn test_claim_zero_step_schedule_rejected() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        // Crafted schedule whose 1 day step never fits in the 12 hours after the cliff,
+        // so it has no steps at all and would otherwise vest nothing until it ends
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY + ONE_DAY / 4) as i64) as u64;
+        let allocated = 1_000_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            36,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY + ONE_DAY / 2,
+            ONE_DAY,
+        );
+
+        create_participant_state(
+            &mut svm,
+            &participant.pubkey(),
+            &schedule,
+            allocated,
+            0,
+        );
+
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, allocated);
+        create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+
+        let instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert_custom_error(&result, INVALID_DURATIONS_ERROR);
+    }
+
+    #[test]
+    fn test_claim_existing_ata_for_other_mint_rejected() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+        let other_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        // Started 3 days ago, 2 of 9 steps unlocked
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let allocated = 900_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            38,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        create_participant_state(&mut svm, &participant.pubkey(), &schedule, allocated, 0);
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, allocated);
+
+        // A token account of the other mint sitting at the participant's ATA address
+        let other_ata = create_ata_with_balance(&mut svm, &participant.pubkey(), &other_mint, 0);
+        let participant_ata = derive_ata(&participant.pubkey(), &token_mint);
+        let other_account = svm.get_account(&other_ata).unwrap();
+        svm.set_account(participant_ata, other_account).unwrap();
+
+        let instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert_custom_error(&result, MINT_MISMATCH_ERROR);
+
+        let vault_account = svm.get_account(&vault).unwrap();
+        let vault_data = TokenAccount::unpack(&vault_account.data).unwrap();
+        assert_eq!(vault_data.amount, allocated);
+    }
+
+    #[test]
+    fn test_claim_to_frozen_ata_rejected() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        // Started 3 days ago, 2 of 9 steps unlocked
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let allocated = 900_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            42,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        let participant_state =
+            create_participant_state(&mut svm, &participant.pubkey(), &schedule, allocated, 0);
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, allocated);
+
+        // The mint's freeze authority froze the participant's ATA
+        let participant_ata =
+            create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+        let mut ata_account = svm.get_account(&participant_ata).unwrap();
+        let mut ata_data = TokenAccount::unpack(&ata_account.data).unwrap();
+        ata_data.state = AccountState::Frozen;
+        TokenAccount::pack(ata_data, &mut ata_account.data).unwrap();
+        svm.set_account(participant_ata, ata_account).unwrap();
+
+        let instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert_custom_error(&result, DESTINATION_FROZEN_ERROR);
+
+        assert_eq!(read_participant_amounts(&svm, &participant_state).0, 0);
+    }
+
+    #[test]
+    fn test_claim_plain_compute_units() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        // Started 3 days ago, 2 of 9 steps unlocked, no optional features
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let allocated = 900_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            43,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        create_participant_state(&mut svm, &participant.pubkey(), &schedule, allocated, 0);
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, allocated);
+        create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+
+        let instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                ComputeBudgetInstruction::set_compute_unit_limit(PLAIN_CLAIM_COMPUTE_BUDGET),
+                instruction,
+            ],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+
+        // The common case ClaimAll repeats per group; the schedule is loaded only once
+        assert!(
+            result.is_ok(),
+            "Plain claim should fit in {} CU",
+            PLAIN_CLAIM_COMPUTE_BUDGET
+        );
+    }
+
+    #[test]
+    fn test_claim_total_claimed_matches_participants() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let first = Keypair::new();
+        let second = Keypair::new();
+        svm.airdrop(&first.pubkey(), 10_000_000_000).unwrap();
+        svm.airdrop(&second.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        // Started 3 days ago
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            44,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        let participants = [(&first, 900_000u64), (&second, 450_000u64)];
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, 1_350_000);
+
+        let claimants: Vec<_> = participants
+            .iter()
+            .map(|(participant, allocated)| {
+                let participant_state = create_participant_state(
+                    &mut svm,
+                    &participant.pubkey(),
+                    &schedule,
+                    *allocated,
+                    0,
+                );
+                let participant_ata =
+                    create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+                (*participant, participant_state, participant_ata)
+            })
+            .collect();
+
+        // Both claim now, again two days later, and once more after full vesting
+        for timestamp in [
+            JAN_1_2025,
+            JAN_1_2025 + (ONE_DAY * 2) as i64,
+            JAN_1_2025 + (ONE_DAY * 10) as i64,
+        ] {
+            warp_to_timestamp(&mut svm, timestamp);
+            svm.expire_blockhash();
+
+            for (participant, _, _) in &claimants {
+                let instruction = claim_ix(
+                    &participant.pubkey(),
+                    &schedule,
+                    &vault,
+                    &token_mint,
+                    &TOKEN_PROGRAM_ID,
+                );
+
+                let tx = Transaction::new_signed_with_payer(
+                    &[instruction],
+                    Some(&participant.pubkey()),
+                    &[*participant],
+                    svm.latest_blockhash(),
+                );
+
+                let result = svm.send_transaction(tx);
+                print_transaction_logs(&result);
+                assert!(result.is_ok(), "Claim should succeed");
+            }
+        }
+
+        let claimed_sum: u64 = claimants
+            .iter()
+            .map(|(_, participant_state, _)| read_participant_amounts(&svm, participant_state).0)
+            .sum();
+        assert_eq!(claimed_sum, 1_350_000);
+
+        assert_eq!(read_vest_schedule(&svm, &schedule).total_claimed, claimed_sum);
+    }
+
+    #[test]
+    fn test_claim_below_min_claim_amount_rejected() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        // Started 3 days ago, 2 of 9 steps (200,000) unlocked
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let allocated = 900_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            45,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+        update_vest_schedule(&mut svm, &schedule, |s| s.min_claim_amount = 250_000);
+
+        let participant_state =
+            create_participant_state(&mut svm, &participant.pubkey(), &schedule, allocated, 0);
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, allocated);
+        create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+
+        let instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction.clone()],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert_custom_error(&result, NO_CLAIMABLE_AMOUNT_ERROR);
+        assert_eq!(read_participant_amounts(&svm, &participant_state).0, 0);
+
+        // One more step takes the claimable amount to 300,000, past the threshold
+        warp_to_timestamp(&mut svm, JAN_1_2025 + ONE_DAY as i64);
+        svm.expire_blockhash();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "Claim past the threshold should succeed");
+        assert_eq!(
+            read_participant_amounts(&svm, &participant_state).0,
+            300_000
+        );
+    }
+
+    #[test]
+    fn test_claim_below_min_claim_amount_after_completion() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        // Fully vested, with only the last 50,000 left to claim
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 11) as i64) as u64;
+        let allocated = 900_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            46,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+        update_vest_schedule(&mut svm, &schedule, |s| s.min_claim_amount = 100_000);
+
+        create_participant_state(
+            &mut svm,
+            &participant.pubkey(),
+            &schedule,
+            allocated,
+            850_000,
+        );
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, 50_000);
+        let participant_ata =
+            create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+
+        let instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert!(
+            result.is_ok(),
+            "Completion should release the dust below the threshold"
+        );
+
+        let ata_account = svm.get_account(&participant_ata).unwrap();
+        let token_data = TokenAccount::unpack(&ata_account.data).unwrap();
+        assert_eq!(token_data.amount, 50_000);
+    }
+
+    #[test]
+    fn test_claim_negative_clock_rejected() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let allocated = 900_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            47,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        let participant_state =
+            create_participant_state(&mut svm, &participant.pubkey(), &schedule, allocated, 0);
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, allocated);
+        create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+
+        // Cast to u64 this would read as far past the end of the schedule
+        warp_to_timestamp(&mut svm, -1);
+
+        let instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert_custom_error(&result, INVALID_CLOCK_ERROR);
+        assert_eq!(read_participant_amounts(&svm, &participant_state).0, 0);
+    }
+
+    #[test]
+    fn test_claim_tampered_participant_bump_rejected() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let allocated = 900_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            48,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        let participant_state =
+            create_participant_state(&mut svm, &participant.pubkey(), &schedule, allocated, 0);
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, allocated);
+        create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+
+        // The stored bump no longer derives the participant state's address
+        update_participant_state(&mut svm, &participant_state, |p| {
+            p.bump = p.bump.wrapping_sub(1)
+        });
+
+        let instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::InvalidSeeds)
+        );
+        assert_eq!(read_participant_amounts(&svm, &participant_state).0, 0);
+    }
+
+    #[test]
+    fn test_claim_compute_units_independent_of_participant_bump() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let allocated = 900_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            49,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, allocated * 2);
+
+        // One participant whose state has the first bump tried, one a bump search would only
+        // reach after several misses
+        let find_participant = |wanted: fn(u8) -> bool| loop {
+            let participant = Keypair::new();
+            if wanted(vest_participant_pda(&participant.pubkey(), &schedule).1) {
+                return participant;
+            }
+        };
+        let participants = [
+            find_participant(|bump| bump == 255),
+            find_participant(|bump| bump <= 250),
+        ];
+
+        let mut compute_units = Vec::new();
+        for participant in &participants {
+            svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+            create_participant_state(&mut svm, &participant.pubkey(), &schedule, allocated, 0);
+            create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+
+            let instruction = claim_ix(
+                &participant.pubkey(),
+                &schedule,
+                &vault,
+                &token_mint,
+                &TOKEN_PROGRAM_ID,
+            );
+
+            let tx = Transaction::new_signed_with_payer(
+                &[instruction],
+                Some(&participant.pubkey()),
+                &[participant],
+                svm.latest_blockhash(),
+            );
+
+            let result = svm.send_transaction(tx);
+            print_transaction_logs(&result);
+            let meta = result.expect("Claim should succeed");
+            compute_units.push(meta.compute_units_consumed);
+        }
+
+        // Re-deriving the state with `find_program_address` cost one 1,500 CU hash per bump
+        // tried; checking the stored bump always costs one
+        println!(
+            "Claim consumed {} CU with bump 255, {} CU with a lower bump",
+            compute_units[0], compute_units[1]
+        );
+        assert!(
+            compute_units[0].abs_diff(compute_units[1]) < 1_500,
+            "Claim cost should not depend on the participant's bump: {:?}",
+            compute_units
+        );
+    }
+
+    #[test]
+    fn test_claim_returns_claimed_amount() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        // Started 3 days ago, 2 of 9 steps unlocked
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let allocated = 900_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            50,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        create_participant_state(&mut svm, &participant.pubkey(), &schedule, allocated, 0);
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, allocated);
+        create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+
+        let instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        let meta = result.expect("Claim should succeed");
+
+        assert_eq!(meta.return_data.program_id, PROGRAM_ID);
+        assert_eq!(meta.return_data.data.len(), 16);
+        let claimed = u64::from_le_bytes(meta.return_data.data[8..16].try_into().unwrap());
+
+        let vault_account = svm.get_account(&vault).unwrap();
+        let vault_amount = TokenAccount::unpack(&vault_account.data).unwrap().amount;
+        assert_eq!(claimed, 200_000);
+        assert_eq!(claimed, allocated - vault_amount);
+    }
+
+    #[test]
+    fn test_claim_zero_cliff_first_step_after_start() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        // No cliff and 10 daily steps from the start, which was exactly one step ago
+        let start_timestamp = (JAN_1_2025 - ONE_DAY as i64) as u64;
+        let allocated = 1_000_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            51,
+            start_timestamp,
+            0,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        let participant_state =
+            create_participant_state(&mut svm, &participant.pubkey(), &schedule, allocated, 0);
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, allocated);
+        let participant_ata =
+            create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+
+        let instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert!(
+            result.is_ok(),
+            "First step should be claimable one step after start"
+        );
+
+        assert_eq!(
+            read_participant_amounts(&svm, &participant_state).0,
+            100_000
+        );
+        let ata_account = svm.get_account(&participant_ata).unwrap();
+        assert_eq!(
+            TokenAccount::unpack(&ata_account.data).unwrap().amount,
+            100_000
+        );
+    }
+
+    #[test]
+    fn test_claim_cooldown_exempts_participant_when_configured() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let allocated = 900_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            52,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        let participant_state =
+            create_participant_state(&mut svm, &participant.pubkey(), &schedule, allocated, 0);
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, allocated);
+        create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+
+        // Last claimed an hour ago, under a one day cooldown
+        update_participant_state(&mut svm, &participant_state, |p| {
+            p.last_claim_ts = JAN_1_2025 as u64 - 3_600
+        });
+        update_vest_schedule(&mut svm, &schedule, |s| s.claim_cooldown = ONE_DAY);
+
+        let instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction.clone()],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert_custom_error(&result, CLAIM_COOLDOWN_ERROR);
+
+        // Exempting the participant lets their own signed claim through
+        update_vest_schedule(&mut svm, &schedule, |s| {
+            s.cooldown_exempts_participant = true
+        });
+        svm.expire_blockhash();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "Exempt participant claim should succeed");
+
+        assert_eq!(
+            read_participant_amounts(&svm, &participant_state).0,
+            200_000
+        );
+        assert_eq!(
+            read_participant_state(&svm, &participant_state).last_claim_ts,
+            JAN_1_2025 as u64
+        );
+    }
+
+    #[test]
+    fn test_claim_cliff_only_releases_everything_at_cliff() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        // Cliff-only schedules end with the cliff, one day from now
+        let start_timestamp = (JAN_1_2025 - ONE_DAY as i64) as u64;
+        let allocated = 900_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            53,
+            start_timestamp,
+            ONE_DAY * 2,
+            ONE_DAY * 2,
+            ONE_DAY * 2,
+        );
+        update_vest_schedule(&mut svm, &schedule, |s| s.vesting_mode = 3);
+
+        let participant_state =
+            create_participant_state(&mut svm, &participant.pubkey(), &schedule, allocated, 0);
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, allocated);
+        let participant_ata =
+            create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+
+        let instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        // One second before the cliff ends nothing is claimable
+        warp_to_timestamp(&mut svm, JAN_1_2025 + ONE_DAY as i64 - 1);
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction.clone()],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert_custom_error(&result, CLIFF_NOT_REACHED_ERROR);
+
+        // The moment it ends the whole allocation is
+        warp_to_timestamp(&mut svm, JAN_1_2025 + ONE_DAY as i64);
+        svm.expire_blockhash();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "Claim at the cliff should succeed");
+
+        let ata_account = svm.get_account(&participant_ata).unwrap();
+        let token_data = TokenAccount::unpack(&ata_account.data).unwrap();
+        assert_eq!(token_data.amount, allocated);
+        assert_eq!(
+            read_participant_amounts(&svm, &participant_state).0,
+            allocated
+        );
+    }
+
+    #[test]
+    fn test_claim_fully_claimed_after_completion() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        // Vesting completed 20 days ago and everything was claimed
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 30) as i64) as u64;
+        let allocated = 900_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            54,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        create_participant_state(
+            &mut svm,
+            &participant.pubkey(),
+            &schedule,
+            allocated,
+            allocated,
+        );
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, 0);
+        create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, allocated);
+
+        let instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+
+        // Not NoClaimableAmount: there is nothing to come back for
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert_custom_error(&result, FULLY_CLAIMED_ERROR);
+    }
+
+    #[test]
+    fn test_claim_window_forfeit_becomes_sweepable_surplus() {
+        let mut svm = setup_svm();
+
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &authority.pubkey());
+
+        // Started 6 days ago, 5 of 9 steps unlocked, the first 3 of them out of the window
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 6) as i64) as u64;
+        let allocated = 900_000u64;
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority.pubkey(),
+            &token_mint,
+            55,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+        set_schedule_claim_window(&mut svm, &schedule, ONE_DAY * 2);
+        set_schedule_total_allocated(&mut svm, &schedule, allocated);
+
+        let participant_state =
+            create_participant_state(&mut svm, &participant.pubkey(), &schedule, allocated, 0);
+        let vault = create_ata_with_balance(&mut svm, &schedule, &token_mint, allocated);
+        create_ata_with_balance(&mut svm, &participant.pubkey(), &token_mint, 0);
+        let authority_ata = create_ata_with_balance(&mut svm, &authority.pubkey(), &token_mint, 0);
+
+        let instruction = claim_ix(
+            &participant.pubkey(),
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "Claim of recent steps should succeed");
+        assert_eq!(
+            read_participant_amounts(&svm, &participant_state),
+            (200_000, 300_000)
+        );
+
+        // The forfeited 300,000 is no longer owed to anyone
+        assert_eq!(read_vest_schedule(&svm, &schedule).total_allocated, 600_000);
+
+        let sweep = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(schedule, false),
+                AccountMeta::new_readonly(token_mint, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            ],
+            data: vec![SWEEP_SURPLUS_DISCRIMINATOR],
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[sweep],
+            Some(&authority.pubkey()),
+            &[&authority],
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "Forfeited tokens should be sweepable");
+
+        // The vault keeps exactly the 400,000 still owed to the participant
+        let authority_account = svm.get_account(&authority_ata).unwrap();
+        assert_eq!(
+            TokenAccount::unpack(&authority_account.data)
+                .unwrap()
+                .amount,
+            300_000
+        );
+        let vault_account = svm.get_account(&vault).unwrap();
+        assert_eq!(
+            TokenAccount::unpack(&vault_account.data).unwrap().amount,
+            400_000
+        );
+    }
+}
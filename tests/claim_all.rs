@@ -0,0 +1,156 @@
+mod common;
+
+#[cfg(test)]
+mod claim_all_tests {
+    use crate::common::*;
+    use litesvm::LiteSVM;
+    use pinocchio_system::ID;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    };
+    use spl_associated_token_account::ID as ATA_PROGRAM_ID;
+    use spl_token::ID as TOKEN_PROGRAM_ID;
+    use token_vesting::instruction_builder::config_pda;
+
+    const CLAIM_ALL_DISCRIMINATOR: u8 = 24;
+
+    // PinocchioError::NoClaimableAmount
+    const NO_CLAIMABLE_AMOUNT_ERROR: u32 = 5;
+
+    struct Group {
+        schedule: Pubkey,
+        participant_state: Pubkey,
+        vault: Pubkey,
+        participant_ata: Pubkey,
+        token_mint: Pubkey,
+    }
+
+    // A schedule with a 1 day cliff and 1 day steps over 10 days, started `days_ago`, holding
+    // the participant's 900_000 allocation
+    fn setup_group(svm: &mut LiteSVM, participant: &Pubkey, seed: u64, days_ago: u64) -> Group {
+        let authority = Keypair::new();
+        let token_mint = create_mock_token_mint(svm, &authority.pubkey());
+
+        let schedule = create_vest_schedule(
+            svm,
+            &authority.pubkey(),
+            &token_mint,
+            seed,
+            (JAN_1_2025 - (ONE_DAY * days_ago) as i64) as u64,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        let participant_state = create_participant_state(svm, participant, &schedule, 900_000, 0);
+        let vault = create_ata_with_balance(svm, &schedule, &token_mint, 900_000);
+        let participant_ata = create_ata_with_balance(svm, participant, &token_mint, 0);
+
+        Group {
+            schedule,
+            participant_state,
+            vault,
+            participant_ata,
+            token_mint,
+        }
+    }
+
+    fn send_claim_all(
+        svm: &mut LiteSVM,
+        participant: &Keypair,
+        groups: &[&Group],
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let mut accounts = vec![
+            AccountMeta::new(participant.pubkey(), true),
+            AccountMeta::new_readonly(ID.into(), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ATA_PROGRAM_ID, false),
+            AccountMeta::new_readonly(config_pda().0, false),
+        ];
+        for group in groups {
+            accounts.extend([
+                AccountMeta::new(group.schedule, false),
+                AccountMeta::new(group.participant_state, false),
+                AccountMeta::new(group.vault, false),
+                AccountMeta::new(group.participant_ata, false),
+                AccountMeta::new_readonly(group.token_mint, false),
+            ]);
+        }
+
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts,
+            data: vec![CLAIM_ALL_DISCRIMINATOR, groups.len() as u8],
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&participant.pubkey()),
+            &[participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        result
+    }
+
+    #[test]
+    fn test_claim_all_from_two_schedules() {
+        let mut svm = setup_svm();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        // 2 of 9 steps vested on the first schedule, 4 of 9 on the second
+        let first = setup_group(&mut svm, &participant.pubkey(), 1, 3);
+        let second = setup_group(&mut svm, &participant.pubkey(), 2, 5);
+
+        let result = send_claim_all(&mut svm, &participant, &[&first, &second]);
+        assert!(
+            result.is_ok(),
+            "Claiming from both schedules should succeed"
+        );
+
+        assert_eq!(token_balance(&svm, &first.participant_ata), 200_000);
+        assert_eq!(token_balance(&svm, &first.vault), 700_000);
+        assert_eq!(token_balance(&svm, &second.participant_ata), 400_000);
+        assert_eq!(token_balance(&svm, &second.vault), 500_000);
+    }
+
+    #[test]
+    fn test_claim_all_skips_group_with_nothing_claimable() {
+        let mut svm = setup_svm();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let vested = setup_group(&mut svm, &participant.pubkey(), 3, 3);
+        // Still inside its cliff
+        let locked = setup_group(&mut svm, &participant.pubkey(), 4, 0);
+
+        let result = send_claim_all(&mut svm, &participant, &[&locked, &vested]);
+        assert!(
+            result.is_ok(),
+            "A locked schedule shouldn't block the others"
+        );
+
+        assert_eq!(token_balance(&svm, &vested.participant_ata), 200_000);
+        assert_eq!(token_balance(&svm, &locked.participant_ata), 0);
+        assert_eq!(token_balance(&svm, &locked.vault), 900_000);
+    }
+
+    #[test]
+    fn test_claim_all_with_nothing_claimable_rejected() {
+        let mut svm = setup_svm();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let locked = setup_group(&mut svm, &participant.pubkey(), 5, 0);
+
+        let result = send_claim_all(&mut svm, &participant, &[&locked]);
+        assert_custom_error(&result, NO_CLAIMABLE_AMOUNT_ERROR);
+    }
+}
@@ -0,0 +1,248 @@
+mod common;
+
+#[cfg(test)]
+mod claim_to_tests {
+    use crate::common::*;
+    use litesvm::LiteSVM;
+    use pinocchio_system::ID;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    };
+    use spl_associated_token_account::ID as ATA_PROGRAM_ID;
+    use spl_token::ID as TOKEN_PROGRAM_ID;
+    use token_vesting::instruction_builder::config_pda;
+
+    const CLAIM_TO_DISCRIMINATOR: u8 = 16;
+    const CLAIM_TO_ESCROW_DISCRIMINATOR: u8 = 30;
+
+    // PinocchioError::InvalidAccountData
+    const INVALID_ACCOUNT_DATA_ERROR: u32 = 2;
+    // PinocchioError::MintMismatch
+    const MINT_MISMATCH_ERROR: u32 = 29;
+    // PinocchioError::EscrowNotAllowed
+    const ESCROW_NOT_ALLOWED_ERROR: u32 = 42;
+
+    struct Fixture {
+        participant: Keypair,
+        token_mint: Pubkey,
+        schedule: Pubkey,
+        participant_state: Pubkey,
+        vault: Pubkey,
+    }
+
+    // Started 3 days ago with a 1 day cliff and 1 day steps: 2 of 9 steps vested
+    fn setup_claim_to(svm: &mut LiteSVM, seed: u64) -> Fixture {
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(svm, &authority.pubkey());
+
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let schedule = create_vest_schedule(
+            svm,
+            &authority.pubkey(),
+            &token_mint,
+            seed,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        let participant_state =
+            create_participant_state(svm, &participant.pubkey(), &schedule, 900_000, 0);
+        let vault = create_ata_with_balance(svm, &schedule, &token_mint, 900_000);
+
+        Fixture {
+            participant,
+            token_mint,
+            schedule,
+            participant_state,
+            vault,
+        }
+    }
+
+    fn send_claim_to(
+        svm: &mut LiteSVM,
+        fixture: &Fixture,
+        destination: &Pubkey,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        send_claim_with(svm, fixture, destination, CLAIM_TO_DISCRIMINATOR)
+    }
+
+    fn send_claim_to_escrow(
+        svm: &mut LiteSVM,
+        fixture: &Fixture,
+        escrow: &Pubkey,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        send_claim_with(svm, fixture, escrow, CLAIM_TO_ESCROW_DISCRIMINATOR)
+    }
+
+    fn send_claim_with(
+        svm: &mut LiteSVM,
+        fixture: &Fixture,
+        destination: &Pubkey,
+        discriminator: u8,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(fixture.participant.pubkey(), true),
+                AccountMeta::new(fixture.participant_state, false),
+                AccountMeta::new(*destination, false),
+                AccountMeta::new(fixture.schedule, false),
+                AccountMeta::new(fixture.vault, false),
+                AccountMeta::new_readonly(fixture.token_mint, false),
+                AccountMeta::new_readonly(ID.into(), false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ATA_PROGRAM_ID, false),
+                AccountMeta::new_readonly(config_pda().0, false),
+            ],
+            data: vec![discriminator],
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&fixture.participant.pubkey()),
+            &[&fixture.participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        result
+    }
+
+    fn allow_escrow_destination(svm: &mut LiteSVM, schedule: &Pubkey) {
+        update_vest_schedule(svm, schedule, |schedule| {
+            schedule.allow_escrow_destination = true
+        });
+    }
+
+    // A token account of the schedule's mint at an arbitrary address, standing in for an
+    // escrow owned by another program's PDA
+    fn create_escrow(svm: &mut LiteSVM, fixture: &Fixture) -> Pubkey {
+        let escrow_owner = Pubkey::new_unique();
+        let template = create_ata_with_balance(svm, &escrow_owner, &fixture.token_mint, 0);
+
+        let escrow = Pubkey::new_unique();
+        let account = svm.get_account(&template).unwrap();
+        svm.set_account(escrow, account).unwrap();
+        escrow
+    }
+
+    // ==================== SUCCESS CASES ====================
+
+    #[test]
+    fn test_claim_to_own_ata() {
+        let mut svm = setup_svm();
+        let fixture = setup_claim_to(&mut svm, 1);
+
+        let own_ata = create_ata_with_balance(
+            &mut svm,
+            &fixture.participant.pubkey(),
+            &fixture.token_mint,
+            0,
+        );
+
+        let result = send_claim_to(&mut svm, &fixture, &own_ata);
+        assert!(
+            result.is_ok(),
+            "Claiming to the participant's own ATA should succeed"
+        );
+
+        assert_eq!(token_balance(&svm, &own_ata), 200_000);
+        assert_eq!(token_balance(&svm, &fixture.vault), 700_000);
+    }
+
+    #[test]
+    fn test_claim_to_third_party_ata() {
+        let mut svm = setup_svm();
+        let fixture = setup_claim_to(&mut svm, 2);
+
+        // e.g. an exchange deposit address
+        let exchange = Pubkey::new_unique();
+        let exchange_ata = create_ata_with_balance(&mut svm, &exchange, &fixture.token_mint, 0);
+
+        let result = send_claim_to(&mut svm, &fixture, &exchange_ata);
+        assert!(
+            result.is_ok(),
+            "Claiming to a third-party ATA should succeed"
+        );
+
+        assert_eq!(token_balance(&svm, &exchange_ata), 200_000);
+        assert_eq!(token_balance(&svm, &fixture.vault), 700_000);
+    }
+
+    // ==================== FAILURE CASES ====================
+
+    #[test]
+    fn test_claim_to_non_ata_token_account_rejected() {
+        let mut svm = setup_svm();
+        let fixture = setup_claim_to(&mut svm, 3);
+
+        // A valid token account of the right mint, but not at its owner's ATA address
+        let exchange = Pubkey::new_unique();
+        let exchange_ata = create_ata_with_balance(&mut svm, &exchange, &fixture.token_mint, 0);
+        let stray = Pubkey::new_unique();
+        let account = svm.get_account(&exchange_ata).unwrap();
+        svm.set_account(stray, account).unwrap();
+
+        let result = send_claim_to(&mut svm, &fixture, &stray);
+        assert_custom_error(&result, INVALID_ACCOUNT_DATA_ERROR);
+
+        assert_eq!(token_balance(&svm, &fixture.vault), 900_000);
+    }
+
+    #[test]
+    fn test_claim_to_ata_of_other_mint_rejected() {
+        let mut svm = setup_svm();
+        let fixture = setup_claim_to(&mut svm, 4);
+
+        // The participant's genuine ATA, but for a different token
+        let other_mint = create_mock_token_mint(&mut svm, &Pubkey::new_unique());
+        let other_ata =
+            create_ata_with_balance(&mut svm, &fixture.participant.pubkey(), &other_mint, 0);
+
+        let result = send_claim_to(&mut svm, &fixture, &other_ata);
+        assert_custom_error(&result, MINT_MISMATCH_ERROR);
+
+        assert_eq!(token_balance(&svm, &fixture.vault), 900_000);
+    }
+
+    #[test]
+    fn test_claim_to_escrow_when_allowed() {
+        let mut svm = setup_svm();
+        let fixture = setup_claim_to(&mut svm, 5);
+        allow_escrow_destination(&mut svm, &fixture.schedule);
+
+        let escrow = create_escrow(&mut svm, &fixture);
+
+        let result = send_claim_to_escrow(&mut svm, &fixture, &escrow);
+        assert!(result.is_ok(), "Claim to escrow should succeed");
+
+        assert_eq!(token_balance(&svm, &escrow), 200_000);
+        assert_eq!(token_balance(&svm, &fixture.vault), 700_000);
+    }
+
+    #[test]
+    fn test_claim_to_escrow_not_allowed_rejected() {
+        let mut svm = setup_svm();
+        let fixture = setup_claim_to(&mut svm, 6);
+
+        let escrow = create_escrow(&mut svm, &fixture);
+
+        let result = send_claim_to_escrow(&mut svm, &fixture, &escrow);
+        assert_custom_error(&result, ESCROW_NOT_ALLOWED_ERROR);
+
+        assert_eq!(token_balance(&svm, &escrow), 0);
+        assert_eq!(token_balance(&svm, &fixture.vault), 900_000);
+    }
+}
@@ -0,0 +1,238 @@
+mod common;
+
+#[cfg(test)]
+mod close_participant_tests {
+    use crate::common::*;
+    use litesvm::LiteSVM;
+    use pinocchio_system::ID;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    };
+    use spl_associated_token_account::ID as ATA_PROGRAM_ID;
+    use spl_token::ID as TOKEN_PROGRAM_ID;
+    use token_vesting::instruction_builder::config_pda;
+
+    const CLAIM_DISCRIMINATOR: u8 = 2;
+    const CLOSE_PARTICIPANT_DISCRIMINATOR: u8 = 5;
+
+    // PinocchioError::VestingNotComplete
+    const VESTING_NOT_COMPLETE_ERROR: u32 = 23;
+
+    fn is_closed(svm: &LiteSVM, account: &Pubkey) -> bool {
+        svm.get_account(account).map_or(true, |account| {
+            account.lamports == 0 && account.data.is_empty()
+        })
+    }
+
+    struct Fixture {
+        participant: Keypair,
+        token_mint: Pubkey,
+        schedule: Pubkey,
+        participant_state: Pubkey,
+        vault: Pubkey,
+    }
+
+    // Cliff 1 day, 10 day total, 1 day steps: 9 equal steps after the cliff
+    fn setup_close(
+        svm: &mut LiteSVM,
+        seed: u64,
+        start_timestamp: u64,
+        allocated: u64,
+        claimed: u64,
+    ) -> Fixture {
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(svm, &authority.pubkey());
+
+        let schedule = create_vest_schedule(
+            svm,
+            &authority.pubkey(),
+            &token_mint,
+            seed,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        let participant_state =
+            create_participant_state(svm, &participant.pubkey(), &schedule, allocated, claimed);
+
+        let vault = create_ata_with_balance(svm, &schedule, &token_mint, allocated - claimed);
+
+        Fixture {
+            participant,
+            token_mint,
+            schedule,
+            participant_state,
+            vault,
+        }
+    }
+
+    // Claim and CloseParticipant take the same accounts
+    fn send_participant_instruction(
+        svm: &mut LiteSVM,
+        fixture: &Fixture,
+        discriminator: u8,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let participant_ata = derive_ata(&fixture.participant.pubkey(), &fixture.token_mint);
+
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(fixture.participant.pubkey(), true),
+                AccountMeta::new(fixture.participant_state, false),
+                AccountMeta::new(participant_ata, false),
+                AccountMeta::new(fixture.schedule, false),
+                AccountMeta::new(fixture.vault, false),
+                AccountMeta::new_readonly(fixture.token_mint, false),
+                AccountMeta::new_readonly(ID.into(), false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ATA_PROGRAM_ID, false),
+                AccountMeta::new_readonly(config_pda().0, false),
+            ],
+            data: vec![discriminator],
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&fixture.participant.pubkey()),
+            &[&fixture.participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        result
+    }
+
+    fn send_close_participant(
+        svm: &mut LiteSVM,
+        fixture: &Fixture,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        send_participant_instruction(svm, fixture, CLOSE_PARTICIPANT_DISCRIMINATOR)
+    }
+
+    // ==================== SUCCESS CASES ====================
+
+    #[test]
+    fn test_close_participant_claims_residual_before_closing() {
+        let mut svm = setup_svm();
+
+        // Started 30 days ago, fully vested, a third already claimed
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 30) as i64) as u64;
+        let fixture = setup_close(&mut svm, 1, start_timestamp, 900_000, 300_000);
+
+        let lamports_before = svm
+            .get_account(&fixture.participant.pubkey())
+            .unwrap()
+            .lamports;
+
+        let result = send_close_participant(&mut svm, &fixture);
+        assert!(
+            result.is_ok(),
+            "Closing a fully vested participant should succeed"
+        );
+
+        // The unclaimed remainder reached the participant before the state was closed
+        let participant_ata = derive_ata(&fixture.participant.pubkey(), &fixture.token_mint);
+        assert_eq!(token_balance(&svm, &participant_ata), 600_000);
+        assert_eq!(token_balance(&svm, &fixture.vault), 0);
+        assert!(is_closed(&svm, &fixture.participant_state));
+
+        // The state's lamports are refunded, more than covering the fee and the ATA rent
+        let lamports_after = svm
+            .get_account(&fixture.participant.pubkey())
+            .unwrap()
+            .lamports;
+        assert!(lamports_after > lamports_before);
+    }
+
+    #[test]
+    fn test_close_participant_fully_claimed() {
+        let mut svm = setup_svm();
+
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 30) as i64) as u64;
+        let fixture = setup_close(&mut svm, 2, start_timestamp, 900_000, 900_000);
+
+        let result = send_close_participant(&mut svm, &fixture);
+        assert!(
+            result.is_ok(),
+            "Closing a fully claimed participant should succeed"
+        );
+
+        let participant_ata = derive_ata(&fixture.participant.pubkey(), &fixture.token_mint);
+        assert_eq!(token_balance(&svm, &participant_ata), 0);
+        assert!(is_closed(&svm, &fixture.participant_state));
+    }
+
+    #[test]
+    fn test_close_participant_after_full_claim_reclaims_rent() {
+        let mut svm = setup_svm();
+
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 30) as i64) as u64;
+        let fixture = setup_close(&mut svm, 4, start_timestamp, 900_000, 0);
+
+        let result = send_participant_instruction(&mut svm, &fixture, CLAIM_DISCRIMINATOR);
+        assert!(result.is_ok(), "Full claim should succeed");
+
+        let state_lamports = svm
+            .get_account(&fixture.participant_state)
+            .unwrap()
+            .lamports;
+        let lamports_before = svm
+            .get_account(&fixture.participant.pubkey())
+            .unwrap()
+            .lamports;
+
+        let result = send_close_participant(&mut svm, &fixture);
+        assert!(result.is_ok(), "Closing after a full claim should succeed");
+
+        // Only the transaction fee is paid, the rest of the state's lamports come back
+        let lamports_after = svm
+            .get_account(&fixture.participant.pubkey())
+            .unwrap()
+            .lamports;
+        assert_eq!(lamports_after, lamports_before + state_lamports - 5_000);
+        assert!(is_closed(&svm, &fixture.participant_state));
+    }
+
+    // ==================== FAILURE CASES ====================
+
+    #[test]
+    fn test_close_participant_partially_claimed_rejected() {
+        let mut svm = setup_svm();
+
+        // 2 of 9 steps vested and claimed, 700_000 still to vest
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let fixture = setup_close(&mut svm, 5, start_timestamp, 900_000, 200_000);
+
+        let result = send_close_participant(&mut svm, &fixture);
+        assert_custom_error(&result, VESTING_NOT_COMPLETE_ERROR);
+
+        assert!(!is_closed(&svm, &fixture.participant_state));
+        assert_eq!(token_balance(&svm, &fixture.vault), 700_000);
+    }
+
+    #[test]
+    fn test_close_participant_still_vesting_rejected() {
+        let mut svm = setup_svm();
+
+        // Started 3 days ago: only 2 of 9 steps vested
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let fixture = setup_close(&mut svm, 3, start_timestamp, 900_000, 0);
+
+        let result = send_close_participant(&mut svm, &fixture);
+        assert_custom_error(&result, VESTING_NOT_COMPLETE_ERROR);
+
+        assert!(!is_closed(&svm, &fixture.participant_state));
+        assert_eq!(token_balance(&svm, &fixture.vault), 900_000);
+    }
+}
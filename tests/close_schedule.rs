@@ -0,0 +1,178 @@
+mod common;
+
+#[cfg(test)]
+mod close_schedule_tests {
+    use crate::common::*;
+    use litesvm::LiteSVM;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction, InstructionError},
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::{Transaction, TransactionError},
+    };
+    use spl_token::ID as TOKEN_PROGRAM_ID;
+
+    const CLOSE_SCHEDULE_DISCRIMINATOR: u8 = 10;
+
+    // PinocchioError::VaultMismatch
+    const VAULT_MISMATCH_ERROR: u32 = 21;
+    // PinocchioError::VaultNotEmpty
+    const VAULT_NOT_EMPTY_ERROR: u32 = 25;
+
+    fn is_closed(svm: &LiteSVM, account: &Pubkey) -> bool {
+        svm.get_account(account).map_or(true, |account| {
+            account.lamports == 0 && account.data.is_empty()
+        })
+    }
+
+    struct Fixture {
+        authority: Keypair,
+        schedule: Pubkey,
+        vault: Pubkey,
+    }
+
+    fn setup_close(svm: &mut LiteSVM, seed: u64, vault_balance: u64) -> Fixture {
+        let authority = Keypair::new();
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(svm, &authority.pubkey());
+
+        // Ended long ago: every participant has claimed or been revoked
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 30) as i64) as u64;
+        let schedule = create_vest_schedule(
+            svm,
+            &authority.pubkey(),
+            &token_mint,
+            seed,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        let vault = create_ata_with_balance(svm, &schedule, &token_mint, vault_balance);
+
+        Fixture {
+            authority,
+            schedule,
+            vault,
+        }
+    }
+
+    fn send_close_schedule(
+        svm: &mut LiteSVM,
+        authority: &Keypair,
+        schedule: &Pubkey,
+        vault: &Pubkey,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new(*schedule, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            ],
+            data: vec![CLOSE_SCHEDULE_DISCRIMINATOR],
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&authority.pubkey()),
+            &[authority],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        result
+    }
+
+    // ==================== SUCCESS CASES ====================
+
+    #[test]
+    fn test_close_schedule_with_empty_vault() {
+        let mut svm = setup_svm();
+        let fixture = setup_close(&mut svm, 1, 0);
+
+        let rent_lamports = svm.get_account(&fixture.schedule).unwrap().lamports
+            + svm.get_account(&fixture.vault).unwrap().lamports;
+        let lamports_before = svm
+            .get_account(&fixture.authority.pubkey())
+            .unwrap()
+            .lamports;
+
+        let result = send_close_schedule(
+            &mut svm,
+            &fixture.authority,
+            &fixture.schedule,
+            &fixture.vault,
+        );
+        assert!(
+            result.is_ok(),
+            "Closing a schedule with an empty vault should succeed"
+        );
+
+        assert!(is_closed(&svm, &fixture.schedule));
+        assert!(is_closed(&svm, &fixture.vault));
+
+        // Both rents go to the authority, minus the transaction fee
+        let lamports_after = svm
+            .get_account(&fixture.authority.pubkey())
+            .unwrap()
+            .lamports;
+        assert_eq!(lamports_after, lamports_before + rent_lamports - 5_000);
+    }
+
+    // ==================== FAILURE CASES ====================
+
+    #[test]
+    fn test_close_schedule_vault_not_empty_rejected() {
+        let mut svm = setup_svm();
+        let fixture = setup_close(&mut svm, 2, 1);
+
+        let result = send_close_schedule(
+            &mut svm,
+            &fixture.authority,
+            &fixture.schedule,
+            &fixture.vault,
+        );
+        assert_custom_error(&result, VAULT_NOT_EMPTY_ERROR);
+
+        assert!(!is_closed(&svm, &fixture.schedule));
+        assert_eq!(token_balance(&svm, &fixture.vault), 1);
+    }
+
+    #[test]
+    fn test_close_schedule_wrong_authority_rejected() {
+        let mut svm = setup_svm();
+        let fixture = setup_close(&mut svm, 3, 0);
+
+        let attacker = Keypair::new();
+        svm.airdrop(&attacker.pubkey(), 10_000_000_000).unwrap();
+
+        let result = send_close_schedule(&mut svm, &attacker, &fixture.schedule, &fixture.vault);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::IllegalOwner)
+        );
+
+        assert!(!is_closed(&svm, &fixture.schedule));
+    }
+
+    #[test]
+    fn test_close_schedule_wrong_vault_rejected() {
+        let mut svm = setup_svm();
+        let fixture = setup_close(&mut svm, 4, 0);
+        let other = setup_close(&mut svm, 5, 0);
+
+        let result = send_close_schedule(
+            &mut svm,
+            &fixture.authority,
+            &fixture.schedule,
+            &other.vault,
+        );
+        assert_custom_error(&result, VAULT_MISMATCH_ERROR);
+    }
+}
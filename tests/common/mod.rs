@@ -0,0 +1,298 @@
+//! Fixtures shared by the LiteSVM tests. Program accounts are built from the crate's own
+//! `new_for_test` constructors and serialized with `to_bytes`, so the tests never spell out
+//! the account layouts byte by byte.
+#![allow(dead_code)]
+
+use litesvm::{
+    types::{FailedTransactionMetadata, TransactionMetadata},
+    LiteSVM,
+};
+use solana_sdk::{
+    account::Account, clock::Clock, instruction::InstructionError, pubkey::Pubkey,
+    transaction::TransactionError,
+};
+use spl_token::solana_program::program_option::COption;
+use spl_token::solana_program::program_pack::Pack;
+use spl_token::state::{Account as TokenAccount, AccountState, Mint};
+use spl_token::ID as TOKEN_PROGRAM_ID;
+use token_vesting::instruction_builder::{
+    associated_token_address, vest_participant_pda, vest_schedule_pda,
+};
+use token_vesting::{VestParticipant, VestParticipantSnapshot, VestSchedule, VestScheduleSnapshot};
+
+pub const PROGRAM_ID: Pubkey = Pubkey::new_from_array(token_vesting::ID);
+
+// January 1, 2025 00:00:00 UTC
+pub const JAN_1_2025: i64 = 1735689600;
+pub const ONE_DAY: u64 = 86_400;
+
+pub type TransactionResult = Result<TransactionMetadata, FailedTransactionMetadata>;
+
+pub fn setup_svm() -> LiteSVM {
+    let mut svm = LiteSVM::new().with_builtins().with_sigverify(false);
+    svm.add_program_from_file(PROGRAM_ID, "target/deploy/token_vesting.so")
+        .expect("Failed to load program");
+
+    warp_to_timestamp(&mut svm, JAN_1_2025);
+
+    svm
+}
+
+pub fn warp_to_timestamp(svm: &mut LiteSVM, unix_timestamp: i64) {
+    let current_clock = svm.get_sysvar::<Clock>();
+    svm.set_sysvar(&Clock {
+        unix_timestamp,
+        ..current_clock
+    });
+}
+
+pub fn print_transaction_logs(result: &TransactionResult) {
+    match result {
+        Ok(meta) => {
+            println!("\n=== Transaction Succeeded ===");
+            for log in &meta.logs {
+                println!("  {}", log);
+            }
+        }
+        Err(err) => {
+            println!("\n=== Transaction Failed ===");
+            println!("Error: {:?}", err.err);
+            for log in &err.meta.logs {
+                println!("  {}", log);
+            }
+        }
+    }
+}
+
+pub fn assert_custom_error(result: &TransactionResult, code: u32) {
+    match result {
+        Ok(_) => panic!("Expected custom error {}, transaction succeeded", code),
+        Err(err) => assert_eq!(
+            err.err,
+            TransactionError::InstructionError(0, InstructionError::Custom(code)),
+            "Unexpected error"
+        ),
+    }
+}
+
+fn set_program_account(svm: &mut LiteSVM, address: &Pubkey, data: Vec<u8>, owner: &Pubkey) {
+    svm.set_account(
+        *address,
+        Account {
+            lamports: 10_000_000,
+            data,
+            owner: *owner,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+}
+
+// ==================== TOKEN ACCOUNTS ====================
+
+pub fn derive_ata(owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+    associated_token_address(owner, mint, &TOKEN_PROGRAM_ID)
+}
+
+pub fn create_mock_token_mint(svm: &mut LiteSVM, authority: &Pubkey) -> Pubkey {
+    create_mock_token_mint_with_decimals(svm, authority, 6)
+}
+
+pub fn create_mock_token_mint_with_decimals(
+    svm: &mut LiteSVM,
+    authority: &Pubkey,
+    decimals: u8,
+) -> Pubkey {
+    let mint_pubkey = Pubkey::new_unique();
+
+    let mint_data = Mint {
+        mint_authority: COption::Some(*authority),
+        supply: 1_000_000_000,
+        decimals,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    };
+
+    let mut data = vec![0u8; Mint::LEN];
+    Mint::pack(mint_data, &mut data).unwrap();
+    set_program_account(svm, &mint_pubkey, data, &TOKEN_PROGRAM_ID);
+
+    mint_pubkey
+}
+
+pub fn create_ata_with_balance(
+    svm: &mut LiteSVM,
+    owner: &Pubkey,
+    mint: &Pubkey,
+    amount: u64,
+) -> Pubkey {
+    create_ata_with_balance_for_program(svm, owner, mint, amount, &TOKEN_PROGRAM_ID)
+}
+
+pub fn create_ata_with_balance_for_program(
+    svm: &mut LiteSVM,
+    owner: &Pubkey,
+    mint: &Pubkey,
+    amount: u64,
+    token_program: &Pubkey,
+) -> Pubkey {
+    let ata = associated_token_address(owner, mint, token_program);
+
+    let token_account = TokenAccount {
+        mint: *mint,
+        owner: *owner,
+        amount,
+        delegate: COption::None,
+        state: AccountState::Initialized,
+        is_native: COption::None,
+        delegated_amount: 0,
+        close_authority: COption::None,
+    };
+
+    let mut data = vec![0u8; TokenAccount::LEN];
+    TokenAccount::pack(token_account, &mut data).unwrap();
+    set_program_account(svm, &ata, data, token_program);
+
+    ata
+}
+
+pub fn token_balance(svm: &LiteSVM, ata: &Pubkey) -> u64 {
+    let account = svm.get_account(ata).unwrap();
+    TokenAccount::unpack(&account.data).unwrap().amount
+}
+
+// ==================== PROGRAM ACCOUNTS ====================
+
+/// Schedule `seed` as `Initialize` writes it with every optional setting off: SPL Token,
+/// the schedule's ATA as vault, the canonical bump and adds allowed after the start.
+pub fn vest_schedule_fixture(
+    authority: &Pubkey,
+    token_mint: &Pubkey,
+    seed: u64,
+    start_timestamp: u64,
+    cliff_duration: u64,
+    total_duration: u64,
+    step_duration: u64,
+) -> VestScheduleSnapshot {
+    let (schedule_pda, bump) = vest_schedule_pda(seed);
+
+    let mut schedule = VestSchedule::new_for_test(
+        start_timestamp,
+        cliff_duration,
+        total_duration,
+        step_duration,
+    )
+    .snapshot();
+    schedule.token_mint = token_mint.to_bytes();
+    schedule.authority = authority.to_bytes();
+    schedule.vault = derive_ata(&schedule_pda, token_mint).to_bytes();
+    schedule.seed = seed;
+    schedule.bump = bump;
+    schedule.token_program = TOKEN_PROGRAM_ID.to_bytes();
+    schedule.allow_add_after_start = true;
+    schedule
+}
+
+pub fn set_vest_schedule(svm: &mut LiteSVM, address: &Pubkey, schedule: &VestScheduleSnapshot) {
+    set_program_account(svm, address, schedule.to_bytes().to_vec(), &PROGRAM_ID);
+}
+
+pub fn create_vest_schedule(
+    svm: &mut LiteSVM,
+    authority: &Pubkey,
+    token_mint: &Pubkey,
+    seed: u64,
+    start_timestamp: u64,
+    cliff_duration: u64,
+    total_duration: u64,
+    step_duration: u64,
+) -> Pubkey {
+    let (schedule_pda, _) = vest_schedule_pda(seed);
+    let schedule = vest_schedule_fixture(
+        authority,
+        token_mint,
+        seed,
+        start_timestamp,
+        cliff_duration,
+        total_duration,
+        step_duration,
+    );
+    set_vest_schedule(svm, &schedule_pda, &schedule);
+
+    schedule_pda
+}
+
+pub fn read_vest_schedule(svm: &LiteSVM, address: &Pubkey) -> VestScheduleSnapshot {
+    VestSchedule::from_bytes(&svm.get_account(address).unwrap().data).unwrap()
+}
+
+/// Rewrites the stored schedule after `update`, for settings the fixture leaves off.
+pub fn update_vest_schedule(
+    svm: &mut LiteSVM,
+    address: &Pubkey,
+    update: impl FnOnce(&mut VestScheduleSnapshot),
+) {
+    let mut schedule = read_vest_schedule(svm, address);
+    update(&mut schedule);
+    set_vest_schedule(svm, address, &schedule);
+}
+
+/// Participant state as `AddParticipant` writes it, with the canonical bump.
+pub fn vest_participant_fixture(
+    participant: &Pubkey,
+    schedule: &Pubkey,
+    allocated_amount: u64,
+    claimed_amount: u64,
+) -> VestParticipantSnapshot {
+    let (_, bump) = vest_participant_pda(participant, schedule);
+
+    let mut participant_state =
+        VestParticipant::new_for_test(allocated_amount, claimed_amount).snapshot();
+    participant_state.participant = participant.to_bytes();
+    participant_state.schedule = schedule.to_bytes();
+    participant_state.bump = bump;
+    participant_state
+}
+
+pub fn set_participant_state(
+    svm: &mut LiteSVM,
+    address: &Pubkey,
+    participant_state: &VestParticipantSnapshot,
+) {
+    set_program_account(
+        svm,
+        address,
+        participant_state.to_bytes().to_vec(),
+        &PROGRAM_ID,
+    );
+}
+
+pub fn create_participant_state(
+    svm: &mut LiteSVM,
+    participant: &Pubkey,
+    schedule: &Pubkey,
+    allocated_amount: u64,
+    claimed_amount: u64,
+) -> Pubkey {
+    let (participant_state, _) = vest_participant_pda(participant, schedule);
+    let fixture = vest_participant_fixture(participant, schedule, allocated_amount, claimed_amount);
+    set_participant_state(svm, &participant_state, &fixture);
+
+    participant_state
+}
+
+pub fn read_participant_state(svm: &LiteSVM, address: &Pubkey) -> VestParticipantSnapshot {
+    VestParticipant::from_bytes(&svm.get_account(address).unwrap().data).unwrap()
+}
+
+/// Rewrites the stored participant state after `update`.
+pub fn update_participant_state(
+    svm: &mut LiteSVM,
+    address: &Pubkey,
+    update: impl FnOnce(&mut VestParticipantSnapshot),
+) {
+    let mut participant_state = read_participant_state(svm, address);
+    update(&mut participant_state);
+    set_participant_state(svm, address, &participant_state);
+}
@@ -0,0 +1,413 @@
+mod common;
+
+#[cfg(test)]
+mod config_tests {
+    use crate::common::*;
+    use litesvm::LiteSVM;
+    use pinocchio_system::ID;
+    use solana_sdk::{
+        account::Account,
+        instruction::{AccountMeta, Instruction, InstructionError},
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::{Transaction, TransactionError},
+    };
+    use spl_associated_token_account::ID as ATA_PROGRAM_ID;
+    use spl_token::ID as TOKEN_PROGRAM_ID;
+    use token_vesting::instruction_builder::config_pda;
+    use token_vesting::{Config, ACCOUNT_VERSION};
+
+    // BPFLoaderUpgradeab1e11111111111111111111111
+    const BPF_LOADER_UPGRADEABLE_ID: Pubkey = Pubkey::new_from_array([
+        0x02, 0xa8, 0xf6, 0x91, 0x4e, 0x88, 0xa1, 0xb0, 0xe2, 0x10, 0x15, 0x3e, 0xf7, 0x63, 0xae,
+        0x2b, 0x00, 0xc2, 0xb9, 0x3d, 0x16, 0xc1, 0x24, 0xd2, 0xc0, 0x53, 0x7a, 0x10, 0x04, 0x80,
+        0x00, 0x00,
+    ]);
+
+    const CLAIM_DISCRIMINATOR: u8 = 2;
+    const INITIALIZE_CONFIG_DISCRIMINATOR: u8 = 7;
+    const SET_CLAIMS_ENABLED_DISCRIMINATOR: u8 = 8;
+    const SET_CONFIG_ADMIN_DISCRIMINATOR: u8 = 32;
+
+    // PinocchioError::ClaimsDisabled
+    const CLAIMS_DISABLED_ERROR: u32 = 24;
+    // PinocchioError::ConfigMismatch
+    const CONFIG_MISMATCH_ERROR: u32 = 34;
+
+    fn derive_program_data() -> Pubkey {
+        Pubkey::find_program_address(&[PROGRAM_ID.as_ref()], &BPF_LOADER_UPGRADEABLE_ID).0
+    }
+
+    // Writes the program's ProgramData account with `authority` as its upgrade authority
+    fn set_upgrade_authority(svm: &mut LiteSVM, authority: &Pubkey) {
+        let mut data = Vec::with_capacity(45);
+        data.extend_from_slice(&3u32.to_le_bytes()); // UpgradeableLoaderState::ProgramData
+        data.extend_from_slice(&0u64.to_le_bytes()); // Slot: u64 (8)
+        data.push(1); // Upgrade authority: Option<Pubkey>, Some
+        data.extend_from_slice(authority.as_ref());
+
+        svm.set_account(
+            derive_program_data(),
+            Account {
+                lamports: 10_000_000,
+                data,
+                owner: BPF_LOADER_UPGRADEABLE_ID,
+                executable: false,
+                rent_epoch: 0,
+            }
+            .into(),
+        );
+    }
+
+    fn stored_admin(svm: &LiteSVM) -> Pubkey {
+        let data = svm.get_account(&config_pda().0).unwrap().data;
+        Pubkey::new_from_array(*Config::load(&data).unwrap().admin())
+    }
+
+    fn stored_claims_enabled(svm: &LiteSVM) -> bool {
+        let data = svm.get_account(&config_pda().0).unwrap().data;
+        Config::load(&data).unwrap().claims_enabled()
+    }
+
+    fn send_initialize_config(
+        svm: &mut LiteSVM,
+        admin: &Keypair,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let (config, bump) = config_pda();
+
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(admin.pubkey(), true),
+                AccountMeta::new(config, false),
+                AccountMeta::new_readonly(derive_program_data(), false),
+                AccountMeta::new_readonly(ID.into(), false),
+            ],
+            data: vec![INITIALIZE_CONFIG_DISCRIMINATOR, bump],
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&admin.pubkey()),
+            &[admin],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        result
+    }
+
+    fn send_set_claims_enabled(
+        svm: &mut LiteSVM,
+        admin: &Keypair,
+        claims_enabled: bool,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let (config, _) = config_pda();
+
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(admin.pubkey(), true),
+                AccountMeta::new(config, false),
+            ],
+            data: vec![SET_CLAIMS_ENABLED_DISCRIMINATOR, claims_enabled as u8],
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&admin.pubkey()),
+            &[admin],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        result
+    }
+
+    fn send_set_config_admin(
+        svm: &mut LiteSVM,
+        admin: &Keypair,
+        new_admin: &Pubkey,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let (config, _) = config_pda();
+
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(admin.pubkey(), true),
+                AccountMeta::new(config, false),
+                AccountMeta::new_readonly(*new_admin, false),
+            ],
+            data: vec![SET_CONFIG_ADMIN_DISCRIMINATOR],
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&admin.pubkey()),
+            &[admin],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        result
+    }
+
+    struct Fixture {
+        participant: Keypair,
+        token_mint: Pubkey,
+        schedule: Pubkey,
+        participant_state: Pubkey,
+        vault: Pubkey,
+    }
+
+    // Fully vested participant of a fresh schedule
+    fn setup_claimable(svm: &mut LiteSVM, seed: u64, allocated: u64) -> Fixture {
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(svm, &authority.pubkey());
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 30) as i64) as u64;
+
+        let schedule = create_vest_schedule(
+            svm,
+            &authority.pubkey(),
+            &token_mint,
+            seed,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        let participant_state =
+            create_participant_state(svm, &participant.pubkey(), &schedule, allocated, 0);
+        let vault = create_ata_with_balance(svm, &schedule, &token_mint, allocated);
+
+        Fixture {
+            participant,
+            token_mint,
+            schedule,
+            participant_state,
+            vault,
+        }
+    }
+
+    fn send_claim(
+        svm: &mut LiteSVM,
+        fixture: &Fixture,
+        config: &Pubkey,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let participant_ata = derive_ata(&fixture.participant.pubkey(), &fixture.token_mint);
+
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(fixture.participant.pubkey(), true),
+                AccountMeta::new(fixture.participant_state, false),
+                AccountMeta::new(participant_ata, false),
+                AccountMeta::new(fixture.schedule, false),
+                AccountMeta::new(fixture.vault, false),
+                AccountMeta::new_readonly(fixture.token_mint, false),
+                AccountMeta::new_readonly(ID.into(), false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ATA_PROGRAM_ID, false),
+                AccountMeta::new_readonly(*config, false),
+            ],
+            data: vec![CLAIM_DISCRIMINATOR],
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&fixture.participant.pubkey()),
+            &[&fixture.participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        result
+    }
+
+    // ==================== SUCCESS CASES ====================
+
+    #[test]
+    fn test_initialize_config() {
+        let mut svm = setup_svm();
+        let admin = Keypair::new();
+        svm.airdrop(&admin.pubkey(), 10_000_000_000).unwrap();
+        set_upgrade_authority(&mut svm, &admin.pubkey());
+
+        let result = send_initialize_config(&mut svm, &admin);
+        assert!(result.is_ok(), "InitializeConfig should succeed");
+
+        let (config, bump) = config_pda();
+        let account = svm.get_account(&config).unwrap();
+        assert_eq!(account.owner, PROGRAM_ID);
+        let config_state = Config::load(&account.data).unwrap();
+        assert_eq!(config_state.discriminator, Config::DISCRIMINATOR);
+        assert_eq!(config_state.version, ACCOUNT_VERSION);
+        assert_eq!(config_state.admin(), &admin.pubkey().to_bytes());
+        assert!(config_state.claims_enabled(), "Claims start enabled");
+        assert_eq!(config_state.bump(), bump);
+    }
+
+    #[test]
+    fn test_claim_without_initialized_config_allowed() {
+        let mut svm = setup_svm();
+        let fixture = setup_claimable(&mut svm, 1, 900_000);
+
+        let (config, _) = config_pda();
+        let result = send_claim(&mut svm, &fixture, &config);
+        assert!(
+            result.is_ok(),
+            "Claims are enabled until a config says otherwise"
+        );
+        assert_eq!(token_balance(&svm, &fixture.vault), 0);
+    }
+
+    #[test]
+    fn test_global_kill_switch_blocks_and_restores_all_schedules() {
+        let mut svm = setup_svm();
+        let admin = Keypair::new();
+        svm.airdrop(&admin.pubkey(), 10_000_000_000).unwrap();
+        set_upgrade_authority(&mut svm, &admin.pubkey());
+
+        assert!(send_initialize_config(&mut svm, &admin).is_ok());
+        let (config, _) = config_pda();
+
+        let first = setup_claimable(&mut svm, 2, 900_000);
+        let second = setup_claimable(&mut svm, 3, 500_000);
+
+        assert!(send_set_claims_enabled(&mut svm, &admin, false).is_ok());
+
+        for fixture in [&first, &second] {
+            let result = send_claim(&mut svm, fixture, &config);
+            assert_custom_error(&result, CLAIMS_DISABLED_ERROR);
+        }
+        assert_eq!(token_balance(&svm, &first.vault), 900_000);
+        assert_eq!(token_balance(&svm, &second.vault), 500_000);
+
+        svm.expire_blockhash();
+        assert!(send_set_claims_enabled(&mut svm, &admin, true).is_ok());
+
+        for fixture in [&first, &second] {
+            let result = send_claim(&mut svm, fixture, &config);
+            assert!(result.is_ok(), "Claims should work again once re-enabled");
+        }
+        assert_eq!(token_balance(&svm, &first.vault), 0);
+        assert_eq!(token_balance(&svm, &second.vault), 0);
+    }
+
+    #[test]
+    fn test_set_config_admin_hands_over_kill_switch() {
+        let mut svm = setup_svm();
+        let admin = Keypair::new();
+        let new_admin = Keypair::new();
+        svm.airdrop(&admin.pubkey(), 10_000_000_000).unwrap();
+        svm.airdrop(&new_admin.pubkey(), 10_000_000_000).unwrap();
+        set_upgrade_authority(&mut svm, &admin.pubkey());
+
+        assert!(send_initialize_config(&mut svm, &admin).is_ok());
+        assert!(send_set_config_admin(&mut svm, &admin, &new_admin.pubkey()).is_ok());
+
+        assert_eq!(stored_admin(&svm), new_admin.pubkey());
+
+        let result = send_set_claims_enabled(&mut svm, &admin, false);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::IllegalOwner)
+        );
+        assert!(send_set_claims_enabled(&mut svm, &new_admin, false).is_ok());
+        assert!(!stored_claims_enabled(&svm));
+    }
+
+    // ==================== FAILURE CASES ====================
+
+    #[test]
+    fn test_initialize_config_not_upgrade_authority_rejected() {
+        let mut svm = setup_svm();
+        let upgrade_authority = Keypair::new();
+        let impostor = Keypair::new();
+        svm.airdrop(&impostor.pubkey(), 10_000_000_000).unwrap();
+        set_upgrade_authority(&mut svm, &upgrade_authority.pubkey());
+
+        let result = send_initialize_config(&mut svm, &impostor);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::IllegalOwner)
+        );
+
+        let (config, _) = config_pda();
+        assert!(svm
+            .get_account(&config)
+            .is_none_or(|account| account.data.is_empty()));
+    }
+
+    #[test]
+    fn test_set_config_admin_wrong_admin_rejected() {
+        let mut svm = setup_svm();
+        let admin = Keypair::new();
+        let impostor = Keypair::new();
+        svm.airdrop(&admin.pubkey(), 10_000_000_000).unwrap();
+        svm.airdrop(&impostor.pubkey(), 10_000_000_000).unwrap();
+        set_upgrade_authority(&mut svm, &admin.pubkey());
+
+        assert!(send_initialize_config(&mut svm, &admin).is_ok());
+
+        let result = send_set_config_admin(&mut svm, &impostor, &impostor.pubkey());
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::IllegalOwner)
+        );
+
+        assert_eq!(stored_admin(&svm), admin.pubkey());
+    }
+
+    #[test]
+    fn test_set_claims_enabled_wrong_admin_rejected() {
+        let mut svm = setup_svm();
+        let admin = Keypair::new();
+        let impostor = Keypair::new();
+        svm.airdrop(&admin.pubkey(), 10_000_000_000).unwrap();
+        svm.airdrop(&impostor.pubkey(), 10_000_000_000).unwrap();
+        set_upgrade_authority(&mut svm, &admin.pubkey());
+
+        assert!(send_initialize_config(&mut svm, &admin).is_ok());
+
+        let result = send_set_claims_enabled(&mut svm, &impostor, false);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::IllegalOwner)
+        );
+
+        assert!(stored_claims_enabled(&svm));
+    }
+
+    #[test]
+    fn test_claim_with_substituted_config_rejected() {
+        let mut svm = setup_svm();
+        let admin = Keypair::new();
+        svm.airdrop(&admin.pubkey(), 10_000_000_000).unwrap();
+        set_upgrade_authority(&mut svm, &admin.pubkey());
+
+        assert!(send_initialize_config(&mut svm, &admin).is_ok());
+        assert!(send_set_claims_enabled(&mut svm, &admin, false).is_ok());
+
+        // Passing any other account must not sidestep the disabled config
+        let fixture = setup_claimable(&mut svm, 4, 900_000);
+        let result = send_claim(&mut svm, &fixture, &Pubkey::new_unique());
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::Custom(CONFIG_MISMATCH_ERROR))
+        );
+        assert_eq!(token_balance(&svm, &fixture.vault), 900_000);
+    }
+}
@@ -0,0 +1,215 @@
+mod common;
+
+#[cfg(test)]
+mod crank_claim_tests {
+    use crate::common::*;
+    use litesvm::LiteSVM;
+    use pinocchio_system::ID;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    };
+    use spl_associated_token_account::ID as ATA_PROGRAM_ID;
+    use spl_token::ID as TOKEN_PROGRAM_ID;
+    use token_vesting::instruction_builder::config_pda;
+
+    const CRANK_CLAIM_DISCRIMINATOR: u8 = 15;
+
+    // PinocchioError::InvalidAccountData
+    const INVALID_ACCOUNT_DATA_ERROR: u32 = 2;
+    // PinocchioError::ClaimCooldown
+    const CLAIM_COOLDOWN_ERROR: u32 = 43;
+
+    fn set_claim_cooldown(
+        svm: &mut LiteSVM,
+        schedule: &Pubkey,
+        cooldown: u64,
+        exempts_participant: bool,
+    ) {
+        update_vest_schedule(svm, schedule, |schedule| {
+            schedule.claim_cooldown = cooldown;
+            schedule.cooldown_exempts_participant = exempts_participant;
+        });
+    }
+
+    fn last_claim_ts(svm: &LiteSVM, participant_state: &Pubkey) -> u64 {
+        read_participant_state(svm, participant_state).last_claim_ts
+    }
+
+    struct Fixture {
+        participant: Pubkey,
+        token_mint: Pubkey,
+        schedule: Pubkey,
+        participant_state: Pubkey,
+        vault: Pubkey,
+        participant_ata: Pubkey,
+    }
+
+    // Started 3 days ago with a 1 day cliff and 1 day steps: 2 of 9 steps vested
+    fn setup_crank(svm: &mut LiteSVM, seed: u64) -> Fixture {
+        let authority = Keypair::new();
+        // The participant never signs or pays
+        let participant = Pubkey::new_unique();
+
+        let token_mint = create_mock_token_mint(svm, &authority.pubkey());
+
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let schedule = create_vest_schedule(
+            svm,
+            &authority.pubkey(),
+            &token_mint,
+            seed,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        let participant_state = create_participant_state(svm, &participant, &schedule, 900_000, 0);
+        let vault = create_ata_with_balance(svm, &schedule, &token_mint, 900_000);
+        let participant_ata = create_ata_with_balance(svm, &participant, &token_mint, 0);
+
+        Fixture {
+            participant,
+            token_mint,
+            schedule,
+            participant_state,
+            vault,
+            participant_ata,
+        }
+    }
+
+    fn send_crank_claim(
+        svm: &mut LiteSVM,
+        keeper: &Keypair,
+        fixture: &Fixture,
+        destination: &Pubkey,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(keeper.pubkey(), true),
+                AccountMeta::new_readonly(fixture.participant, false),
+                AccountMeta::new(fixture.participant_state, false),
+                AccountMeta::new(*destination, false),
+                AccountMeta::new(fixture.schedule, false),
+                AccountMeta::new(fixture.vault, false),
+                AccountMeta::new_readonly(fixture.token_mint, false),
+                AccountMeta::new_readonly(ID.into(), false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ATA_PROGRAM_ID, false),
+                AccountMeta::new_readonly(config_pda().0, false),
+            ],
+            data: vec![CRANK_CLAIM_DISCRIMINATOR],
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&keeper.pubkey()),
+            &[keeper],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        result
+    }
+
+    // ==================== SUCCESS CASES ====================
+
+    #[test]
+    fn test_crank_claim_pays_participant_ata() {
+        let mut svm = setup_svm();
+        let fixture = setup_crank(&mut svm, 1);
+
+        let keeper = Keypair::new();
+        svm.airdrop(&keeper.pubkey(), 10_000_000_000).unwrap();
+
+        let result = send_crank_claim(&mut svm, &keeper, &fixture, &fixture.participant_ata);
+        assert!(result.is_ok(), "Anyone should be able to crank a claim");
+
+        assert_eq!(token_balance(&svm, &fixture.participant_ata), 200_000);
+        assert_eq!(token_balance(&svm, &fixture.vault), 700_000);
+    }
+
+    #[test]
+    fn test_crank_claim_after_cooldown_elapses() {
+        let mut svm = setup_svm();
+        let fixture = setup_crank(&mut svm, 3);
+        set_claim_cooldown(&mut svm, &fixture.schedule, ONE_DAY * 2, false);
+
+        let keeper = Keypair::new();
+        svm.airdrop(&keeper.pubkey(), 10_000_000_000).unwrap();
+
+        let result = send_crank_claim(&mut svm, &keeper, &fixture, &fixture.participant_ata);
+        assert!(result.is_ok(), "A first claim has no cooldown to wait for");
+        assert_eq!(
+            last_claim_ts(&svm, &fixture.participant_state),
+            JAN_1_2025 as u64
+        );
+
+        warp_to_timestamp(&mut svm, JAN_1_2025 + (ONE_DAY * 2) as i64);
+        svm.expire_blockhash();
+
+        let result = send_crank_claim(&mut svm, &keeper, &fixture, &fixture.participant_ata);
+        assert!(
+            result.is_ok(),
+            "Claim should succeed once the cooldown elapsed"
+        );
+
+        assert_eq!(token_balance(&svm, &fixture.participant_ata), 400_000);
+        assert_eq!(
+            last_claim_ts(&svm, &fixture.participant_state),
+            JAN_1_2025 as u64 + ONE_DAY * 2
+        );
+    }
+
+    // ==================== FAILURE CASES ====================
+
+    #[test]
+    fn test_crank_claim_to_foreign_ata_rejected() {
+        let mut svm = setup_svm();
+        let fixture = setup_crank(&mut svm, 2);
+
+        let keeper = Keypair::new();
+        svm.airdrop(&keeper.pubkey(), 10_000_000_000).unwrap();
+        let keeper_ata =
+            create_ata_with_balance(&mut svm, &keeper.pubkey(), &fixture.token_mint, 0);
+
+        let result = send_crank_claim(&mut svm, &keeper, &fixture, &keeper_ata);
+        assert_custom_error(&result, INVALID_ACCOUNT_DATA_ERROR);
+
+        assert_eq!(token_balance(&svm, &keeper_ata), 0);
+        assert_eq!(token_balance(&svm, &fixture.vault), 900_000);
+    }
+
+    #[test]
+    fn test_crank_claim_within_cooldown_rejected() {
+        let mut svm = setup_svm();
+        // Exempting the participant doesn't let a keeper through
+        let fixture = setup_crank(&mut svm, 4);
+        set_claim_cooldown(&mut svm, &fixture.schedule, ONE_DAY * 2, true);
+
+        let keeper = Keypair::new();
+        svm.airdrop(&keeper.pubkey(), 10_000_000_000).unwrap();
+
+        let result = send_crank_claim(&mut svm, &keeper, &fixture, &fixture.participant_ata);
+        assert!(result.is_ok(), "A first claim has no cooldown to wait for");
+
+        // A new step has vested, but the cooldown runs for another day
+        warp_to_timestamp(&mut svm, JAN_1_2025 + ONE_DAY as i64);
+        svm.expire_blockhash();
+
+        let result = send_crank_claim(&mut svm, &keeper, &fixture, &fixture.participant_ata);
+        assert_custom_error(&result, CLAIM_COOLDOWN_ERROR);
+
+        assert_eq!(token_balance(&svm, &fixture.participant_ata), 200_000);
+        assert_eq!(
+            last_claim_ts(&svm, &fixture.participant_state),
+            JAN_1_2025 as u64
+        );
+    }
+}
@@ -0,0 +1,149 @@
+mod common;
+
+#[cfg(test)]
+mod ensure_vault_tests {
+    use crate::common::*;
+    use litesvm::LiteSVM;
+    use pinocchio_system::ID;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction, InstructionError},
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::{Transaction, TransactionError},
+    };
+    use spl_associated_token_account::ID as ATA_PROGRAM_ID;
+    use spl_token::solana_program::program_pack::Pack;
+    use spl_token::state::Account as TokenAccount;
+    use spl_token::ID as TOKEN_PROGRAM_ID;
+
+    const ENSURE_VAULT_DISCRIMINATOR: u8 = 29;
+
+    struct Fixture {
+        authority: Keypair,
+        schedule: Pubkey,
+        vault: Pubkey,
+        token_mint: Pubkey,
+    }
+
+    // A schedule created without a vault: none recorded and no token account on chain
+    fn setup_schedule_without_vault(svm: &mut LiteSVM, seed: u64) -> Fixture {
+        let authority = Keypair::new();
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(svm, &authority.pubkey());
+        let schedule = create_vest_schedule(
+            svm,
+            &authority.pubkey(),
+            &token_mint,
+            seed,
+            (JAN_1_2025 + ONE_DAY as i64) as u64,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        update_vest_schedule(svm, &schedule, |schedule| schedule.vault = [0; 32]);
+
+        Fixture {
+            authority,
+            schedule,
+            vault: derive_ata(&schedule, &token_mint),
+            token_mint,
+        }
+    }
+
+    fn send_ensure_vault(
+        svm: &mut LiteSVM,
+        fixture: &Fixture,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(fixture.authority.pubkey(), true),
+                AccountMeta::new(fixture.schedule, false),
+                AccountMeta::new(fixture.vault, false),
+                AccountMeta::new_readonly(fixture.token_mint, false),
+                AccountMeta::new_readonly(ID.into(), false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ATA_PROGRAM_ID, false),
+            ],
+            data: vec![ENSURE_VAULT_DISCRIMINATOR],
+        };
+
+        // Re-runs would otherwise be identical transactions
+        svm.expire_blockhash();
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&fixture.authority.pubkey()),
+            &[&fixture.authority],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        result
+    }
+
+    fn recorded_vault(svm: &LiteSVM, schedule: &Pubkey) -> Pubkey {
+        Pubkey::new_from_array(read_vest_schedule(svm, schedule).vault)
+    }
+
+    fn vault_token_account(svm: &LiteSVM, vault: &Pubkey) -> TokenAccount {
+        let account = svm.get_account(vault).unwrap();
+        assert_eq!(account.owner, TOKEN_PROGRAM_ID);
+        TokenAccount::unpack(&account.data).unwrap()
+    }
+
+    #[test]
+    fn test_ensure_vault_creates_missing_vault() {
+        let mut svm = setup_svm();
+        let fixture = setup_schedule_without_vault(&mut svm, 1);
+        assert!(svm.get_account(&fixture.vault).is_none());
+
+        let result = send_ensure_vault(&mut svm, &fixture);
+        assert!(result.is_ok(), "Creating the missing vault should succeed");
+
+        let vault = vault_token_account(&svm, &fixture.vault);
+        assert_eq!(vault.owner, fixture.schedule);
+        assert_eq!(vault.mint, fixture.token_mint);
+        assert_eq!(vault.amount, 0);
+        assert_eq!(recorded_vault(&svm, &fixture.schedule), fixture.vault);
+
+        // Running it again changes nothing
+        let result = send_ensure_vault(&mut svm, &fixture);
+        assert!(result.is_ok(), "Re-running should be a no-op");
+        assert_eq!(recorded_vault(&svm, &fixture.schedule), fixture.vault);
+    }
+
+    #[test]
+    fn test_ensure_vault_existing_vault_is_noop() {
+        let mut svm = setup_svm();
+        let fixture = setup_schedule_without_vault(&mut svm, 2);
+        create_ata_with_balance(&mut svm, &fixture.schedule, &fixture.token_mint, 5_000);
+
+        let result = send_ensure_vault(&mut svm, &fixture);
+        assert!(result.is_ok(), "An existing vault should be accepted");
+
+        assert_eq!(vault_token_account(&svm, &fixture.vault).amount, 5_000);
+        assert_eq!(recorded_vault(&svm, &fixture.schedule), fixture.vault);
+    }
+
+    #[test]
+    fn test_ensure_vault_wrong_authority_rejected() {
+        let mut svm = setup_svm();
+        let mut fixture = setup_schedule_without_vault(&mut svm, 3);
+
+        let impostor = Keypair::new();
+        svm.airdrop(&impostor.pubkey(), 10_000_000_000).unwrap();
+        fixture.authority = impostor;
+
+        let result = send_ensure_vault(&mut svm, &fixture);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::IllegalOwner)
+        );
+        assert!(svm.get_account(&fixture.vault).is_none());
+    }
+}
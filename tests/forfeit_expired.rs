@@ -0,0 +1,179 @@
+mod common;
+
+#[cfg(test)]
+mod forfeit_expired_tests {
+    use crate::common::*;
+    use litesvm::LiteSVM;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction, InstructionError},
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::{Transaction, TransactionError},
+    };
+    use spl_token::ID as TOKEN_PROGRAM_ID;
+    use token_vesting::instruction_builder::claim_ix;
+
+    const FORFEIT_EXPIRED_DISCRIMINATOR: u8 = 33;
+
+    // PinocchioError::FullyClaimed
+    const FULLY_CLAIMED_ERROR: u32 = 44;
+
+    struct Fixture {
+        authority: Keypair,
+        participant: Keypair,
+        token_mint: Pubkey,
+        schedule: Pubkey,
+        participant_state: Pubkey,
+        vault: Pubkey,
+    }
+
+    // Fully vested 20 days ago: cliff 1 day, 10 day total, 1 day steps, 900,000 allocated
+    fn setup_schedule(svm: &mut LiteSVM, seed: u64, claim_window: u64) -> Fixture {
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(svm, &authority.pubkey());
+        let schedule = create_vest_schedule(
+            svm,
+            &authority.pubkey(),
+            &token_mint,
+            seed,
+            (JAN_1_2025 - (ONE_DAY * 30) as i64) as u64,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+        update_vest_schedule(svm, &schedule, |schedule| {
+            schedule.claim_window = claim_window;
+            schedule.total_allocated = 900_000;
+        });
+
+        let participant_state =
+            create_participant_state(svm, &participant.pubkey(), &schedule, 900_000, 0);
+        let vault = create_ata_with_balance(svm, &schedule, &token_mint, 900_000);
+        create_ata_with_balance(svm, &participant.pubkey(), &token_mint, 0);
+
+        Fixture {
+            authority,
+            participant,
+            token_mint,
+            schedule,
+            participant_state,
+            vault,
+        }
+    }
+
+    fn send_forfeit_expired(
+        svm: &mut LiteSVM,
+        fixture: &Fixture,
+        signer: &Keypair,
+    ) -> TransactionResult {
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(signer.pubkey(), true),
+                AccountMeta::new(fixture.schedule, false),
+                AccountMeta::new(fixture.participant_state, false),
+            ],
+            data: vec![FORFEIT_EXPIRED_DISCRIMINATOR],
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        result
+    }
+
+    #[test]
+    fn test_forfeit_expired_releases_allocation() {
+        let mut svm = setup_svm();
+        let fixture = setup_schedule(&mut svm, 1, ONE_DAY * 2);
+
+        let result = send_forfeit_expired(&mut svm, &fixture, &fixture.authority);
+        assert!(
+            result.is_ok(),
+            "Authority should be able to record the forfeiture"
+        );
+
+        assert_eq!(
+            read_participant_state(&svm, &fixture.participant_state).forfeited_amount,
+            900_000
+        );
+        assert_eq!(
+            read_vest_schedule(&svm, &fixture.schedule).total_allocated,
+            0
+        );
+
+        // A second call finds nothing new to forfeit
+        svm.expire_blockhash();
+        let result = send_forfeit_expired(&mut svm, &fixture, &fixture.authority);
+        assert!(result.is_ok(), "Re-running should be a no-op");
+        assert_eq!(
+            read_vest_schedule(&svm, &fixture.schedule).total_allocated,
+            0
+        );
+
+        // With the forfeiture recorded the participant is done rather than expired
+        let instruction = claim_ix(
+            &fixture.participant.pubkey(),
+            &fixture.schedule,
+            &fixture.vault,
+            &fixture.token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&fixture.participant.pubkey()),
+            &[&fixture.participant],
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert_custom_error(&result, FULLY_CLAIMED_ERROR);
+    }
+
+    #[test]
+    fn test_forfeit_expired_without_claim_window_changes_nothing() {
+        let mut svm = setup_svm();
+        let fixture = setup_schedule(&mut svm, 2, 0);
+
+        let result = send_forfeit_expired(&mut svm, &fixture, &fixture.authority);
+        assert!(result.is_ok(), "Nothing expired should still succeed");
+
+        assert_eq!(
+            read_participant_state(&svm, &fixture.participant_state).forfeited_amount,
+            0
+        );
+        assert_eq!(
+            read_vest_schedule(&svm, &fixture.schedule).total_allocated,
+            900_000
+        );
+    }
+
+    #[test]
+    fn test_forfeit_expired_rejects_non_authority() {
+        let mut svm = setup_svm();
+        let fixture = setup_schedule(&mut svm, 3, ONE_DAY * 2);
+
+        let attacker = Keypair::new();
+        svm.airdrop(&attacker.pubkey(), 10_000_000_000).unwrap();
+
+        let result = send_forfeit_expired(&mut svm, &fixture, &attacker);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::IllegalOwner)
+        );
+        assert_eq!(
+            read_participant_state(&svm, &fixture.participant_state).forfeited_amount,
+            0
+        );
+    }
+}
@@ -0,0 +1,197 @@
+mod common;
+
+#[cfg(test)]
+mod freeze_claims_tests {
+    use crate::common::*;
+    use litesvm::LiteSVM;
+    use pinocchio_system::ID;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction, InstructionError},
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::{Transaction, TransactionError},
+    };
+    use spl_associated_token_account::ID as ATA_PROGRAM_ID;
+    use spl_token::ID as TOKEN_PROGRAM_ID;
+    use token_vesting::instruction_builder::config_pda;
+
+    const CLAIM_DISCRIMINATOR: u8 = 2;
+    const FREEZE_CLAIMS_DISCRIMINATOR: u8 = 27;
+    const UNFREEZE_CLAIMS_DISCRIMINATOR: u8 = 28;
+
+    // PinocchioError::ClaimsFrozen
+    const CLAIMS_FROZEN_ERROR: u32 = 39;
+    // PinocchioError::ClaimsNotFrozen
+    const CLAIMS_NOT_FROZEN_ERROR: u32 = 40;
+
+    struct Fixture {
+        authority: Keypair,
+        participant: Keypair,
+        token_mint: Pubkey,
+        schedule: Pubkey,
+        participant_state: Pubkey,
+        vault: Pubkey,
+    }
+
+    // Started 3 days ago with a 1 day cliff and 1 day steps: 2 of 9 steps vested
+    fn setup_schedule(svm: &mut LiteSVM, seed: u64) -> Fixture {
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(svm, &authority.pubkey());
+
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let schedule = create_vest_schedule(
+            svm,
+            &authority.pubkey(),
+            &token_mint,
+            seed,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        let participant_state =
+            create_participant_state(svm, &participant.pubkey(), &schedule, 900_000, 0);
+        let vault = create_ata_with_balance(svm, &schedule, &token_mint, 900_000);
+
+        Fixture {
+            authority,
+            participant,
+            token_mint,
+            schedule,
+            participant_state,
+            vault,
+        }
+    }
+
+    fn send_freeze_or_unfreeze(
+        svm: &mut LiteSVM,
+        fixture: &Fixture,
+        discriminator: u8,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(fixture.authority.pubkey(), true),
+                AccountMeta::new(fixture.schedule, false),
+            ],
+            data: vec![discriminator],
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&fixture.authority.pubkey()),
+            &[&fixture.authority],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        result
+    }
+
+    fn send_claim(
+        svm: &mut LiteSVM,
+        fixture: &Fixture,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let participant_ata = derive_ata(&fixture.participant.pubkey(), &fixture.token_mint);
+
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(fixture.participant.pubkey(), true),
+                AccountMeta::new(fixture.participant_state, false),
+                AccountMeta::new(participant_ata, false),
+                AccountMeta::new(fixture.schedule, false),
+                AccountMeta::new(fixture.vault, false),
+                AccountMeta::new_readonly(fixture.token_mint, false),
+                AccountMeta::new_readonly(ID.into(), false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ATA_PROGRAM_ID, false),
+                AccountMeta::new_readonly(config_pda().0, false),
+            ],
+            data: vec![CLAIM_DISCRIMINATOR],
+        };
+
+        // Repeated claims would otherwise be identical transactions
+        svm.expire_blockhash();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&fixture.participant.pubkey()),
+            &[&fixture.participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        result
+    }
+
+    fn claimed_so_far(svm: &LiteSVM, fixture: &Fixture) -> u64 {
+        token_balance(
+            svm,
+            &derive_ata(&fixture.participant.pubkey(), &fixture.token_mint),
+        )
+    }
+
+    // ==================== SUCCESS CASES ====================
+
+    #[test]
+    fn test_freeze_claims_holds_claims_and_catches_up_after_unfreeze() {
+        let mut svm = setup_svm();
+        let fixture = setup_schedule(&mut svm, 1);
+
+        assert!(send_freeze_or_unfreeze(&mut svm, &fixture, FREEZE_CLAIMS_DISCRIMINATOR).is_ok());
+
+        let result = send_claim(&mut svm, &fixture);
+        assert_custom_error(&result, CLAIMS_FROZEN_ERROR);
+        assert_eq!(claimed_so_far(&svm, &fixture), 0);
+
+        // Accrual kept running through the two day freeze
+        warp_to_timestamp(&mut svm, JAN_1_2025 + (ONE_DAY * 2) as i64);
+        assert!(send_freeze_or_unfreeze(&mut svm, &fixture, UNFREEZE_CLAIMS_DISCRIMINATOR).is_ok());
+
+        assert!(send_claim(&mut svm, &fixture).is_ok());
+        assert_eq!(claimed_so_far(&svm, &fixture), 400_000);
+    }
+
+    // ==================== FAILURE CASES ====================
+
+    #[test]
+    fn test_freeze_twice_and_unfreeze_unfrozen_rejected() {
+        let mut svm = setup_svm();
+        let fixture = setup_schedule(&mut svm, 2);
+
+        let result = send_freeze_or_unfreeze(&mut svm, &fixture, UNFREEZE_CLAIMS_DISCRIMINATOR);
+        assert_custom_error(&result, CLAIMS_NOT_FROZEN_ERROR);
+
+        assert!(send_freeze_or_unfreeze(&mut svm, &fixture, FREEZE_CLAIMS_DISCRIMINATOR).is_ok());
+
+        svm.expire_blockhash();
+        let result = send_freeze_or_unfreeze(&mut svm, &fixture, FREEZE_CLAIMS_DISCRIMINATOR);
+        assert_custom_error(&result, CLAIMS_FROZEN_ERROR);
+    }
+
+    #[test]
+    fn test_freeze_claims_wrong_authority_rejected() {
+        let mut svm = setup_svm();
+        let mut fixture = setup_schedule(&mut svm, 3);
+
+        let impostor = Keypair::new();
+        svm.airdrop(&impostor.pubkey(), 10_000_000_000).unwrap();
+        fixture.authority = impostor;
+
+        let result = send_freeze_or_unfreeze(&mut svm, &fixture, FREEZE_CLAIMS_DISCRIMINATOR);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::IllegalOwner)
+        );
+    }
+}
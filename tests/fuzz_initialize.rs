@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod fuzz_initialize_tests {
+    use token_vesting::InitializeInstructionData;
+
+    // January 1, 2025 00:00:00 UTC
+    const NOW: u64 = 1735689600;
+    const ONE_DAY: u64 = 86_400;
+    const ITERATIONS: usize = 20_000;
+
+    // InitializeInstructionData without the instruction discriminator
+    const INITIALIZE_DATA_LEN: usize = 210;
+
+    /// xorshift64*, seeded so every run walks the same inputs.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 >> 12;
+            self.0 ^= self.0 << 25;
+            self.0 ^= self.0 >> 27;
+            self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+        }
+
+        fn below(&mut self, bound: u64) -> u64 {
+            self.next() % bound
+        }
+
+        /// Mostly small or boundary values, sometimes anything up to u64::MAX.
+        fn duration(&mut self) -> u64 {
+            match self.below(4) {
+                0 => [0, 1, 2, ONE_DAY - 1, ONE_DAY, ONE_DAY + 1][self.below(6) as usize],
+                1 => self.below(ONE_DAY * 100),
+                2 => ONE_DAY * self.below(30),
+                _ => u64::MAX - self.below(3),
+            }
+        }
+
+        fn tuple(&mut self) -> (u64, u64, u64, u64, u64) {
+            let seed = if self.below(10) == 0 { 0 } else { self.next() };
+
+            let start = match self.below(4) {
+                0 => NOW - 1 - self.below(ONE_DAY),
+                1 => NOW,
+                2 => NOW + self.below(ONE_DAY * 365),
+                _ => u64::MAX - self.below(ONE_DAY * 30),
+            };
+
+            let cliff = self.duration();
+            let step = self.duration();
+
+            // Half of the time build a total around cliff + n * step so the divisibility
+            // and one-step boundaries are hit often
+            let total = if self.below(2) == 0 {
+                let steps = self.below(12);
+                let jitter = [0u64, 0, 0, 1, u64::MAX][self.below(5) as usize];
+                cliff
+                    .wrapping_add(step.wrapping_mul(steps))
+                    .wrapping_add(jitter)
+            } else {
+                self.duration()
+            };
+
+            (seed, start, cliff, total, step)
+        }
+    }
+
+    fn instruction_data(seed: u64, start: u64, cliff: u64, total: u64, step: u64) -> Vec<u8> {
+        let mut data = Vec::with_capacity(INITIALIZE_DATA_LEN);
+        data.extend_from_slice(&seed.to_le_bytes());
+        data.extend_from_slice(&start.to_le_bytes());
+        data.extend_from_slice(&cliff.to_le_bytes());
+        data.extend_from_slice(&total.to_le_bytes());
+        data.extend_from_slice(&step.to_le_bytes());
+        data.push(255); // Bump
+        data.extend_from_slice(&[0u8; 32]); // Cosigner
+        data.extend_from_slice(&0u64.to_le_bytes()); // Cosign threshold
+        data.extend_from_slice(&0u64.to_le_bytes()); // Claim window
+        data.push(0); // Use vault authority
+        data.push(0); // Vault authority bump
+        data.extend_from_slice(&[0u8; 32]); // Fee recipient
+        data.extend_from_slice(&0u16.to_le_bytes()); // Claim fee bps
+        data.push(1); // Allow add after start
+        data.push(0); // Vesting mode: stepped
+        data.extend_from_slice(&0u64.to_le_bytes()); // Cliff unlock bps
+        data.extend_from_slice(&[0u8; 32]); // Revoke destination
+        data.push(0); // Pause freezes accrual
+        data.extend_from_slice(&0u64.to_le_bytes()); // Max participants: unlimited
+        data.extend_from_slice(&0u64.to_le_bytes()); // Min claim amount: none
+        data.push(0); // Allow escrow destination: no
+        data.extend_from_slice(&[0u8; 16]); // Label: none
+        data.extend_from_slice(&0u64.to_le_bytes()); // Claim cooldown: none
+        data.push(0); // Cooldown exempts participant: no
+        assert_eq!(data.len(), INITIALIZE_DATA_LEN);
+        data
+    }
+
+    /// The documented schedule rules, written independently of the program's checks.
+    fn satisfies_invariants(seed: u64, start: u64, cliff: u64, total: u64, step: u64) -> bool {
+        seed != 0
+            && start >= NOW
+            && step > 0
+            && cliff < total
+            && start.checked_add(total).is_some()
+            && step <= total - cliff
+            && (total - cliff).is_multiple_of(step)
+    }
+
+    #[test]
+    fn test_fuzz_initialize_accepts_exactly_valid_tuples() {
+        let mut rng = Rng(0x5eed_1753);
+        let mut accepted = 0usize;
+
+        for _ in 0..ITERATIONS {
+            let (seed, start, cliff, total, step) = rng.tuple();
+            let data = instruction_data(seed, start, cliff, total, step);
+
+            let result = InitializeInstructionData::parse(&data, NOW);
+            let expected = satisfies_invariants(seed, start, cliff, total, step);
+
+            assert_eq!(
+                result.is_ok(),
+                expected,
+                "seed={} start={} cliff={} total={} step={}: got {:?}",
+                seed,
+                start,
+                cliff,
+                total,
+                step,
+                result.err()
+            );
+
+            if expected {
+                accepted += 1;
+            }
+        }
+
+        // Guard against a generator that never reaches the accepting region
+        assert!(
+            accepted > ITERATIONS / 50,
+            "Only {} valid tuples generated",
+            accepted
+        );
+    }
+
+    #[test]
+    fn test_fuzz_initialize_rejects_malformed_lengths_without_panicking() {
+        let mut rng = Rng(0xbad_1e9);
+
+        for _ in 0..ITERATIONS / 10 {
+            let len = rng.below(INITIALIZE_DATA_LEN as u64 * 2) as usize;
+            if len == INITIALIZE_DATA_LEN {
+                continue;
+            }
+
+            let data: Vec<u8> = (0..len).map(|_| rng.next() as u8).collect();
+            assert!(InitializeInstructionData::parse(&data, NOW).is_err());
+        }
+    }
+}
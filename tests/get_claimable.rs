@@ -0,0 +1,154 @@
+mod common;
+
+#[cfg(test)]
+mod get_claimable_tests {
+    use crate::common::*;
+    use litesvm::LiteSVM;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    };
+
+    const GET_CLAIMABLE_DISCRIMINATOR: u8 = 18;
+
+    fn get_claimable(
+        svm: &mut LiteSVM,
+        schedule: &Pubkey,
+        participant_state: &Pubkey,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let payer = Keypair::new();
+        svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(*schedule, false),
+                AccountMeta::new_readonly(*participant_state, false),
+            ],
+            data: vec![GET_CLAIMABLE_DISCRIMINATOR],
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[&payer],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        result
+    }
+
+    fn decode_claimable(
+        result: Result<
+            litesvm::types::TransactionMetadata,
+            litesvm::types::FailedTransactionMetadata,
+        >,
+    ) -> u64 {
+        let data = result
+            .expect("GetClaimable should succeed")
+            .return_data
+            .data;
+        assert_eq!(data.len(), 8);
+        u64::from_le_bytes(data.try_into().unwrap())
+    }
+
+    #[test]
+    fn test_get_claimable_partially_vested() {
+        let mut svm = setup_svm();
+
+        let authority = Pubkey::new_unique();
+        let token_mint = create_mock_token_mint(&mut svm, &authority);
+
+        // Started 3 days ago, 2 of 9 steps unlocked
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority,
+            &token_mint,
+            1,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        // 200,000 vested, 50,000 of it already claimed
+        let participant_state =
+            create_participant_state(&mut svm, &Pubkey::new_unique(), &schedule, 900_000, 50_000);
+
+        let result = get_claimable(&mut svm, &schedule, &participant_state);
+        assert_eq!(decode_claimable(result), 150_000);
+
+        // One more step unlocks another 100,000
+        warp_to_timestamp(&mut svm, JAN_1_2025 + ONE_DAY as i64);
+        let result = get_claimable(&mut svm, &schedule, &participant_state);
+        assert_eq!(decode_claimable(result), 250_000);
+    }
+
+    #[test]
+    fn test_get_claimable_before_cliff_is_zero() {
+        let mut svm = setup_svm();
+
+        let authority = Pubkey::new_unique();
+        let token_mint = create_mock_token_mint(&mut svm, &authority);
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority,
+            &token_mint,
+            2,
+            JAN_1_2025 as u64,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+        let participant_state =
+            create_participant_state(&mut svm, &Pubkey::new_unique(), &schedule, 900_000, 0);
+
+        let result = get_claimable(&mut svm, &schedule, &participant_state);
+        assert_eq!(decode_claimable(result), 0);
+    }
+
+    #[test]
+    fn test_get_claimable_participant_of_other_schedule_rejected() {
+        let mut svm = setup_svm();
+
+        let authority = Pubkey::new_unique();
+        let token_mint = create_mock_token_mint(&mut svm, &authority);
+
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority,
+            &token_mint,
+            3,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+        let other_schedule = create_vest_schedule(
+            &mut svm,
+            &authority,
+            &token_mint,
+            4,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+        let participant_state =
+            create_participant_state(&mut svm, &Pubkey::new_unique(), &other_schedule, 900_000, 0);
+
+        let result = get_claimable(&mut svm, &schedule, &participant_state);
+        assert!(
+            result.is_err(),
+            "A participant of another schedule should be rejected"
+        );
+    }
+}
@@ -1,34 +1,46 @@
+mod common;
+
 #[cfg(test)]
 mod tests {
+    use crate::common::*;
     use litesvm::LiteSVM;
     use solana_sdk::{
         account::Account,
-        clock::Clock,
-        instruction::{AccountMeta, Instruction},
+        instruction::{AccountMeta, Instruction, InstructionError},
         pubkey::Pubkey,
         signature::{Keypair, Signer},
-        transaction::Transaction,
+        transaction::{Transaction, TransactionError},
     };
     use pinocchio_system::ID;
 
     use spl_associated_token_account::ID as ATA_PROGRAM_ID;
     use spl_token::solana_program::program_option::COption;
     use spl_token::solana_program::program_pack::Pack;
-    use spl_token::state::Mint;
+    use spl_token::state::{Account as TokenAccount, AccountState, Mint};
     use spl_token::ID as TOKEN_PROGRAM_ID;
-
-    const PROGRAM_ID: Pubkey = Pubkey::new_from_array([
-        0x0f, 0x1e, 0x6b, 0x14, 0x21, 0xc0, 0x4a, 0x07, 0x04, 0x31, 0x26, 0x5c, 0x19, 0xc5, 0xbb,
-        0xee, 0x19, 0x92, 0xba, 0xe8, 0xaf, 0xd1, 0xcd, 0x07, 0x8e, 0xf8, 0xaf, 0x70, 0x47, 0xdc,
-        0x11, 0xf7,
-    ]);
-
-    // January 1, 2025 00:00:00 UTC
-    const JAN_1_2025: i64 = 1735689600;
-    const ONE_DAY: u64 = 86_400;
-
-    // VestSchedule::LEN = discriminator(1) + 3*Pubkey(96) + 5*u64(40) + bump(1) = 138
-    const VEST_SCHEDULE_LEN: usize = 138;
+    use token_vesting::instruction_builder::{generation_pda, vest_schedule_pda};
+    use token_vesting::{VestGeneration, VestSchedule, VestScheduleSnapshot, ACCOUNT_VERSION};
+
+    // Offset of the vault authority flag in the Initialize instruction data (after the discriminator)
+    const VAULT_AUTHORITY_FLAG_OFFSET: usize = 90;
+
+    // Offset of the vesting mode byte in the Initialize instruction data (after the discriminator)
+    const VESTING_MODE_OFFSET: usize = 127;
+    // Offset of the cliff unlock bps in the Initialize instruction data (after the discriminator)
+    const CLIFF_UNLOCK_BPS_OFFSET: usize = 128;
+    // Offset of the revoke destination in the Initialize instruction data (after the discriminator)
+    const REVOKE_DESTINATION_OFFSET: usize = 136;
+    // Offset of the label in the Initialize instruction data (after the discriminator)
+    const LABEL_OFFSET: usize = 186;
+
+    // PinocchioError::InvalidDurations
+    const INVALID_DURATIONS_ERROR: u32 = 7;
+    // PinocchioError::StepExceedsVestingPeriod
+    const STEP_EXCEEDS_VESTING_PERIOD_ERROR: u32 = 16;
+    // PinocchioError::InvalidTokenProgram
+    const INVALID_TOKEN_PROGRAM_ERROR: u32 = 17;
+
+    const TOKEN_2022_PROGRAM_ID: Pubkey = Pubkey::new_from_array(token_vesting::TOKEN_2022_PROGRAM_ID);
 
     fn create_initialize_instruction_data(
         seed: u64,
@@ -45,90 +57,27 @@ mod tests {
         data.extend_from_slice(&total_duration.to_le_bytes());
         data.extend_from_slice(&step_duration.to_le_bytes());
         data.push(bump);
+        data.extend_from_slice(&[0u8; 32]); // Cosigner (unused while threshold is 0)
+        data.extend_from_slice(&0u64.to_le_bytes()); // Cosign threshold
+        data.extend_from_slice(&0u64.to_le_bytes()); // Claim window (disabled)
+        data.push(0); // Use vault authority: no
+        data.push(0); // Vault authority bump (unused)
+        data.extend_from_slice(&[0u8; 32]); // Fee recipient (unused while fee is 0)
+        data.extend_from_slice(&0u16.to_le_bytes()); // Claim fee bps
+        data.push(1); // Allow adding participants after start
+        data.push(0); // Vesting mode: stepped
+        data.extend_from_slice(&0u64.to_le_bytes()); // Cliff unlock bps
+        data.extend_from_slice(&[0u8; 32]); // Revoke destination: authority ATA
+        data.push(0); // Pause freezes accrual: no
+        data.extend_from_slice(&0u64.to_le_bytes()); // Max participants: unlimited
+        data.extend_from_slice(&0u64.to_le_bytes()); // Min claim amount: none
+        data.push(0); // Allow escrow destination: no
+        data.extend_from_slice(&[0u8; 16]); // Label: none
+        data.extend_from_slice(&0u64.to_le_bytes()); // Claim cooldown: none
+        data.push(0); // Cooldown exempts participant: no
         data
     }
 
-    // Updated: PDA now uses only seed (no token_mint or initializer)
-    fn derive_vest_schedule_pda(seed: u64) -> (Pubkey, u8) {
-        Pubkey::find_program_address(
-            &[b"vest_schedule", &seed.to_le_bytes()],
-            &PROGRAM_ID,
-        )
-    }
-
-    fn derive_ata(owner: &Pubkey, mint: &Pubkey) -> Pubkey {
-        spl_associated_token_account::get_associated_token_address(owner, mint)
-    }
-
-    fn setup_svm() -> LiteSVM {
-        let mut svm = LiteSVM::new().with_builtins().with_sigverify(false);
-
-        svm.add_program_from_file(PROGRAM_ID, "target/deploy/token_vesting.so")
-            .expect("Failed to load program");
-
-        // Warp to Jan 1, 2025
-        warp_to_timestamp(&mut svm, JAN_1_2025);
-
-        svm
-    }
-
-    fn warp_to_timestamp(svm: &mut LiteSVM, unix_timestamp: i64) {
-        let current_clock = svm.get_sysvar::<Clock>();
-        svm.set_sysvar(&Clock {
-            unix_timestamp,
-            ..current_clock
-        });
-    }
-
-    fn print_transaction_logs(
-        result: &Result<
-            litesvm::types::TransactionMetadata,
-            litesvm::types::FailedTransactionMetadata,
-        >,
-    ) {
-        match result {
-            Ok(meta) => {
-                println!("\n=== Transaction Succeeded ===");
-                for log in &meta.logs {
-                    println!("  {}", log);
-                }
-            }
-            Err(err) => {
-                println!("\n=== Transaction Failed ===");
-                println!("Error: {:?}", err.err);
-                for log in &err.meta.logs {
-                    println!("  {}", log);
-                }
-            }
-        }
-    }
-
-    fn create_mock_token_mint(svm: &mut LiteSVM, authority: &Pubkey) -> Pubkey {
-        let mint_keypair = Keypair::new();
-        let mint_pubkey = mint_keypair.pubkey();
-
-        let mint_data = Mint {
-            mint_authority: COption::Some(*authority),
-            supply: 1_000_000_000,
-            decimals: 6,
-            is_initialized: true,
-            freeze_authority: COption::None,
-        };
-
-        let mut data = vec![0u8; Mint::LEN];
-        Mint::pack(mint_data, &mut data).unwrap();
-
-        svm.set_account(mint_pubkey, Account {
-            lamports: 10_000_000,
-            data,
-            owner: TOKEN_PROGRAM_ID,
-            executable: false,
-            rent_epoch: 0,
-        }.into());
-
-        mint_pubkey
-    }
-
     fn build_initialize_instruction(
         initializer: &Pubkey,
         vest_schedule_pda: &Pubkey,
@@ -136,6 +85,9 @@ mod tests {
         vault: &Pubkey,
         instruction_data: Vec<u8>,
     ) -> Instruction {
+        // The seed follows the discriminator
+        let seed = u64::from_le_bytes(instruction_data[1..9].try_into().unwrap());
+
         Instruction {
             program_id: PROGRAM_ID,
             accounts: vec![
@@ -146,6 +98,7 @@ mod tests {
                 AccountMeta::new_readonly(ID.into(), false),
                 AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
                 AccountMeta::new_readonly(ATA_PROGRAM_ID, false),
+                AccountMeta::new(generation_pda(seed).0, false),
             ],
             data: instruction_data,
         }
@@ -166,7 +119,7 @@ mod tests {
         let total_duration = ONE_DAY * 10;
         let step_duration = ONE_DAY;
 
-        let (vest_schedule_pda, bump) = derive_vest_schedule_pda(seed);
+        let (vest_schedule_pda, bump) = vest_schedule_pda(seed);
         let vault = derive_ata(&vest_schedule_pda, &token_mint);
 
         let instruction_data = create_initialize_instruction_data(
@@ -203,7 +156,15 @@ mod tests {
 
         let account = vest_schedule_account.unwrap();
         assert_eq!(account.owner, PROGRAM_ID, "Should be owned by program");
-        assert_eq!(account.data.len(), VEST_SCHEDULE_LEN, "Should have correct data length");
+        assert_eq!(account.data.len(), VestScheduleSnapshot::LEN, "Should have correct data length");
+
+        let schedule = VestSchedule::from_bytes(&account.data).unwrap();
+        assert_eq!(schedule.version, ACCOUNT_VERSION, "Should carry the current layout version");
+        assert_eq!(
+            schedule.token_program,
+            TOKEN_PROGRAM_ID.to_bytes(),
+            "Should record the token program it was created with"
+        );
     }
 
     #[test]
@@ -221,7 +182,7 @@ mod tests {
         let total_duration = ONE_DAY * 10;
         let step_duration = ONE_DAY;
 
-        let (vest_schedule_pda, bump) = derive_vest_schedule_pda(seed);
+        let (vest_schedule_pda, bump) = vest_schedule_pda(seed);
         let vault = derive_ata(&vest_schedule_pda, &token_mint);
 
         let instruction_data = create_initialize_instruction_data(
@@ -268,7 +229,7 @@ mod tests {
         let total_duration = ONE_DAY;      // 1 day total (less than cliff!)
         let step_duration = ONE_DAY;
 
-        let (vest_schedule_pda, bump) = derive_vest_schedule_pda(seed);
+        let (vest_schedule_pda, bump) = vest_schedule_pda(seed);
         let vault = derive_ata(&vest_schedule_pda, &token_mint);
 
         let instruction_data = create_initialize_instruction_data(
@@ -315,7 +276,7 @@ mod tests {
         let total_duration = ONE_DAY * 10;
         let step_duration = ONE_DAY * 20; // Step > total
 
-        let (vest_schedule_pda, bump) = derive_vest_schedule_pda(seed);
+        let (vest_schedule_pda, bump) = vest_schedule_pda(seed);
         let vault = derive_ata(&vest_schedule_pda, &token_mint);
 
         let instruction_data = create_initialize_instruction_data(
@@ -362,7 +323,7 @@ mod tests {
         let total_duration = ONE_DAY * 10;
         let step_duration = 77777; // Doesn't divide evenly into (total - cliff)
 
-        let (vest_schedule_pda, bump) = derive_vest_schedule_pda(seed);
+        let (vest_schedule_pda, bump) = vest_schedule_pda(seed);
         let vault = derive_ata(&vest_schedule_pda, &token_mint);
 
         let instruction_data = create_initialize_instruction_data(
@@ -409,7 +370,7 @@ mod tests {
         let total_duration = ONE_DAY * 10;
         let step_duration = ONE_DAY;
 
-        let (vest_schedule_pda, bump) = derive_vest_schedule_pda(seed);
+        let (vest_schedule_pda, bump) = vest_schedule_pda(seed);
         let vault = derive_ata(&vest_schedule_pda, &token_mint);
 
         let instruction_data = create_initialize_instruction_data(
@@ -438,7 +399,9 @@ mod tests {
 
         let result = svm.send_transaction(transaction);
         print_transaction_logs(&result);
-        assert!(result.is_err(), "Should fail with zero cliff duration");
+        assert!(result.is_ok(), "Zero cliff should be accepted");
+
+        assert_eq!(read_vest_schedule(&svm, &vest_schedule_pda).cliff_duration, 0);
     }
 
     #[test]
@@ -456,7 +419,7 @@ mod tests {
         let total_duration = ONE_DAY * 10;
         let step_duration = 0; // Zero step
 
-        let (vest_schedule_pda, bump) = derive_vest_schedule_pda(seed);
+        let (vest_schedule_pda, bump) = vest_schedule_pda(seed);
         let vault = derive_ata(&vest_schedule_pda, &token_mint);
 
         let instruction_data = create_initialize_instruction_data(
@@ -503,7 +466,7 @@ mod tests {
         let total_duration = ONE_DAY * 10;
         let step_duration = ONE_DAY;
 
-        let (vest_schedule_pda, bump) = derive_vest_schedule_pda(seed);
+        let (vest_schedule_pda, bump) = vest_schedule_pda(seed);
         let vault = derive_ata(&vest_schedule_pda, &token_mint);
 
         let instruction_data = create_initialize_instruction_data(
@@ -547,7 +510,7 @@ mod tests {
         let seed = 12345u64;
         let start_timestamp = (JAN_1_2025 + ONE_DAY as i64) as u64;
 
-        let (vest_schedule_pda, correct_bump) = derive_vest_schedule_pda(seed);
+        let (vest_schedule_pda, correct_bump) = vest_schedule_pda(seed);
         let wrong_bump = correct_bump.wrapping_add(1);
         let vault = derive_ata(&vest_schedule_pda, &token_mint);
 
@@ -592,7 +555,7 @@ mod tests {
         let seed = 12345u64;
         let start_timestamp = (JAN_1_2025 + ONE_DAY as i64) as u64;
 
-        let (vest_schedule_pda, bump) = derive_vest_schedule_pda(seed);
+        let (vest_schedule_pda, bump) = vest_schedule_pda(seed);
         let vault = derive_ata(&vest_schedule_pda, &token_mint);
 
         let instruction_data = create_initialize_instruction_data(
@@ -650,7 +613,7 @@ mod tests {
         let seeds = [12345u64, 67890u64];
 
         for seed in seeds.iter() {
-            let (vest_schedule_pda, bump) = derive_vest_schedule_pda(*seed);
+            let (vest_schedule_pda, bump) = vest_schedule_pda(*seed);
             let vault = derive_ata(&vest_schedule_pda, &token_mint);
 
             let instruction_data = create_initialize_instruction_data(
@@ -738,7 +701,7 @@ mod tests {
         let seed = 12345u64;
         let start_timestamp = (JAN_1_2025 + ONE_DAY as i64) as u64;
 
-        let (vest_schedule_pda, bump) = derive_vest_schedule_pda(seed);
+        let (vest_schedule_pda, bump) = vest_schedule_pda(seed);
         let vault = derive_ata(&vest_schedule_pda, &token_mint);
 
         let instruction_data = create_initialize_instruction_data(
@@ -761,6 +724,7 @@ mod tests {
                 AccountMeta::new_readonly(ID.into(), false),
                 AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
                 AccountMeta::new_readonly(ATA_PROGRAM_ID, false),
+                AccountMeta::new(generation_pda(seed).0, false),
             ],
             data: instruction_data,
         };
@@ -792,7 +756,7 @@ mod tests {
         let total_duration = ONE_DAY * 10; // Equal to cliff
         let step_duration = ONE_DAY;
 
-        let (vest_schedule_pda, bump) = derive_vest_schedule_pda(seed);
+        let (vest_schedule_pda, bump) = vest_schedule_pda(seed);
         let vault = derive_ata(&vest_schedule_pda, &token_mint);
 
         let instruction_data = create_initialize_instruction_data(
@@ -823,4 +787,1231 @@ mod tests {
         print_transaction_logs(&result);
         assert!(result.is_err(), "Should fail when cliff equals total duration");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_initialize_reinitialize_rejected_by_discriminator() {
+        let mut svm = setup_svm();
+        let initializer = Keypair::new();
+
+        svm.airdrop(&initializer.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &initializer.pubkey());
+
+        let seed = 12345u64;
+        let start_timestamp = (JAN_1_2025 + ONE_DAY as i64) as u64;
+
+        let (vest_schedule_pda, bump) = vest_schedule_pda(seed);
+        let vault = derive_ata(&vest_schedule_pda, &token_mint);
+
+        let instruction_data = create_initialize_instruction_data(
+            seed,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+            bump,
+        );
+
+        let instruction = build_initialize_instruction(
+            &initializer.pubkey(),
+            &vest_schedule_pda,
+            &token_mint,
+            &vault,
+            instruction_data,
+        );
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction.clone()],
+            Some(&initializer.pubkey()),
+            &[&initializer],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "First initialization should succeed");
+
+        assert_eq!(
+            read_vest_schedule(&svm, &vest_schedule_pda).discriminator,
+            VestSchedule::DISCRIMINATOR,
+            "Schedule discriminator should be set"
+        );
+
+        // Same PDA again, the vault already exists so only the schedule guard can reject it
+        svm.expire_blockhash();
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&initializer.pubkey()),
+            &[&initializer],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::AccountAlreadyInitialized),
+            "Re-initialization should be rejected as already initialized"
+        );
+    }
+
+    #[test]
+    fn test_initialize_with_vault_authority() {
+        let mut svm = setup_svm();
+        let initializer = Keypair::new();
+
+        svm.airdrop(&initializer.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &initializer.pubkey());
+
+        let seed = 4242u64;
+        let start_timestamp = (JAN_1_2025 + ONE_DAY as i64) as u64;
+
+        let (vest_schedule_pda, bump) = vest_schedule_pda(seed);
+        let (vault_authority, vault_authority_bump) = Pubkey::find_program_address(
+            &[b"vault", vest_schedule_pda.as_ref()],
+            &PROGRAM_ID,
+        );
+        let vault = derive_ata(&vault_authority, &token_mint);
+
+        let mut instruction_data = create_initialize_instruction_data(
+            seed,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+            bump,
+        );
+        instruction_data[VAULT_AUTHORITY_FLAG_OFFSET] = 1; // Use vault authority
+        instruction_data[VAULT_AUTHORITY_FLAG_OFFSET + 1] = vault_authority_bump;
+
+        let mut instruction = build_initialize_instruction(
+            &initializer.pubkey(),
+            &vest_schedule_pda,
+            &token_mint,
+            &vault,
+            instruction_data,
+        );
+        instruction
+            .accounts
+            .push(AccountMeta::new_readonly(vault_authority, false));
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&initializer.pubkey()),
+            &[&initializer],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "Transaction should succeed");
+
+        let vault_account = svm.get_account(&vault).expect("Vault should exist");
+        let vault_state = spl_token::state::Account::unpack(&vault_account.data).unwrap();
+        assert_eq!(vault_state.owner, vault_authority, "Vault should be owned by the vault authority");
+
+        let schedule = read_vest_schedule(&svm, &vest_schedule_pda);
+        assert_eq!(schedule.vault_authority, vault_authority.to_bytes());
+        assert_eq!(schedule.vault_authority_bump, vault_authority_bump);
+    }
+
+    #[test]
+    fn test_initialize_vault_authority_missing_account() {
+        let mut svm = setup_svm();
+        let initializer = Keypair::new();
+
+        svm.airdrop(&initializer.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &initializer.pubkey());
+
+        let seed = 4243u64;
+        let start_timestamp = (JAN_1_2025 + ONE_DAY as i64) as u64;
+
+        let (vest_schedule_pda, bump) = vest_schedule_pda(seed);
+        let (vault_authority, vault_authority_bump) = Pubkey::find_program_address(
+            &[b"vault", vest_schedule_pda.as_ref()],
+            &PROGRAM_ID,
+        );
+        let vault = derive_ata(&vault_authority, &token_mint);
+
+        let mut instruction_data = create_initialize_instruction_data(
+            seed,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+            bump,
+        );
+        instruction_data[VAULT_AUTHORITY_FLAG_OFFSET] = 1;
+        instruction_data[VAULT_AUTHORITY_FLAG_OFFSET + 1] = vault_authority_bump;
+
+        let instruction = build_initialize_instruction(
+            &initializer.pubkey(),
+            &vest_schedule_pda,
+            &token_mint,
+            &vault,
+            instruction_data,
+        );
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&initializer.pubkey()),
+            &[&initializer],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(transaction);
+        assert!(result.is_err(), "Should fail without the vault authority account");
+    }
+
+    fn send_initialize_with_durations(
+        svm: &mut LiteSVM,
+        seed: u64,
+        cliff_duration: u64,
+        total_duration: u64,
+        step_duration: u64,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let initializer = Keypair::new();
+        svm.airdrop(&initializer.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(svm, &initializer.pubkey());
+        let start_timestamp = (JAN_1_2025 + ONE_DAY as i64) as u64;
+
+        let (vest_schedule_pda, bump) = vest_schedule_pda(seed);
+        let vault = derive_ata(&vest_schedule_pda, &token_mint);
+
+        let instruction = build_initialize_instruction(
+            &initializer.pubkey(),
+            &vest_schedule_pda,
+            &token_mint,
+            &vault,
+            create_initialize_instruction_data(
+                seed,
+                start_timestamp,
+                cliff_duration,
+                total_duration,
+                step_duration,
+                bump,
+            ),
+        );
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&initializer.pubkey()),
+            &[&initializer],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        result
+    }
+
+    #[test]
+    fn test_initialize_single_step_after_cliff() {
+        let mut svm = setup_svm();
+
+        // step == total - cliff: everything unlocks in one step
+        let result =
+            send_initialize_with_durations(&mut svm, 5150, ONE_DAY, ONE_DAY * 10, ONE_DAY * 9);
+        assert!(result.is_ok(), "A single step covering the whole period should be allowed");
+    }
+
+    #[test]
+    fn test_initialize_step_exceeds_period_after_cliff() {
+        let mut svm = setup_svm();
+
+        // step == total - cliff + 1: still shorter than total, but longer than the vesting period
+        let result =
+            send_initialize_with_durations(&mut svm, 5151, ONE_DAY, ONE_DAY * 10, ONE_DAY * 9 + 1);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::Custom(STEP_EXCEEDS_VESTING_PERIOD_ERROR)),
+            "Step longer than total - cliff should be rejected explicitly"
+        );
+    }
+
+    #[test]
+    fn test_initialize_single_step_without_cliff() {
+        let mut svm = setup_svm();
+
+        // With no cliff the vesting period is the whole duration, so step == total is one step
+        let result = send_initialize_with_durations(&mut svm, 5168, 0, ONE_DAY * 10, ONE_DAY * 10);
+        assert!(
+            result.is_ok(),
+            "A single step spanning the whole schedule should be allowed"
+        );
+
+        let result =
+            send_initialize_with_durations(&mut svm, 5169, 0, ONE_DAY * 10, ONE_DAY * 10 + 1);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(STEP_EXCEEDS_VESTING_PERIOD_ERROR)
+            ),
+            "A step longer than the whole schedule leaves no step to unlock"
+        );
+    }
+
+    #[test]
+    fn test_initialize_linear_step_exceeds_period_rejected() {
+        let mut svm = setup_svm();
+
+        // Linear schedules ignore the step, but it still has to fit in the 9 day vesting period
+        let (result, _) = send_initialize_with_vesting_mode(&mut svm, 5172, ONE_DAY * 9 + 1, 1);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(STEP_EXCEEDS_VESTING_PERIOD_ERROR)
+            ),
+        );
+    }
+
+    #[test]
+    fn test_initialize_start_near_u64_max_rejected() {
+        let mut svm = setup_svm();
+        let initializer = Keypair::new();
+        svm.airdrop(&initializer.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &initializer.pubkey());
+        let seed = 5152u64;
+        let (vest_schedule_pda, bump) = vest_schedule_pda(seed);
+        let vault = derive_ata(&vest_schedule_pda, &token_mint);
+
+        // In the future, but start + total doesn't fit in a u64
+        let instruction = build_initialize_instruction(
+            &initializer.pubkey(),
+            &vest_schedule_pda,
+            &token_mint,
+            &vault,
+            create_initialize_instruction_data(
+                seed,
+                u64::MAX - ONE_DAY,
+                ONE_DAY,
+                ONE_DAY * 10,
+                ONE_DAY,
+                bump,
+            ),
+        );
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&initializer.pubkey()),
+            &[&initializer],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::Custom(INVALID_DURATIONS_ERROR))
+        );
+        assert!(svm.get_account(&vest_schedule_pda).is_none());
+    }
+
+    fn build_add_participant_instruction(
+        authority: &Pubkey,
+        authority_ata: &Pubkey,
+        vault: &Pubkey,
+        participant: &Pubkey,
+        schedule: &Pubkey,
+        token_mint: &Pubkey,
+        allocated_amount: u64,
+    ) -> Instruction {
+        let (participant_state, participant_bump) = Pubkey::find_program_address(
+            &[b"vest_participant", participant.as_ref(), schedule.as_ref()],
+            &PROGRAM_ID,
+        );
+
+        let mut data = vec![1u8]; // Discriminator for AddParticipant
+        data.extend_from_slice(&allocated_amount.to_le_bytes());
+        data.push(participant_bump);
+
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(*authority, true),
+                AccountMeta::new(*authority_ata, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new_readonly(*participant, false),
+                AccountMeta::new(participant_state, false),
+                AccountMeta::new(*schedule, false),
+                AccountMeta::new_readonly(*token_mint, false),
+                AccountMeta::new_readonly(ID.into(), false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            ],
+            data,
+        }
+    }
+
+    #[test]
+    fn test_initialize_discriminator_round_trips() {
+        let mut svm = setup_svm();
+        let initializer = Keypair::new();
+        let participant = Keypair::new();
+
+        svm.airdrop(&initializer.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &initializer.pubkey());
+
+        let seed = 777u64;
+        let start_timestamp = (JAN_1_2025 + ONE_DAY as i64) as u64;
+
+        let (vest_schedule_pda, bump) = vest_schedule_pda(seed);
+        let vault = derive_ata(&vest_schedule_pda, &token_mint);
+
+        let instruction = build_initialize_instruction(
+            &initializer.pubkey(),
+            &vest_schedule_pda,
+            &token_mint,
+            &vault,
+            create_initialize_instruction_data(
+                seed,
+                start_timestamp,
+                ONE_DAY,
+                ONE_DAY * 10,
+                ONE_DAY,
+                bump,
+            ),
+        );
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&initializer.pubkey()),
+            &[&initializer],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "Initialization should succeed");
+
+        assert_eq!(
+            read_vest_schedule(&svm, &vest_schedule_pda).discriminator,
+            VestSchedule::DISCRIMINATOR,
+            "Initialize should stamp the VestSchedule discriminator"
+        );
+
+        // AddParticipant runs ProgramAccount::check::<VestSchedule> on the fresh schedule
+        let authority_ata =
+            create_ata_with_balance(&mut svm, &initializer.pubkey(), &token_mint, 1_000_000);
+
+        let instruction = build_add_participant_instruction(
+            &initializer.pubkey(),
+            &authority_ata,
+            &vault,
+            &participant.pubkey(),
+            &vest_schedule_pda,
+            &token_mint,
+            100_000,
+        );
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&initializer.pubkey()),
+            &[&initializer],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "Schedule written by Initialize should pass the discriminator check");
+    }
+
+    #[test]
+    fn test_initialize_rejects_mint_and_initializer_seeded_pda() {
+        let mut svm = setup_svm();
+        let initializer = Keypair::new();
+
+        svm.airdrop(&initializer.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &initializer.pubkey());
+
+        let seed = 888u64;
+        let start_timestamp = (JAN_1_2025 + ONE_DAY as i64) as u64;
+
+        // The old derivation also mixed in the mint and initializer
+        let (legacy_pda, legacy_bump) = Pubkey::find_program_address(
+            &[
+                b"vest_schedule",
+                &seed.to_le_bytes(),
+                token_mint.as_ref(),
+                initializer.pubkey().as_ref(),
+            ],
+            &PROGRAM_ID,
+        );
+        let vault = derive_ata(&legacy_pda, &token_mint);
+
+        let instruction = build_initialize_instruction(
+            &initializer.pubkey(),
+            &legacy_pda,
+            &token_mint,
+            &vault,
+            create_initialize_instruction_data(
+                seed,
+                start_timestamp,
+                ONE_DAY,
+                ONE_DAY * 10,
+                ONE_DAY,
+                legacy_bump,
+            ),
+        );
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&initializer.pubkey()),
+            &[&initializer],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::InvalidAccountData),
+            "Only [\"vest_schedule\", seed] should derive the schedule PDA"
+        );
+    }
+
+    fn send_initialize_with_vesting_mode(
+        svm: &mut LiteSVM,
+        seed: u64,
+        step_duration: u64,
+        vesting_mode: u8,
+    ) -> (
+        Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>,
+        Pubkey,
+    ) {
+        let initializer = Keypair::new();
+        svm.airdrop(&initializer.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(svm, &initializer.pubkey());
+        let start_timestamp = (JAN_1_2025 + ONE_DAY as i64) as u64;
+
+        let (vest_schedule_pda, bump) = vest_schedule_pda(seed);
+        let vault = derive_ata(&vest_schedule_pda, &token_mint);
+
+        let mut instruction_data = create_initialize_instruction_data(
+            seed,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            step_duration,
+            bump,
+        );
+        instruction_data[VESTING_MODE_OFFSET] = vesting_mode;
+
+        let instruction = build_initialize_instruction(
+            &initializer.pubkey(),
+            &vest_schedule_pda,
+            &token_mint,
+            &vault,
+            instruction_data,
+        );
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&initializer.pubkey()),
+            &[&initializer],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        (result, vest_schedule_pda)
+    }
+
+    #[test]
+    fn test_initialize_linear_mode_skips_step_divisibility() {
+        let mut svm = setup_svm();
+
+        // 7 seconds does not divide the 9 day vesting period
+        let (result, vest_schedule_pda) = send_initialize_with_vesting_mode(&mut svm, 5160, 7, 1);
+        assert!(result.is_ok(), "Linear schedules should not need a dividing step");
+
+        let schedule = read_vest_schedule(&svm, &vest_schedule_pda);
+        assert_eq!(schedule.vesting_mode, 1, "Vesting mode should be linear");
+
+        // The same durations are still rejected for a stepped schedule
+        let (result, _) = send_initialize_with_vesting_mode(&mut svm, 5161, 7, 0);
+        assert!(result.is_err(), "Stepped schedules still need a dividing step");
+    }
+
+    #[test]
+    fn test_initialize_unknown_vesting_mode_rejected() {
+        let mut svm = setup_svm();
+
+        let (result, _) = send_initialize_with_vesting_mode(&mut svm, 5162, ONE_DAY, 3);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::InvalidInstructionData)
+        );
+    }
+
+    #[test]
+    fn test_initialize_backloaded_mode() {
+        let mut svm = setup_svm();
+
+        let (result, vest_schedule_pda) =
+            send_initialize_with_vesting_mode(&mut svm, 5163, ONE_DAY, 2);
+        assert!(result.is_ok(), "Backloaded schedules should be accepted");
+
+        let schedule = read_vest_schedule(&svm, &vest_schedule_pda);
+        assert_eq!(schedule.vesting_mode, 2, "Vesting mode should be backloaded");
+
+        // Backloaded schedules unlock in steps, so the step must still divide the period
+        let (result, _) = send_initialize_with_vesting_mode(&mut svm, 5164, 7, 2);
+        assert!(result.is_err(), "Backloaded schedules still need a dividing step");
+    }
+
+    #[test]
+    fn test_initialize_cliff_only_mode() {
+        let mut svm = setup_svm();
+
+        // The 10 day total and the non-dividing 7 second step are ignored
+        let (result, vest_schedule_pda) = send_initialize_with_vesting_mode(&mut svm, 5173, 7, 3);
+        assert!(result.is_ok(), "Cliff-only schedules should be accepted");
+
+        let schedule = read_vest_schedule(&svm, &vest_schedule_pda);
+        assert_eq!(schedule.vesting_mode, 3, "Vesting mode should be cliff-only");
+
+        // The schedule ends with the one day cliff
+        assert_eq!(schedule.total_duration, ONE_DAY);
+        assert_eq!(schedule.step_duration, ONE_DAY);
+    }
+
+    fn send_initialize_with_cliff_unlock(
+        svm: &mut LiteSVM,
+        seed: u64,
+        cliff_unlock_bps: u64,
+    ) -> (
+        Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>,
+        Pubkey,
+    ) {
+        let initializer = Keypair::new();
+        svm.airdrop(&initializer.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(svm, &initializer.pubkey());
+        let start_timestamp = (JAN_1_2025 + ONE_DAY as i64) as u64;
+
+        let (vest_schedule_pda, bump) = vest_schedule_pda(seed);
+        let vault = derive_ata(&vest_schedule_pda, &token_mint);
+
+        let mut instruction_data = create_initialize_instruction_data(
+            seed,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+            bump,
+        );
+        instruction_data[CLIFF_UNLOCK_BPS_OFFSET..CLIFF_UNLOCK_BPS_OFFSET + 8]
+            .copy_from_slice(&cliff_unlock_bps.to_le_bytes());
+
+        let instruction = build_initialize_instruction(
+            &initializer.pubkey(),
+            &vest_schedule_pda,
+            &token_mint,
+            &vault,
+            instruction_data,
+        );
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&initializer.pubkey()),
+            &[&initializer],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        (result, vest_schedule_pda)
+    }
+
+    #[test]
+    fn test_initialize_cliff_unlock_bps_bounds() {
+        let mut svm = setup_svm();
+
+        let (result, vest_schedule_pda) = send_initialize_with_cliff_unlock(&mut svm, 5170, 10_000);
+        assert!(result.is_ok(), "Unlocking everything at the cliff is allowed");
+
+        assert_eq!(read_vest_schedule(&svm, &vest_schedule_pda).cliff_unlock_bps, 10_000);
+
+        let (result, _) = send_initialize_with_cliff_unlock(&mut svm, 5171, 10_001);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::InvalidInstructionData)
+        );
+    }
+
+    #[test]
+    fn test_initialize_stores_revoke_destination() {
+        let mut svm = setup_svm();
+
+        let initializer = Keypair::new();
+        svm.airdrop(&initializer.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &initializer.pubkey());
+        let start_timestamp = (JAN_1_2025 + ONE_DAY as i64) as u64;
+
+        let (vest_schedule_pda, bump) = vest_schedule_pda(5180);
+        let vault = derive_ata(&vest_schedule_pda, &token_mint);
+        let treasury = Pubkey::new_unique();
+
+        let mut instruction_data = create_initialize_instruction_data(
+            5180,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+            bump,
+        );
+        instruction_data[REVOKE_DESTINATION_OFFSET..REVOKE_DESTINATION_OFFSET + 32]
+            .copy_from_slice(treasury.as_ref());
+
+        let instruction = build_initialize_instruction(
+            &initializer.pubkey(),
+            &vest_schedule_pda,
+            &token_mint,
+            &vault,
+            instruction_data,
+        );
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&initializer.pubkey()),
+            &[&initializer],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "Initialize with a revoke destination should succeed");
+
+        assert_eq!(
+            read_vest_schedule(&svm, &vest_schedule_pda).revoke_destination,
+            treasury.to_bytes()
+        );
+    }
+
+    fn read_generation(svm: &LiteSVM, seed: u64) -> u32 {
+        let marker = svm.get_account(&generation_pda(seed).0).unwrap();
+        VestGeneration::load(&marker.data).unwrap().generation()
+    }
+
+    #[test]
+    fn test_initialize_reused_seed_increments_generation() {
+        let mut svm = setup_svm();
+
+        let initializer = Keypair::new();
+        svm.airdrop(&initializer.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &initializer.pubkey());
+        let start_timestamp = (JAN_1_2025 + ONE_DAY as i64) as u64;
+
+        let (vest_schedule_pda, bump) = vest_schedule_pda(5190);
+        let vault = derive_ata(&vest_schedule_pda, &token_mint);
+
+        let send_initialize = |svm: &mut LiteSVM| {
+            let instruction = build_initialize_instruction(
+                &initializer.pubkey(),
+                &vest_schedule_pda,
+                &token_mint,
+                &vault,
+                create_initialize_instruction_data(
+                    5190,
+                    start_timestamp,
+                    ONE_DAY,
+                    ONE_DAY * 10,
+                    ONE_DAY,
+                    bump,
+                ),
+            );
+            let transaction = Transaction::new_signed_with_payer(
+                &[instruction],
+                Some(&initializer.pubkey()),
+                &[&initializer],
+                svm.latest_blockhash(),
+            );
+            let result = svm.send_transaction(transaction);
+            print_transaction_logs(&result);
+            result
+        };
+
+        assert!(send_initialize(&mut svm).is_ok());
+        assert_eq!(read_generation(&svm, 5190), 1);
+        assert_eq!(read_vest_schedule(&svm, &vest_schedule_pda).generation, 1);
+
+        // CloseSchedule: the vault is still empty
+        let close = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(initializer.pubkey(), true),
+                AccountMeta::new(vest_schedule_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            ],
+            data: vec![10],
+        };
+        let transaction = Transaction::new_signed_with_payer(
+            &[close],
+            Some(&initializer.pubkey()),
+            &[&initializer],
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "Closing the empty schedule should succeed");
+
+        // The marker survives the close and counts the next schedule with this seed
+        svm.expire_blockhash();
+        assert!(send_initialize(&mut svm).is_ok());
+        assert_eq!(read_generation(&svm, 5190), 2);
+
+        // The schedule carries its generation, so it can be told apart without the marker
+        assert_eq!(read_vest_schedule(&svm, &vest_schedule_pda).generation, 2);
+    }
+
+    fn derive_ata_with_program(owner: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+        spl_associated_token_account::get_associated_token_address_with_program_id(
+            owner,
+            mint,
+            token_program,
+        )
+    }
+
+    // A Token-2022 mint carrying the MintCloseAuthority extension, so it is longer than the
+    // base mint layout
+    fn create_token_2022_mint_with_extension(svm: &mut LiteSVM, authority: &Pubkey) -> Pubkey {
+        let mint_pubkey = Keypair::new().pubkey();
+
+        let mint_data = Mint {
+            mint_authority: COption::Some(*authority),
+            supply: 1_000_000_000,
+            decimals: 6,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        };
+
+        let mut data = vec![0u8; Mint::LEN];
+        Mint::pack(mint_data, &mut data).unwrap();
+        data.resize(TokenAccount::LEN, 0); // Padding up to the token account length
+        data.push(1); // AccountType::Mint
+        data.extend_from_slice(&3u16.to_le_bytes()); // ExtensionType::MintCloseAuthority
+        data.extend_from_slice(&32u16.to_le_bytes()); // Extension length
+        data.extend_from_slice(authority.as_ref()); // Close authority
+
+        svm.set_account(mint_pubkey, Account {
+            lamports: 10_000_000,
+            data,
+            owner: TOKEN_2022_PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        }.into()).unwrap();
+
+        mint_pubkey
+    }
+
+    fn create_token_2022_ata_with_balance(
+        svm: &mut LiteSVM,
+        owner: &Pubkey,
+        mint: &Pubkey,
+        amount: u64,
+    ) -> Pubkey {
+        let ata = derive_ata_with_program(owner, mint, &TOKEN_2022_PROGRAM_ID);
+
+        let token_account = TokenAccount {
+            mint: *mint,
+            owner: *owner,
+            amount,
+            delegate: COption::None,
+            state: AccountState::Initialized,
+            is_native: COption::None,
+            delegated_amount: 0,
+            close_authority: COption::None,
+        };
+
+        let mut data = vec![0u8; TokenAccount::LEN];
+        TokenAccount::pack(token_account, &mut data).unwrap();
+
+        svm.set_account(ata, Account {
+            lamports: 10_000_000,
+            data,
+            owner: TOKEN_2022_PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        }.into()).unwrap();
+
+        ata
+    }
+
+    fn read_token_amount(svm: &LiteSVM, token_account: &Pubkey) -> u64 {
+        let account = svm.get_account(token_account).unwrap();
+        TokenAccount::unpack(&account.data[..TokenAccount::LEN]).unwrap().amount
+    }
+
+    #[test]
+    fn test_initialize_add_claim_share_schedule_owned_vault() {
+        let mut svm = setup_svm();
+
+        let initializer = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&initializer.pubkey(), 10_000_000_000).unwrap();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &initializer.pubkey());
+
+        let seed = 5210u64;
+        let start_timestamp = (JAN_1_2025 + ONE_DAY as i64) as u64;
+        let (vest_schedule_pda, bump) = vest_schedule_pda(seed);
+        let vault = derive_ata(&vest_schedule_pda, &token_mint);
+
+        let instruction = build_initialize_instruction(
+            &initializer.pubkey(),
+            &vest_schedule_pda,
+            &token_mint,
+            &vault,
+            create_initialize_instruction_data(seed, start_timestamp, ONE_DAY, ONE_DAY * 10, ONE_DAY, bump),
+        );
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&initializer.pubkey()),
+            &[&initializer],
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "Initialize should succeed");
+
+        // Initialize creates the vault as the schedule's ATA
+        let vault_account = svm.get_account(&vault).unwrap();
+        assert_eq!(TokenAccount::unpack(&vault_account.data).unwrap().owner, vest_schedule_pda);
+
+        let authority_ata =
+            create_ata_with_balance(&mut svm, &initializer.pubkey(), &token_mint, 1_000_000);
+        let instruction = build_add_participant_instruction(
+            &initializer.pubkey(),
+            &authority_ata,
+            &vault,
+            &participant.pubkey(),
+            &vest_schedule_pda,
+            &token_mint,
+            900_000,
+        );
+        let participant_state = instruction.accounts[4].pubkey;
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&initializer.pubkey()),
+            &[&initializer],
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "AddParticipant should deposit into the schedule's vault");
+        assert_eq!(read_token_amount(&svm, &vault), 900_000);
+        assert!(
+            svm.get_account(&derive_ata(&participant_state, &token_mint)).is_none(),
+            "No vault owned by the participant state should exist"
+        );
+
+        // Claim after 2 of 9 steps draws from the same vault
+        warp_to_timestamp(&mut svm, JAN_1_2025 + (ONE_DAY * 4) as i64);
+
+        let participant_ata = derive_ata(&participant.pubkey(), &token_mint);
+        let config = Pubkey::find_program_address(&[b"config"], &PROGRAM_ID).0;
+
+        let claim = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(participant.pubkey(), true),
+                AccountMeta::new(participant_state, false),
+                AccountMeta::new(participant_ata, false),
+                AccountMeta::new(vest_schedule_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(token_mint, false),
+                AccountMeta::new_readonly(ID.into(), false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ATA_PROGRAM_ID, false),
+                AccountMeta::new_readonly(config, false),
+            ],
+            data: vec![2], // Discriminator for Claim
+        };
+        let transaction = Transaction::new_signed_with_payer(
+            &[claim],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "Claim should draw from the schedule's vault");
+
+        assert_eq!(read_token_amount(&svm, &participant_ata), 200_000);
+        assert_eq!(read_token_amount(&svm, &vault), 700_000);
+    }
+
+    #[test]
+    fn test_token_2022_extension_mint_initialize_add_claim_flow() {
+        let mut svm = setup_svm();
+
+        let initializer = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&initializer.pubkey(), 10_000_000_000).unwrap();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_token_2022_mint_with_extension(&mut svm, &initializer.pubkey());
+
+        let seed = 5200u64;
+        let start_timestamp = (JAN_1_2025 + ONE_DAY as i64) as u64;
+        let (vest_schedule_pda, bump) = vest_schedule_pda(seed);
+        let vault = derive_ata_with_program(&vest_schedule_pda, &token_mint, &TOKEN_2022_PROGRAM_ID);
+
+        // Initialize with Token-2022: the vault is created by the Token-2022 program
+        let mut instruction = build_initialize_instruction(
+            &initializer.pubkey(),
+            &vest_schedule_pda,
+            &token_mint,
+            &vault,
+            create_initialize_instruction_data(seed, start_timestamp, ONE_DAY, ONE_DAY * 10, ONE_DAY, bump),
+        );
+        instruction.accounts[5] = AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false);
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&initializer.pubkey()),
+            &[&initializer],
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "Initialize with a Token-2022 extension mint should succeed");
+
+        let schedule = read_vest_schedule(&svm, &vest_schedule_pda);
+        assert_eq!(schedule.token_program, TOKEN_2022_PROGRAM_ID.to_bytes());
+        assert_eq!(svm.get_account(&vault).unwrap().owner, TOKEN_2022_PROGRAM_ID);
+
+        // AddParticipant through the recorded token program
+        let authority_ata =
+            create_token_2022_ata_with_balance(&mut svm, &initializer.pubkey(), &token_mint, 1_000_000);
+
+        let mut instruction = build_add_participant_instruction(
+            &initializer.pubkey(),
+            &authority_ata,
+            &vault,
+            &participant.pubkey(),
+            &vest_schedule_pda,
+            &token_mint,
+            900_000,
+        );
+        instruction.accounts[8] = AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false);
+        let participant_state = instruction.accounts[4].pubkey;
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&initializer.pubkey()),
+            &[&initializer],
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "AddParticipant with Token-2022 should succeed");
+        assert_eq!(read_token_amount(&svm, &vault), 900_000);
+
+        // Claim after 2 of 9 steps; the participant's Token-2022 ATA is created on the way
+        warp_to_timestamp(&mut svm, JAN_1_2025 + (ONE_DAY * 4) as i64);
+
+        let participant_ata =
+            derive_ata_with_program(&participant.pubkey(), &token_mint, &TOKEN_2022_PROGRAM_ID);
+        let config = Pubkey::find_program_address(&[b"config"], &PROGRAM_ID).0;
+
+        let claim = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(participant.pubkey(), true),
+                AccountMeta::new(participant_state, false),
+                AccountMeta::new(participant_ata, false),
+                AccountMeta::new(vest_schedule_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(token_mint, false),
+                AccountMeta::new_readonly(ID.into(), false),
+                AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ATA_PROGRAM_ID, false),
+                AccountMeta::new_readonly(config, false),
+            ],
+            data: vec![2], // Discriminator for Claim
+        };
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[claim],
+            Some(&participant.pubkey()),
+            &[&participant],
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "Claim with Token-2022 should succeed");
+
+        assert_eq!(svm.get_account(&participant_ata).unwrap().owner, TOKEN_2022_PROGRAM_ID);
+        assert_eq!(read_token_amount(&svm, &participant_ata), 200_000);
+        assert_eq!(read_token_amount(&svm, &vault), 700_000);
+    }
+
+    #[test]
+    fn test_initialize_mint_of_other_token_program_rejected() {
+        let mut svm = setup_svm();
+
+        let initializer = Keypair::new();
+        svm.airdrop(&initializer.pubkey(), 10_000_000_000).unwrap();
+
+        // Legacy mint, Token-2022 program
+        let token_mint = create_mock_token_mint(&mut svm, &initializer.pubkey());
+
+        let seed = 5201u64;
+        let (vest_schedule_pda, bump) = vest_schedule_pda(seed);
+        let vault = derive_ata_with_program(&vest_schedule_pda, &token_mint, &TOKEN_2022_PROGRAM_ID);
+
+        let mut instruction = build_initialize_instruction(
+            &initializer.pubkey(),
+            &vest_schedule_pda,
+            &token_mint,
+            &vault,
+            create_initialize_instruction_data(
+                seed,
+                (JAN_1_2025 + ONE_DAY as i64) as u64,
+                ONE_DAY,
+                ONE_DAY * 10,
+                ONE_DAY,
+                bump,
+            ),
+        );
+        instruction.accounts[5] = AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false);
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&initializer.pubkey()),
+            &[&initializer],
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::Custom(INVALID_TOKEN_PROGRAM_ERROR))
+        );
+    }
+
+    fn send_initialize_with_label(
+        svm: &mut LiteSVM,
+        seed: u64,
+        label: [u8; 16],
+    ) -> (
+        Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>,
+        Pubkey,
+    ) {
+        let initializer = Keypair::new();
+        svm.airdrop(&initializer.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(svm, &initializer.pubkey());
+        let start_timestamp = (JAN_1_2025 + ONE_DAY as i64) as u64;
+
+        let (vest_schedule_pda, bump) = vest_schedule_pda(seed);
+        let vault = derive_ata(&vest_schedule_pda, &token_mint);
+
+        let mut instruction_data = create_initialize_instruction_data(
+            seed,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+            bump,
+        );
+        instruction_data[LABEL_OFFSET..LABEL_OFFSET + 16].copy_from_slice(&label);
+
+        let instruction = build_initialize_instruction(
+            &initializer.pubkey(),
+            &vest_schedule_pda,
+            &token_mint,
+            &vault,
+            instruction_data,
+        );
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&initializer.pubkey()),
+            &[&initializer],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        (result, vest_schedule_pda)
+    }
+
+    #[test]
+    fn test_initialize_stores_label() {
+        let mut svm = setup_svm();
+
+        let mut label = [0u8; 16];
+        label[..10].copy_from_slice(b"SEED-ROUND");
+
+        let (result, vest_schedule_pda) = send_initialize_with_label(&mut svm, 5165, label);
+        assert!(result.is_ok(), "Initialize with a label should succeed");
+
+        let stored = read_vest_schedule(&svm, &vest_schedule_pda).label;
+        assert_eq!(stored, label);
+
+        let text = std::str::from_utf8(&stored).unwrap().trim_end_matches('\0');
+        assert_eq!(text, "SEED-ROUND");
+    }
+
+    #[test]
+    fn test_initialize_invalid_utf8_label_rejected() {
+        let mut svm = setup_svm();
+
+        // "é" cut after its first byte
+        let mut label = [0u8; 16];
+        label[..5].copy_from_slice(b"SEED-");
+        label[5] = 0xc3;
+
+        let (result, _) = send_initialize_with_label(&mut svm, 5166, label);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::InvalidInstructionData)
+        );
+    }
+
+    #[test]
+    fn test_initialize_creates_empty_vault_ata() {
+        let mut svm = setup_svm();
+
+        let (result, vest_schedule_pda) = send_initialize_with_label(&mut svm, 5167, [0u8; 16]);
+        assert!(result.is_ok(), "Initialize should succeed");
+
+        let schedule = read_vest_schedule(&svm, &vest_schedule_pda);
+        let token_mint = Pubkey::new_from_array(schedule.token_mint);
+        let vault = derive_ata(&vest_schedule_pda, &token_mint);
+        assert_eq!(
+            schedule.vault,
+            vault.to_bytes(),
+            "Should record the schedule's ATA as its vault"
+        );
+
+        let vault_account = svm.get_account(&vault).expect("Vault should exist");
+        assert_eq!(vault_account.owner, TOKEN_PROGRAM_ID);
+
+        let vault_state = TokenAccount::unpack(&vault_account.data).unwrap();
+        assert_eq!(
+            vault_state.owner, vest_schedule_pda,
+            "Vault should belong to the schedule"
+        );
+        assert_eq!(vault_state.mint, token_mint);
+        assert_eq!(vault_state.amount, 0);
+        assert_eq!(vault_state.state, AccountState::Initialized);
+    }
+}
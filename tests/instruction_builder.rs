@@ -0,0 +1,225 @@
+mod common;
+
+#[cfg(test)]
+mod instruction_builder_tests {
+    use crate::common::*;
+    use litesvm::LiteSVM;
+    use solana_sdk::{
+        instruction::Instruction,
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    };
+    use spl_token::ID as TOKEN_PROGRAM_ID;
+    use token_vesting::instruction_builder::{
+        add_participant_ix, associated_token_address, claim_ix, config_pda, generation_pda,
+        initialize_ix, vault_address, vault_authority_pda, vest_participant_pda, vest_schedule_pda,
+    };
+    use token_vesting::InitializeInstructionData;
+
+    fn send(svm: &mut LiteSVM, instruction: Instruction, signer: &Keypair) {
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "Built instruction should be accepted");
+    }
+
+    /// `(pubkey, is_signer, is_writable)` per account, for comparing against `TryFrom`.
+    fn account_layout(instruction: &Instruction) -> Vec<(Pubkey, bool, bool)> {
+        instruction
+            .accounts
+            .iter()
+            .map(|meta| (meta.pubkey, meta.is_signer, meta.is_writable))
+            .collect()
+    }
+
+    #[test]
+    fn test_initialize_data_round_trips_through_parse() {
+        let mut data =
+            InitializeInstructionData::new(7, JAN_1_2025 as u64, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+        data.claim_cooldown = ONE_DAY;
+        data.label[..4].copy_from_slice(b"SEED");
+
+        let bytes = data.to_bytes();
+        let parsed = InitializeInstructionData::parse(&bytes, JAN_1_2025 as u64).unwrap();
+
+        assert_eq!(parsed.to_bytes(), bytes);
+        assert_eq!({ parsed.bump }, vest_schedule_pda(7).1);
+    }
+
+    #[test]
+    fn test_builders_follow_on_chain_account_order() {
+        let initializer = Pubkey::new_unique();
+        let participant = Pubkey::new_unique();
+        let token_mint = Pubkey::new_unique();
+        let system_program = Pubkey::new_from_array(pinocchio_system::ID);
+        let ata_program = spl_associated_token_account::ID;
+
+        let (schedule, _) = vest_schedule_pda(11);
+        let vault = vault_address(&schedule, &token_mint, &TOKEN_PROGRAM_ID, false);
+        let (participant_state, _) = vest_participant_pda(&participant, &schedule);
+
+        // InitializeAccounts: initializer, vest_schedule, token_mint, vault, system_program,
+        // token_program, ata_program, generation_marker, then the vault authority
+        let mut data =
+            InitializeInstructionData::new(11, JAN_1_2025 as u64, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+        data.use_vault_authority = true;
+        let initialize = initialize_ix(&initializer, &token_mint, &TOKEN_PROGRAM_ID, &data);
+        let (vault_authority, _) = vault_authority_pda(&schedule);
+        assert_eq!(
+            account_layout(&initialize),
+            vec![
+                (initializer, true, true),
+                (schedule, false, true),
+                (token_mint, false, false),
+                (
+                    vault_address(&schedule, &token_mint, &TOKEN_PROGRAM_ID, true),
+                    false,
+                    true
+                ),
+                (system_program, false, false),
+                (TOKEN_PROGRAM_ID, false, false),
+                (ata_program, false, false),
+                (generation_pda(11).0, false, true),
+                (vault_authority, false, false),
+            ]
+        );
+        assert_eq!(initialize.data[0], 0);
+
+        // AddParticipantAccounts: authority, authority_ata, vault, participant,
+        // participant_state, schedule, token_mint, system_program, token_program
+        let add = add_participant_ix(
+            &initializer,
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+            &participant,
+            900_000,
+        );
+        assert_eq!(
+            account_layout(&add),
+            vec![
+                (initializer, true, true),
+                (
+                    spl_associated_token_account::get_associated_token_address(
+                        &initializer,
+                        &token_mint
+                    ),
+                    false,
+                    true
+                ),
+                (vault, false, true),
+                (participant, false, false),
+                (participant_state, false, true),
+                (schedule, false, true),
+                (token_mint, false, false),
+                (system_program, false, false),
+                (TOKEN_PROGRAM_ID, false, false),
+            ]
+        );
+        assert_eq!(add.data[0], 1);
+        assert_eq!(&add.data[1..], &900_000u64.to_le_bytes());
+
+        // ClaimAccounts: participant, participant_state, participant_ata, vest_schedule, vault,
+        // token_mint, system_program, token_program, ata_program, config
+        let claim = claim_ix(
+            &participant,
+            &schedule,
+            &vault,
+            &token_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+        assert_eq!(
+            account_layout(&claim),
+            vec![
+                (participant, true, true),
+                (participant_state, false, true),
+                (
+                    spl_associated_token_account::get_associated_token_address(
+                        &participant,
+                        &token_mint
+                    ),
+                    false,
+                    true
+                ),
+                (schedule, false, true),
+                (vault, false, true),
+                (token_mint, false, false),
+                (system_program, false, false),
+                (TOKEN_PROGRAM_ID, false, false),
+                (ata_program, false, false),
+                (config_pda().0, false, false),
+            ]
+        );
+        assert_eq!(claim.data, vec![2]);
+    }
+
+    #[test]
+    fn test_built_instructions_initialize_add_and_claim() {
+        let mut svm = setup_svm();
+
+        let initializer = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&initializer.pubkey(), 10_000_000_000).unwrap();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(&mut svm, &initializer.pubkey());
+
+        let seed = 12u64;
+        let start_timestamp = (JAN_1_2025 + ONE_DAY as i64) as u64;
+        let data =
+            InitializeInstructionData::new(seed, start_timestamp, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+        let (schedule, _) = vest_schedule_pda(seed);
+        let vault = vault_address(&schedule, &token_mint, &TOKEN_PROGRAM_ID, false);
+
+        send(
+            &mut svm,
+            initialize_ix(&initializer.pubkey(), &token_mint, &TOKEN_PROGRAM_ID, &data),
+            &initializer,
+        );
+        assert_eq!(token_balance(&svm, &vault), 0);
+
+        create_ata_with_balance(&mut svm, &initializer.pubkey(), &token_mint, 1_000_000);
+        send(
+            &mut svm,
+            add_participant_ix(
+                &initializer.pubkey(),
+                &schedule,
+                &vault,
+                &token_mint,
+                &TOKEN_PROGRAM_ID,
+                &participant.pubkey(),
+                900_000,
+            ),
+            &initializer,
+        );
+        assert_eq!(token_balance(&svm, &vault), 900_000);
+
+        // 2 of 9 steps vested
+        warp_to_timestamp(&mut svm, JAN_1_2025 + (ONE_DAY * 4) as i64);
+
+        send(
+            &mut svm,
+            claim_ix(
+                &participant.pubkey(),
+                &schedule,
+                &vault,
+                &token_mint,
+                &TOKEN_PROGRAM_ID,
+            ),
+            &participant,
+        );
+
+        let participant_ata =
+            associated_token_address(&participant.pubkey(), &token_mint, &TOKEN_PROGRAM_ID);
+        assert_eq!(token_balance(&svm, &participant_ata), 200_000);
+        assert_eq!(token_balance(&svm, &vault), 700_000);
+    }
+}
@@ -0,0 +1,94 @@
+mod common;
+
+#[cfg(test)]
+mod participant_info_tests {
+    use crate::common::*;
+    use litesvm::LiteSVM;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    };
+
+    const PARTICIPANT_INFO_DISCRIMINATOR: u8 = 21;
+
+    // Returns (allocated, claimed, claimable, locked)
+    fn participant_info(
+        svm: &mut LiteSVM,
+        schedule: &Pubkey,
+        participant_state: &Pubkey,
+    ) -> (u64, u64, u64, u64) {
+        let payer = Keypair::new();
+        svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(*schedule, false),
+                AccountMeta::new_readonly(*participant_state, false),
+            ],
+            data: vec![PARTICIPANT_INFO_DISCRIMINATOR],
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[&payer],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+
+        let data = result
+            .expect("ParticipantInfo should succeed")
+            .return_data
+            .data;
+        assert_eq!(data.len(), 32);
+
+        let field =
+            |index: usize| u64::from_le_bytes(data[index * 8..index * 8 + 8].try_into().unwrap());
+        (field(0), field(1), field(2), field(3))
+    }
+
+    #[test]
+    fn test_participant_info_partially_vested_breakdown_adds_up() {
+        let mut svm = setup_svm();
+
+        let authority = Pubkey::new_unique();
+        let token_mint = create_mock_token_mint(&mut svm, &authority);
+
+        // Started 3 days ago, 2 of 9 steps unlocked
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &authority,
+            &token_mint,
+            1,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        // 200,000 vested, 50,000 of it already claimed
+        let participant_state =
+            create_participant_state(&mut svm, &Pubkey::new_unique(), &schedule, 900_000, 50_000);
+
+        let (allocated, claimed, claimable, locked) =
+            participant_info(&mut svm, &schedule, &participant_state);
+        assert_eq!(allocated, 900_000);
+        assert_eq!(claimed, 50_000);
+        assert_eq!(claimable, 150_000);
+        assert_eq!(locked, 700_000);
+        assert_eq!(claimed + claimable + locked, allocated);
+
+        // A step later, 100,000 moves from locked to claimable
+        warp_to_timestamp(&mut svm, JAN_1_2025 + ONE_DAY as i64);
+        let (allocated, claimed, claimable, locked) =
+            participant_info(&mut svm, &schedule, &participant_state);
+        assert_eq!((claimable, locked), (250_000, 600_000));
+        assert_eq!(claimed + claimable + locked, allocated);
+    }
+}
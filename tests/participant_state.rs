@@ -0,0 +1,239 @@
+#[cfg(test)]
+mod participant_state_tests {
+    use token_vesting::{
+        Claimable, PinocchioError, VestParticipant, VestSchedule, ACCOUNT_VERSION,
+    };
+
+    // January 1, 2025 00:00:00 UTC
+    const JAN_1_2025: u64 = 1735689600;
+    const ONE_DAY: u64 = 86_400;
+
+    fn create_vest_schedule_data(
+        start_timestamp: u64,
+        cliff_duration: u64,
+        total_duration: u64,
+        step_duration: u64,
+    ) -> Vec<u8> {
+        let mut data = vec![0u8; VestSchedule::LEN];
+        VestSchedule::load_mut(&mut data).unwrap().set_inner(
+            [1u8; 32],
+            [2u8; 32],
+            [3u8; 32],
+            1,
+            start_timestamp,
+            cliff_duration,
+            total_duration,
+            step_duration,
+            255,
+        );
+        data
+    }
+
+    fn create_participant_data(allocated_amount: u64, claimed_amount: u64) -> Vec<u8> {
+        let mut data = vec![0u8; VestParticipant::LEN];
+        VestParticipant::load_mut(&mut data).unwrap().set_inner(
+            [4u8; 32],
+            [5u8; 32],
+            allocated_amount,
+            claimed_amount,
+            255,
+        );
+        data
+    }
+
+    #[test]
+    fn test_claimable_now_matches_schedule_calculation() {
+        let schedule_data = create_vest_schedule_data(JAN_1_2025, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+        let schedule = VestSchedule::load(&schedule_data).unwrap();
+
+        let participant_data = create_participant_data(900_000, 100_000);
+        let participant = VestParticipant::load(&participant_data).unwrap();
+
+        // Before the cliff, mid-stepping, exactly on a step boundary and after completion
+        let timestamps = [
+            JAN_1_2025,
+            JAN_1_2025 + ONE_DAY,
+            JAN_1_2025 + ONE_DAY * 3 + 1,
+            JAN_1_2025 + ONE_DAY * 5,
+            JAN_1_2025 + ONE_DAY * 30,
+        ];
+
+        for now in timestamps {
+            assert_eq!(
+                participant.claimable_now(schedule, now),
+                schedule.calculate_claimable_amount(
+                    now,
+                    participant.allocated_amount(),
+                    participant.claimed_amount(),
+                ),
+                "claimable_now should match the schedule calculation at {}",
+                now
+            );
+        }
+    }
+
+    #[test]
+    fn test_in_memory_participant_claimable_at_boundaries() {
+        let schedule = VestSchedule::new_for_test(JAN_1_2025, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+        let participant = VestParticipant::new_for_test(900_000, 100_000);
+
+        // One step claimed already, so the first step adds nothing new
+        assert_eq!(
+            participant.claimable_now(&schedule, JAN_1_2025 + ONE_DAY * 2),
+            0
+        );
+        assert_eq!(
+            participant.claimable_now(&schedule, JAN_1_2025 + ONE_DAY * 3),
+            100_000
+        );
+        assert_eq!(
+            participant.claimable_now(&schedule, JAN_1_2025 + ONE_DAY * 10),
+            800_000
+        );
+    }
+
+    #[test]
+    fn test_duration_overrides_vest_participants_at_different_rates() {
+        let schedule = VestSchedule::new_for_test(JAN_1_2025, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+        let on_schedule = VestParticipant::new_for_test(1_000_000, 0);
+
+        // Same schedule, but a three day cliff and twice the vesting period: 17 steps instead of 9
+        let mut overridden = VestParticipant::new_for_test(1_000_000, 0);
+        overridden.set_duration_overrides(ONE_DAY * 3, ONE_DAY * 20);
+        assert_eq!(overridden.cliff_override(), ONE_DAY * 3);
+        assert_eq!(overridden.total_override(), ONE_DAY * 20);
+
+        let now = JAN_1_2025 + ONE_DAY * 2;
+        assert_eq!(on_schedule.claimable_now(&schedule, now), 111_111);
+        assert_eq!(overridden.claimable_now(&schedule, now), 0);
+
+        let now = JAN_1_2025 + ONE_DAY * 4;
+        assert_eq!(on_schedule.claimable_now(&schedule, now), 333_333);
+        assert_eq!(overridden.claimable_now(&schedule, now), 58_823);
+
+        let now = JAN_1_2025 + ONE_DAY * 10;
+        assert_eq!(on_schedule.claimable_now(&schedule, now), 1_000_000);
+        assert_eq!(overridden.claimable_now(&schedule, now), 411_764);
+        assert_eq!(
+            overridden.claimable_now(&schedule, JAN_1_2025 + ONE_DAY * 20),
+            1_000_000
+        );
+    }
+
+    #[test]
+    fn test_zero_overrides_keep_schedule_terms() {
+        let schedule = VestSchedule::new_for_test(JAN_1_2025, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+        let participant = VestParticipant::new_for_test(900_000, 0);
+        let terms = participant.terms(&schedule);
+
+        assert_eq!(terms.cliff_duration(), schedule.cliff_duration());
+        assert_eq!(terms.total_duration(), schedule.total_duration());
+    }
+
+    #[test]
+    fn test_revoked_before_cliff_is_completed_not_before_cliff() {
+        let schedule = VestSchedule::new_for_test(JAN_1_2025, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+
+        // Revoked during the cliff, so the frozen allocation is zero
+        let mut participant = VestParticipant::new_for_test(0, 0);
+        participant.set_revoked(true);
+
+        // Nothing will ever vest, so there is no cliff to wait for
+        assert_eq!(
+            participant.claimable(&schedule, JAN_1_2025),
+            Claimable::Completed
+        );
+        assert_eq!(
+            participant.claimable(&schedule, JAN_1_2025 + ONE_DAY * 2),
+            Claimable::Completed
+        );
+    }
+
+    #[test]
+    fn test_load_rejects_unknown_future_version() {
+        let mut data = create_participant_data(900_000, 0);
+        assert_eq!(data[1], ACCOUNT_VERSION);
+
+        data[1] = ACCOUNT_VERSION + 1;
+
+        assert_eq!(
+            VestParticipant::load(&data).err(),
+            Some(PinocchioError::UnsupportedVersion.into())
+        );
+        assert!(VestParticipant::load_mut(&mut data).is_err());
+    }
+
+    #[test]
+    fn test_total_fees_paid_starts_at_zero() {
+        let mut data = create_participant_data(900_000, 0);
+        let participant = VestParticipant::load_mut(&mut data).unwrap();
+        assert_eq!(participant.total_fees_paid(), 0);
+
+        participant.set_total_fees_paid(1_234);
+        assert_eq!(participant.total_fees_paid(), 1_234);
+    }
+
+    #[test]
+    fn test_load_rejects_claimed_above_allocated() {
+        let data = create_participant_data(900_000, 900_001);
+
+        assert_eq!(
+            VestParticipant::load(&data).err(),
+            Some(PinocchioError::ClaimExceedsAllocation.into())
+        );
+    }
+
+    #[test]
+    fn test_load_rejects_wrong_discriminator() {
+        let mut data = create_participant_data(900_000, 0);
+        data[0] = VestSchedule::DISCRIMINATOR;
+
+        assert_eq!(
+            VestParticipant::load(&data).err(),
+            Some(PinocchioError::InvalidDiscriminator.into())
+        );
+    }
+
+    #[test]
+    fn test_from_bytes_round_trips_account_data() {
+        let mut data = create_participant_data(900_000, 200_000);
+        {
+            let participant = VestParticipant::load_mut(&mut data).unwrap();
+            participant.set_forfeited_amount(50_000);
+            participant.set_total_fees_paid(1_234);
+            participant.set_revoked(true);
+            participant.set_duration_overrides(ONE_DAY * 2, ONE_DAY * 20);
+            participant.set_claim_delegate([6u8; 32]);
+            participant.set_last_claim_ts(JAN_1_2025);
+        }
+
+        let view = VestParticipant::from_bytes(&data).unwrap();
+        assert_eq!(view.discriminator, VestParticipant::DISCRIMINATOR);
+        assert_eq!(view.version, ACCOUNT_VERSION);
+        assert_eq!(view.participant, [4u8; 32]);
+        assert_eq!(view.schedule, [5u8; 32]);
+        assert_eq!(view.allocated_amount, 900_000);
+        assert_eq!(view.claimed_amount, 200_000);
+        assert_eq!(view.bump, 255);
+        assert_eq!(view.forfeited_amount, 50_000);
+        assert_eq!(view.total_fees_paid, 1_234);
+        assert!(view.revoked);
+        assert_eq!(view.cliff_override, ONE_DAY * 2);
+        assert_eq!(view.total_override, ONE_DAY * 20);
+        assert_eq!(view.claim_delegate, [6u8; 32]);
+        assert_eq!(view.last_claim_ts, JAN_1_2025);
+
+        // Borsh-style serialization has no padding, so it reproduces the account bytes
+        assert_eq!(view.to_bytes().to_vec(), data);
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_invalid_state() {
+        let data = create_participant_data(900_000, 900_001);
+
+        assert_eq!(
+            VestParticipant::from_bytes(&data).err(),
+            Some(PinocchioError::ClaimExceedsAllocation.into())
+        );
+    }
+}
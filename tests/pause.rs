@@ -0,0 +1,257 @@
+mod common;
+
+#[cfg(test)]
+mod pause_tests {
+    use crate::common::*;
+    use litesvm::LiteSVM;
+    use pinocchio_system::ID;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction, InstructionError},
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::{Transaction, TransactionError},
+    };
+    use spl_associated_token_account::ID as ATA_PROGRAM_ID;
+    use spl_token::ID as TOKEN_PROGRAM_ID;
+    use token_vesting::instruction_builder::config_pda;
+
+    const CLAIM_DISCRIMINATOR: u8 = 2;
+    const PAUSE_DISCRIMINATOR: u8 = 13;
+    const RESUME_DISCRIMINATOR: u8 = 14;
+
+    // PinocchioError::NoClaimableAmount
+    const NO_CLAIMABLE_AMOUNT_ERROR: u32 = 5;
+    // PinocchioError::SchedulePaused
+    const SCHEDULE_PAUSED_ERROR: u32 = 27;
+    // PinocchioError::ScheduleNotPaused
+    const SCHEDULE_NOT_PAUSED_ERROR: u32 = 28;
+
+    struct Fixture {
+        authority: Keypair,
+        participant: Keypair,
+        token_mint: Pubkey,
+        schedule: Pubkey,
+        participant_state: Pubkey,
+        vault: Pubkey,
+    }
+
+    // Started 3 days ago with a 1 day cliff and 1 day steps: 2 of 9 steps vested
+    fn setup_pause(svm: &mut LiteSVM, seed: u64, pause_freezes_accrual: bool) -> Fixture {
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(svm, &authority.pubkey());
+
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let schedule = create_vest_schedule(
+            svm,
+            &authority.pubkey(),
+            &token_mint,
+            seed,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        update_vest_schedule(svm, &schedule, |schedule| {
+            schedule.pause_freezes_accrual = pause_freezes_accrual
+        });
+
+        let participant_state =
+            create_participant_state(svm, &participant.pubkey(), &schedule, 900_000, 0);
+        let vault = create_ata_with_balance(svm, &schedule, &token_mint, 900_000);
+
+        Fixture {
+            authority,
+            participant,
+            token_mint,
+            schedule,
+            participant_state,
+            vault,
+        }
+    }
+
+    fn send_pause_or_resume(
+        svm: &mut LiteSVM,
+        fixture: &Fixture,
+        discriminator: u8,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(fixture.authority.pubkey(), true),
+                AccountMeta::new(fixture.schedule, false),
+            ],
+            data: vec![discriminator],
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&fixture.authority.pubkey()),
+            &[&fixture.authority],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        result
+    }
+
+    fn send_claim(
+        svm: &mut LiteSVM,
+        fixture: &Fixture,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let participant_ata = derive_ata(&fixture.participant.pubkey(), &fixture.token_mint);
+
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(fixture.participant.pubkey(), true),
+                AccountMeta::new(fixture.participant_state, false),
+                AccountMeta::new(participant_ata, false),
+                AccountMeta::new(fixture.schedule, false),
+                AccountMeta::new(fixture.vault, false),
+                AccountMeta::new_readonly(fixture.token_mint, false),
+                AccountMeta::new_readonly(ID.into(), false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ATA_PROGRAM_ID, false),
+                AccountMeta::new_readonly(config_pda().0, false),
+            ],
+            data: vec![CLAIM_DISCRIMINATOR],
+        };
+
+        // Repeated claims would otherwise be identical transactions
+        svm.expire_blockhash();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&fixture.participant.pubkey()),
+            &[&fixture.participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        result
+    }
+
+    fn claimed_so_far(svm: &LiteSVM, fixture: &Fixture) -> u64 {
+        token_balance(
+            svm,
+            &derive_ata(&fixture.participant.pubkey(), &fixture.token_mint),
+        )
+    }
+
+    // ==================== SUCCESS CASES ====================
+
+    #[test]
+    fn test_pause_freezing_accrual_shifts_vesting() {
+        let mut svm = setup_svm();
+        let fixture = setup_pause(&mut svm, 1, true);
+
+        assert!(send_claim(&mut svm, &fixture).is_ok());
+        assert_eq!(claimed_so_far(&svm, &fixture), 200_000);
+
+        assert!(send_pause_or_resume(&mut svm, &fixture, PAUSE_DISCRIMINATOR).is_ok());
+
+        // A day into the pause nothing new has vested
+        warp_to_timestamp(&mut svm, JAN_1_2025 + ONE_DAY as i64);
+        let result = send_claim(&mut svm, &fixture);
+        assert_custom_error(&result, NO_CLAIMABLE_AMOUNT_ERROR);
+
+        // Resume after two days, then wait one more: only one more step has vested
+        warp_to_timestamp(&mut svm, JAN_1_2025 + (ONE_DAY * 2) as i64);
+        assert!(send_pause_or_resume(&mut svm, &fixture, RESUME_DISCRIMINATOR).is_ok());
+
+        warp_to_timestamp(&mut svm, JAN_1_2025 + (ONE_DAY * 3) as i64);
+        assert!(send_claim(&mut svm, &fixture).is_ok());
+        assert_eq!(claimed_so_far(&svm, &fixture), 300_000);
+
+        // The schedule ends two days late
+        warp_to_timestamp(&mut svm, JAN_1_2025 + (ONE_DAY * 8) as i64);
+        assert!(send_claim(&mut svm, &fixture).is_ok());
+        assert_eq!(claimed_so_far(&svm, &fixture), 800_000);
+
+        warp_to_timestamp(&mut svm, JAN_1_2025 + (ONE_DAY * 9) as i64);
+        assert!(send_claim(&mut svm, &fixture).is_ok());
+        assert_eq!(claimed_so_far(&svm, &fixture), 900_000);
+    }
+
+    #[test]
+    fn test_pause_without_freezing_holds_claims_only() {
+        let mut svm = setup_svm();
+        let fixture = setup_pause(&mut svm, 2, false);
+
+        assert!(send_pause_or_resume(&mut svm, &fixture, PAUSE_DISCRIMINATOR).is_ok());
+
+        let result = send_claim(&mut svm, &fixture);
+        assert_custom_error(&result, SCHEDULE_PAUSED_ERROR);
+
+        // Accrual kept running through the two day pause
+        warp_to_timestamp(&mut svm, JAN_1_2025 + (ONE_DAY * 2) as i64);
+        assert!(send_pause_or_resume(&mut svm, &fixture, RESUME_DISCRIMINATOR).is_ok());
+
+        assert!(send_claim(&mut svm, &fixture).is_ok());
+        assert_eq!(claimed_so_far(&svm, &fixture), 400_000);
+    }
+
+    #[test]
+    fn test_pause_at_timestamp_zero_holds_claims() {
+        let mut svm = setup_svm();
+        let fixture = setup_pause(&mut svm, 5, false);
+
+        // A clock reading zero is a valid pause time, not the unpaused sentinel
+        warp_to_timestamp(&mut svm, 0);
+        assert!(send_pause_or_resume(&mut svm, &fixture, PAUSE_DISCRIMINATOR).is_ok());
+
+        let schedule = read_vest_schedule(&svm, &fixture.schedule);
+        assert!(schedule.paused);
+        assert_eq!(schedule.paused_at, 0);
+
+        warp_to_timestamp(&mut svm, JAN_1_2025);
+        let result = send_claim(&mut svm, &fixture);
+        assert_custom_error(&result, SCHEDULE_PAUSED_ERROR);
+
+        assert!(send_pause_or_resume(&mut svm, &fixture, RESUME_DISCRIMINATOR).is_ok());
+        assert!(!read_vest_schedule(&svm, &fixture.schedule).paused);
+        assert!(send_claim(&mut svm, &fixture).is_ok());
+    }
+
+    // ==================== FAILURE CASES ====================
+
+    #[test]
+    fn test_pause_twice_and_resume_unpaused_rejected() {
+        let mut svm = setup_svm();
+        let fixture = setup_pause(&mut svm, 3, true);
+
+        let result = send_pause_or_resume(&mut svm, &fixture, RESUME_DISCRIMINATOR);
+        assert_custom_error(&result, SCHEDULE_NOT_PAUSED_ERROR);
+
+        assert!(send_pause_or_resume(&mut svm, &fixture, PAUSE_DISCRIMINATOR).is_ok());
+
+        svm.expire_blockhash();
+        let result = send_pause_or_resume(&mut svm, &fixture, PAUSE_DISCRIMINATOR);
+        assert_custom_error(&result, SCHEDULE_PAUSED_ERROR);
+    }
+
+    #[test]
+    fn test_pause_wrong_authority_rejected() {
+        let mut svm = setup_svm();
+        let mut fixture = setup_pause(&mut svm, 4, true);
+
+        let impostor = Keypair::new();
+        svm.airdrop(&impostor.pubkey(), 10_000_000_000).unwrap();
+        fixture.authority = impostor;
+
+        let result = send_pause_or_resume(&mut svm, &fixture, PAUSE_DISCRIMINATOR);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::IllegalOwner)
+        );
+    }
+}
@@ -0,0 +1,190 @@
+mod common;
+
+#[cfg(test)]
+mod precreate_atas_tests {
+    use crate::common::*;
+    use litesvm::LiteSVM;
+    use pinocchio_system::ID;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction, InstructionError},
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::{Transaction, TransactionError},
+    };
+    use spl_associated_token_account::ID as ATA_PROGRAM_ID;
+    use spl_token::solana_program::program_pack::Pack;
+    use spl_token::state::Account as TokenAccount;
+    use spl_token::ID as TOKEN_PROGRAM_ID;
+    use token_vesting::instruction_builder::config_pda;
+
+    const CLAIM_DISCRIMINATOR: u8 = 2;
+    const PRECREATE_ATAS_DISCRIMINATOR: u8 = 19;
+
+    struct Fixture {
+        authority: Keypair,
+        token_mint: Pubkey,
+        schedule: Pubkey,
+        vault: Pubkey,
+    }
+
+    // Started 3 days ago with a 1 day cliff and 1 day steps: 2 of 9 steps vested
+    fn setup_schedule(svm: &mut LiteSVM, seed: u64) -> Fixture {
+        let authority = Keypair::new();
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(svm, &authority.pubkey());
+
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let schedule = create_vest_schedule(
+            svm,
+            &authority.pubkey(),
+            &token_mint,
+            seed,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+        let vault = create_ata_with_balance(svm, &schedule, &token_mint, 2_700_000);
+
+        Fixture {
+            authority,
+            token_mint,
+            schedule,
+            vault,
+        }
+    }
+
+    fn send_precreate_atas(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        fixture: &Fixture,
+        participants: &[Pubkey],
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let mut accounts = vec![
+            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new_readonly(fixture.schedule, false),
+            AccountMeta::new_readonly(fixture.token_mint, false),
+            AccountMeta::new_readonly(ID.into(), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ATA_PROGRAM_ID, false),
+        ];
+        for participant in participants {
+            accounts.push(AccountMeta::new_readonly(*participant, false));
+            accounts.push(AccountMeta::new(
+                derive_ata(participant, &fixture.token_mint),
+                false,
+            ));
+        }
+
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts,
+            data: vec![PRECREATE_ATAS_DISCRIMINATOR],
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[payer],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        result
+    }
+
+    // ==================== SUCCESS CASES ====================
+
+    #[test]
+    fn test_precreate_atas_is_idempotent_and_makes_claims_cheap() {
+        let mut svm = setup_svm();
+        let fixture = setup_schedule(&mut svm, 1);
+
+        let claimer = Keypair::new();
+        svm.airdrop(&claimer.pubkey(), 10_000_000_000).unwrap();
+        let participants = [claimer.pubkey(), Pubkey::new_unique(), Pubkey::new_unique()];
+
+        let result = send_precreate_atas(&mut svm, &fixture.authority, &fixture, &participants);
+        assert!(result.is_ok(), "PrecreateAtas should succeed");
+
+        for participant in &participants {
+            let ata = derive_ata(participant, &fixture.token_mint);
+            let account = svm.get_account(&ata).expect("ATA should exist");
+            assert_eq!(account.owner, TOKEN_PROGRAM_ID);
+            let token_account = TokenAccount::unpack(&account.data).unwrap();
+            assert_eq!(token_account.owner, *participant);
+            assert_eq!(token_account.amount, 0);
+        }
+
+        // Re-running skips the existing ATAs
+        svm.expire_blockhash();
+        let result = send_precreate_atas(&mut svm, &fixture.authority, &fixture, &participants);
+        assert!(result.is_ok(), "PrecreateAtas should be idempotent");
+
+        // The claim only pays the transaction fee, not ATA rent
+        let participant_state =
+            create_participant_state(&mut svm, &claimer.pubkey(), &fixture.schedule, 900_000, 0);
+        let participant_ata = derive_ata(&claimer.pubkey(), &fixture.token_mint);
+        let lamports_before = svm.get_account(&claimer.pubkey()).unwrap().lamports;
+
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(claimer.pubkey(), true),
+                AccountMeta::new(participant_state, false),
+                AccountMeta::new(participant_ata, false),
+                AccountMeta::new(fixture.schedule, false),
+                AccountMeta::new(fixture.vault, false),
+                AccountMeta::new_readonly(fixture.token_mint, false),
+                AccountMeta::new_readonly(ID.into(), false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ATA_PROGRAM_ID, false),
+                AccountMeta::new_readonly(config_pda().0, false),
+            ],
+            data: vec![CLAIM_DISCRIMINATOR],
+        };
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&claimer.pubkey()),
+            &[&claimer],
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        assert!(
+            result.is_ok(),
+            "Claim into the precreated ATA should succeed"
+        );
+
+        assert_eq!(token_balance(&svm, &participant_ata), 200_000);
+        let lamports_spent = lamports_before - svm.get_account(&claimer.pubkey()).unwrap().lamports;
+        assert!(
+            lamports_spent < 1_000_000,
+            "Claim paid {lamports_spent} lamports"
+        );
+    }
+
+    // ==================== FAILURE CASES ====================
+
+    #[test]
+    fn test_precreate_atas_non_authority_rejected() {
+        let mut svm = setup_svm();
+        let fixture = setup_schedule(&mut svm, 2);
+
+        let stranger = Keypair::new();
+        svm.airdrop(&stranger.pubkey(), 10_000_000_000).unwrap();
+        let participant = Pubkey::new_unique();
+
+        let result = send_precreate_atas(&mut svm, &stranger, &fixture, &[participant]);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::IllegalOwner)
+        );
+        assert!(svm
+            .get_account(&derive_ata(&participant, &fixture.token_mint))
+            .is_none());
+    }
+}
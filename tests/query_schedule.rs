@@ -0,0 +1,62 @@
+mod common;
+
+#[cfg(test)]
+mod query_schedule_tests {
+    use crate::common::*;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    };
+    use token_vesting::{VestSchedule, VestScheduleSnapshot};
+
+    const QUERY_SCHEDULE_DISCRIMINATOR: u8 = 9;
+
+    #[test]
+    fn test_query_schedule_returns_snapshot_bytes() {
+        let mut svm = setup_svm();
+        let payer = Keypair::new();
+        svm.airdrop(&payer.pubkey(), 10_000_000_000).unwrap();
+
+        let schedule = create_vest_schedule(
+            &mut svm,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            7,
+            (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![AccountMeta::new_readonly(schedule, false)],
+            data: vec![QUERY_SCHEDULE_DISCRIMINATOR],
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[&payer],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        let meta = result.expect("QuerySchedule should succeed");
+
+        // The snapshot serializes field by field without padding, i.e. the account layout
+        let account = svm.get_account(&schedule).unwrap();
+        assert_eq!(meta.return_data.program_id, PROGRAM_ID);
+        assert_eq!(meta.return_data.data.len(), VestScheduleSnapshot::LEN);
+        assert_eq!(meta.return_data.data, account.data);
+        assert_eq!(
+            VestSchedule::from_bytes(&meta.return_data.data)
+                .unwrap()
+                .seed,
+            7
+        );
+    }
+}
@@ -0,0 +1,94 @@
+mod common;
+
+#[cfg(test)]
+mod query_vault_health_tests {
+    use crate::common::*;
+    use litesvm::LiteSVM;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    };
+
+    const QUERY_VAULT_HEALTH_DISCRIMINATOR: u8 = 12;
+
+    fn setup_health(svm: &mut LiteSVM, seed: u64, vault_balance: u64) -> (Pubkey, Pubkey) {
+        let authority = Pubkey::new_unique();
+        let token_mint = create_mock_token_mint(svm, &authority);
+
+        // 1,000,000 allocated, 100,000 of it already claimed: 900,000 still owed
+        let start_timestamp = (JAN_1_2025 - ONE_DAY as i64) as u64;
+        let schedule = create_vest_schedule(
+            svm,
+            &authority,
+            &token_mint,
+            seed,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+        update_vest_schedule(svm, &schedule, |schedule| {
+            schedule.total_allocated = 1_000_000;
+            schedule.total_claimed = 100_000;
+        });
+
+        let vault = create_ata_with_balance(svm, &schedule, &token_mint, vault_balance);
+
+        (schedule, vault)
+    }
+
+    fn query_vault_health(svm: &mut LiteSVM, schedule: &Pubkey, vault: &Pubkey) -> (u64, u64, i64) {
+        let payer = Keypair::new();
+        svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(*schedule, false),
+                AccountMeta::new_readonly(*vault, false),
+            ],
+            data: vec![QUERY_VAULT_HEALTH_DISCRIMINATOR],
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[&payer],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        let data = result
+            .expect("QueryVaultHealth should succeed")
+            .return_data
+            .data;
+        assert_eq!(data.len(), 24);
+
+        (
+            u64::from_le_bytes(data[0..8].try_into().unwrap()),
+            u64::from_le_bytes(data[8..16].try_into().unwrap()),
+            i64::from_le_bytes(data[16..24].try_into().unwrap()),
+        )
+    }
+
+    #[test]
+    fn test_query_vault_health_funded() {
+        let mut svm = setup_svm();
+        let (schedule, vault) = setup_health(&mut svm, 1, 1_000_000);
+
+        let health = query_vault_health(&mut svm, &schedule, &vault);
+        assert_eq!(health, (1_000_000, 900_000, 100_000));
+    }
+
+    #[test]
+    fn test_query_vault_health_underfunded_reports_shortfall() {
+        let mut svm = setup_svm();
+        let (schedule, vault) = setup_health(&mut svm, 2, 500_000);
+
+        let health = query_vault_health(&mut svm, &schedule, &vault);
+        assert_eq!(health, (500_000, 900_000, -400_000));
+    }
+}
@@ -0,0 +1,207 @@
+mod common;
+
+#[cfg(test)]
+mod reassign_participant_tests {
+    use crate::common::*;
+    use litesvm::LiteSVM;
+    use pinocchio_system::ID;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction, InstructionError},
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::{Transaction, TransactionError},
+    };
+    use spl_token::ID as TOKEN_PROGRAM_ID;
+    use token_vesting::instruction_builder::vest_participant_pda;
+
+    const REASSIGN_PARTICIPANT_DISCRIMINATOR: u8 = 26;
+
+    fn create_add_participant_instruction_data(
+        allocated_amount: u64,
+        participant_bump: u8,
+    ) -> Vec<u8> {
+        let mut data = vec![1u8]; // Discriminator for AddParticipant
+        data.extend_from_slice(&allocated_amount.to_le_bytes());
+        data.push(participant_bump);
+        data
+    }
+
+    fn build_add_participant_instruction(
+        authority: &Pubkey,
+        authority_ata: &Pubkey,
+        vault: &Pubkey,
+        participant: &Pubkey,
+        participant_state: &Pubkey,
+        schedule: &Pubkey,
+        token_mint: &Pubkey,
+        instruction_data: Vec<u8>,
+    ) -> Instruction {
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(*authority, true),
+                AccountMeta::new(*authority_ata, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new_readonly(*participant, false),
+                AccountMeta::new(*participant_state, false),
+                AccountMeta::new(*schedule, false),
+                AccountMeta::new_readonly(*token_mint, false),
+                AccountMeta::new_readonly(ID.into(), false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            ],
+            data: instruction_data,
+        }
+    }
+
+    struct Fixture {
+        authority: Keypair,
+        participant: Pubkey,
+        participant_state: Pubkey,
+        schedule: Pubkey,
+    }
+
+    // Schedule starting tomorrow with a 1 day cliff, and a participant allocated 100,000
+    fn setup_participant(svm: &mut LiteSVM, seed: u64) -> Fixture {
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(svm, &authority.pubkey());
+        let schedule = create_vest_schedule(
+            svm,
+            &authority.pubkey(),
+            &token_mint,
+            seed,
+            (JAN_1_2025 + ONE_DAY as i64) as u64,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        let authority_ata =
+            create_ata_with_balance(svm, &authority.pubkey(), &token_mint, 1_000_000);
+        let vault = create_ata_with_balance(svm, &schedule, &token_mint, 0);
+        let (participant_state, participant_bump) =
+            vest_participant_pda(&participant.pubkey(), &schedule);
+
+        let instruction = build_add_participant_instruction(
+            &authority.pubkey(),
+            &authority_ata,
+            &vault,
+            &participant.pubkey(),
+            &participant_state,
+            &schedule,
+            &token_mint,
+            create_add_participant_instruction_data(100_000, participant_bump),
+        );
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&authority.pubkey()),
+            &[&authority],
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "Adding the participant should succeed");
+
+        Fixture {
+            authority,
+            participant: participant.pubkey(),
+            participant_state,
+            schedule,
+        }
+    }
+
+    fn send_reassign_participant(
+        svm: &mut LiteSVM,
+        fixture: &Fixture,
+        signer: &Keypair,
+        new_participant: &Pubkey,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let (new_participant_state, _) = vest_participant_pda(new_participant, &fixture.schedule);
+
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(signer.pubkey(), true),
+                AccountMeta::new(fixture.participant_state, false),
+                AccountMeta::new_readonly(*new_participant, false),
+                AccountMeta::new(new_participant_state, false),
+                AccountMeta::new_readonly(fixture.schedule, false),
+                AccountMeta::new_readonly(ID.into(), false),
+            ],
+            data: vec![REASSIGN_PARTICIPANT_DISCRIMINATOR],
+        };
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        result
+    }
+
+    fn is_closed(svm: &LiteSVM, account: &Pubkey) -> bool {
+        svm.get_account(account).map_or(true, |account| {
+            account.lamports == 0 && account.data.is_empty()
+        })
+    }
+
+    #[test]
+    fn test_reassign_participant_moves_entitlement() {
+        let mut svm = setup_svm();
+        let fixture = setup_participant(&mut svm, 1);
+
+        // Give the old state some history to carry over
+        update_participant_state(&mut svm, &fixture.participant_state, |state| {
+            state.claimed_amount = 25_000
+        });
+
+        let new_participant = Pubkey::new_unique();
+        let result =
+            send_reassign_participant(&mut svm, &fixture, &fixture.authority, &new_participant);
+        assert!(
+            result.is_ok(),
+            "Reassignment by the authority should succeed"
+        );
+
+        assert!(
+            is_closed(&svm, &fixture.participant_state),
+            "Old participant state should be closed"
+        );
+
+        let (new_participant_state, _) = vest_participant_pda(&new_participant, &fixture.schedule);
+        let new_state = read_participant_state(&svm, &new_participant_state);
+        assert_eq!(new_state.participant, new_participant.to_bytes());
+        assert_eq!(new_state.allocated_amount, 100_000);
+        assert_eq!(new_state.claimed_amount, 25_000);
+        assert_eq!(
+            read_vest_schedule(&svm, &fixture.schedule).total_allocated,
+            100_000
+        );
+    }
+
+    #[test]
+    fn test_reassign_participant_non_authority_rejected() {
+        let mut svm = setup_svm();
+        let fixture = setup_participant(&mut svm, 2);
+
+        let attacker = Keypair::new();
+        svm.airdrop(&attacker.pubkey(), 10_000_000_000).unwrap();
+
+        let result = send_reassign_participant(&mut svm, &fixture, &attacker, &attacker.pubkey());
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::IllegalOwner)
+        );
+
+        let old_state = read_participant_state(&svm, &fixture.participant_state);
+        assert_eq!(old_state.participant, fixture.participant.to_bytes());
+    }
+}
@@ -0,0 +1,228 @@
+mod common;
+
+#[cfg(test)]
+mod reduce_allocation_tests {
+    use crate::common::*;
+    use litesvm::LiteSVM;
+    use pinocchio_system::ID;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction, InstructionError},
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::{Transaction, TransactionError},
+    };
+    use spl_token::ID as TOKEN_PROGRAM_ID;
+    use token_vesting::instruction_builder::vest_participant_pda;
+
+    const REDUCE_ALLOCATION_DISCRIMINATOR: u8 = 23;
+
+    // PinocchioError::ClaimExceedsAllocation
+    const CLAIM_EXCEEDS_ALLOCATION_ERROR: u32 = 10;
+
+    fn create_add_participant_instruction_data(
+        allocated_amount: u64,
+        participant_bump: u8,
+    ) -> Vec<u8> {
+        let mut data = vec![1u8]; // Discriminator for AddParticipant
+        data.extend_from_slice(&allocated_amount.to_le_bytes());
+        data.push(participant_bump);
+        data
+    }
+
+    fn build_add_participant_instruction(
+        authority: &Pubkey,
+        authority_ata: &Pubkey,
+        vault: &Pubkey,
+        participant: &Pubkey,
+        participant_state: &Pubkey,
+        schedule: &Pubkey,
+        token_mint: &Pubkey,
+        instruction_data: Vec<u8>,
+    ) -> Instruction {
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(*authority, true),
+                AccountMeta::new(*authority_ata, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new_readonly(*participant, false),
+                AccountMeta::new(*participant_state, false),
+                AccountMeta::new(*schedule, false),
+                AccountMeta::new_readonly(*token_mint, false),
+                AccountMeta::new_readonly(ID.into(), false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            ],
+            data: instruction_data,
+        }
+    }
+
+    struct Fixture {
+        authority: Keypair,
+        authority_ata: Pubkey,
+        vault: Pubkey,
+        participant_state: Pubkey,
+        schedule: Pubkey,
+        token_mint: Pubkey,
+    }
+
+    // Schedule starting tomorrow with a 1 day cliff, and a participant allocated 100,000
+    fn setup_participant(svm: &mut LiteSVM, seed: u64) -> Fixture {
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(svm, &authority.pubkey());
+        let schedule = create_vest_schedule(
+            svm,
+            &authority.pubkey(),
+            &token_mint,
+            seed,
+            (JAN_1_2025 + ONE_DAY as i64) as u64,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        let authority_ata =
+            create_ata_with_balance(svm, &authority.pubkey(), &token_mint, 1_000_000);
+        let vault = create_ata_with_balance(svm, &schedule, &token_mint, 0);
+        let (participant_state, participant_bump) =
+            vest_participant_pda(&participant.pubkey(), &schedule);
+
+        let instruction = build_add_participant_instruction(
+            &authority.pubkey(),
+            &authority_ata,
+            &vault,
+            &participant.pubkey(),
+            &participant_state,
+            &schedule,
+            &token_mint,
+            create_add_participant_instruction_data(100_000, participant_bump),
+        );
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&authority.pubkey()),
+            &[&authority],
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "Adding the participant should succeed");
+
+        Fixture {
+            authority,
+            authority_ata,
+            vault,
+            participant_state,
+            schedule,
+            token_mint,
+        }
+    }
+
+    fn send_reduce_allocation(
+        svm: &mut LiteSVM,
+        fixture: &Fixture,
+        new_allocated_amount: u64,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let mut data = vec![REDUCE_ALLOCATION_DISCRIMINATOR];
+        data.extend_from_slice(&new_allocated_amount.to_le_bytes());
+
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(fixture.authority.pubkey(), true),
+                AccountMeta::new(fixture.authority_ata, false),
+                AccountMeta::new(fixture.vault, false),
+                AccountMeta::new(fixture.participant_state, false),
+                AccountMeta::new(fixture.schedule, false),
+                AccountMeta::new_readonly(fixture.token_mint, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        // Rejected amounts are retried within one test
+        svm.expire_blockhash();
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&fixture.authority.pubkey()),
+            &[&fixture.authority],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        result
+    }
+
+    #[test]
+    fn test_reduce_allocation_refunds_difference() {
+        let mut svm = setup_svm();
+        let fixture = setup_participant(&mut svm, 1);
+
+        let result = send_reduce_allocation(&mut svm, &fixture, 60_000);
+        assert!(result.is_ok(), "Reduction before the cliff should succeed");
+
+        assert_eq!(
+            read_participant_state(&svm, &fixture.participant_state).allocated_amount,
+            60_000
+        );
+        assert_eq!(
+            read_vest_schedule(&svm, &fixture.schedule).total_allocated,
+            60_000
+        );
+        assert_eq!(token_balance(&svm, &fixture.vault), 60_000);
+        assert_eq!(token_balance(&svm, &fixture.authority_ata), 940_000);
+    }
+
+    #[test]
+    fn test_reduce_allocation_to_zero_or_not_lower_rejected() {
+        let mut svm = setup_svm();
+        let fixture = setup_participant(&mut svm, 2);
+
+        for new_allocated_amount in [0, 100_000, 150_000] {
+            let result = send_reduce_allocation(&mut svm, &fixture, new_allocated_amount);
+            assert_eq!(
+                result.unwrap_err().err,
+                TransactionError::InstructionError(0, InstructionError::InvalidInstructionData),
+                "{new_allocated_amount} should be rejected"
+            );
+        }
+
+        assert_eq!(
+            read_participant_state(&svm, &fixture.participant_state).allocated_amount,
+            100_000
+        );
+        assert_eq!(token_balance(&svm, &fixture.vault), 100_000);
+    }
+
+    #[test]
+    fn test_reduce_allocation_below_claimed_rejected() {
+        let mut svm = setup_svm();
+        let fixture = setup_participant(&mut svm, 3);
+
+        // As if SetClaimed had recorded an off-chain distribution
+        update_participant_state(&mut svm, &fixture.participant_state, |state| {
+            state.claimed_amount = 50_000
+        });
+
+        let result = send_reduce_allocation(&mut svm, &fixture, 40_000);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(CLAIM_EXCEEDS_ALLOCATION_ERROR)
+            )
+        );
+
+        let result = send_reduce_allocation(&mut svm, &fixture, 50_000);
+        assert!(
+            result.is_ok(),
+            "Reducing down to the claimed amount should succeed"
+        );
+        assert_eq!(token_balance(&svm, &fixture.vault), 50_000);
+    }
+}
@@ -0,0 +1,502 @@
+mod common;
+
+#[cfg(test)]
+mod revoke_tests {
+    use crate::common::*;
+    use litesvm::LiteSVM;
+    use pinocchio_system::ID;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction, InstructionError},
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::{Transaction, TransactionError},
+    };
+    use spl_associated_token_account::ID as ATA_PROGRAM_ID;
+    use spl_token::ID as TOKEN_PROGRAM_ID;
+    use token_vesting::instruction_builder::config_pda;
+
+    const CLAIM_DISCRIMINATOR: u8 = 2;
+    const REVOKE_DISCRIMINATOR: u8 = 4;
+    const SWEEP_SURPLUS_DISCRIMINATOR: u8 = 25;
+
+    // PinocchioError::VestingRevoked
+    const VESTING_REVOKED_ERROR: u32 = 22;
+    // PinocchioError::AllocationOverflow
+    const ALLOCATION_OVERFLOW_ERROR: u32 = 26;
+    // PinocchioError::NoSurplus
+    const NO_SURPLUS_ERROR: u32 = 38;
+
+    // Returns (allocated_amount, claimed_amount, forfeited_amount, revoked)
+    fn read_participant(svm: &LiteSVM, participant_state: &Pubkey) -> (u64, u64, u64, bool) {
+        let state = read_participant_state(svm, participant_state);
+        (
+            state.allocated_amount,
+            state.claimed_amount,
+            state.forfeited_amount,
+            state.revoked,
+        )
+    }
+
+    fn build_revoke_instruction(
+        authority: &Pubkey,
+        vest_schedule: &Pubkey,
+        participant_state: &Pubkey,
+        vault: &Pubkey,
+        destination: &Pubkey,
+        token_mint: &Pubkey,
+    ) -> Instruction {
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(*authority, true),
+                AccountMeta::new(*vest_schedule, false),
+                AccountMeta::new(*participant_state, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(*destination, false),
+                AccountMeta::new_readonly(*token_mint, false),
+                AccountMeta::new_readonly(ID.into(), false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            ],
+            data: vec![REVOKE_DISCRIMINATOR],
+        }
+    }
+
+    struct Fixture {
+        authority: Keypair,
+        participant: Keypair,
+        token_mint: Pubkey,
+        schedule: Pubkey,
+        participant_state: Pubkey,
+        vault: Pubkey,
+        authority_ata: Pubkey,
+    }
+
+    // Cliff 1 day, 10 day total, 1 day steps: 9 equal steps after the cliff
+    fn setup_revoke(
+        svm: &mut LiteSVM,
+        seed: u64,
+        start_timestamp: u64,
+        allocated: u64,
+        claimed: u64,
+    ) -> Fixture {
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(svm, &authority.pubkey());
+
+        let schedule = create_vest_schedule(
+            svm,
+            &authority.pubkey(),
+            &token_mint,
+            seed,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        set_total_allocated(svm, &schedule, allocated);
+
+        let participant_state =
+            create_participant_state(svm, &participant.pubkey(), &schedule, allocated, claimed);
+
+        let vault = create_ata_with_balance(svm, &schedule, &token_mint, allocated - claimed);
+        let authority_ata = create_ata_with_balance(svm, &authority.pubkey(), &token_mint, 0);
+
+        Fixture {
+            authority,
+            participant,
+            token_mint,
+            schedule,
+            participant_state,
+            vault,
+            authority_ata,
+        }
+    }
+
+    fn set_total_allocated(svm: &mut LiteSVM, schedule: &Pubkey, total_allocated: u64) {
+        update_vest_schedule(svm, schedule, |schedule| {
+            schedule.total_allocated = total_allocated
+        });
+    }
+
+    fn set_total_claimed(svm: &mut LiteSVM, schedule: &Pubkey, total_claimed: u64) {
+        update_vest_schedule(svm, schedule, |schedule| {
+            schedule.total_claimed = total_claimed
+        });
+    }
+
+    fn read_total_allocated(svm: &LiteSVM, schedule: &Pubkey) -> u64 {
+        read_vest_schedule(svm, schedule).total_allocated
+    }
+
+    fn set_revoke_destination(svm: &mut LiteSVM, schedule: &Pubkey, destination: &Pubkey) {
+        update_vest_schedule(svm, schedule, |schedule| {
+            schedule.revoke_destination = destination.to_bytes()
+        });
+    }
+
+    fn send_revoke(
+        svm: &mut LiteSVM,
+        fixture: &Fixture,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        send_revoke_to(svm, fixture, &fixture.authority_ata)
+    }
+
+    fn send_revoke_to(
+        svm: &mut LiteSVM,
+        fixture: &Fixture,
+        destination: &Pubkey,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let instruction = build_revoke_instruction(
+            &fixture.authority.pubkey(),
+            &fixture.schedule,
+            &fixture.participant_state,
+            &fixture.vault,
+            destination,
+            &fixture.token_mint,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&fixture.authority.pubkey()),
+            &[&fixture.authority],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        result
+    }
+
+    fn send_claim(
+        svm: &mut LiteSVM,
+        fixture: &Fixture,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let participant_ata = derive_ata(&fixture.participant.pubkey(), &fixture.token_mint);
+
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(fixture.participant.pubkey(), true),
+                AccountMeta::new(fixture.participant_state, false),
+                AccountMeta::new(participant_ata, false),
+                AccountMeta::new(fixture.schedule, false),
+                AccountMeta::new(fixture.vault, false),
+                AccountMeta::new_readonly(fixture.token_mint, false),
+                AccountMeta::new_readonly(ID.into(), false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ATA_PROGRAM_ID, false),
+                AccountMeta::new_readonly(config_pda().0, false),
+            ],
+            data: vec![CLAIM_DISCRIMINATOR],
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&fixture.participant.pubkey()),
+            &[&fixture.participant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        result
+    }
+
+    fn send_sweep_surplus(
+        svm: &mut LiteSVM,
+        fixture: &Fixture,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(fixture.authority.pubkey(), true),
+                AccountMeta::new(fixture.authority_ata, false),
+                AccountMeta::new(fixture.vault, false),
+                AccountMeta::new_readonly(fixture.schedule, false),
+                AccountMeta::new_readonly(fixture.token_mint, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            ],
+            data: vec![SWEEP_SURPLUS_DISCRIMINATOR],
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&fixture.authority.pubkey()),
+            &[&fixture.authority],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        result
+    }
+
+    // ==================== SUCCESS CASES ====================
+
+    #[test]
+    fn test_revoke_before_cliff_returns_everything() {
+        let mut svm = setup_svm();
+
+        // Starts tomorrow, nothing has vested
+        let start_timestamp = (JAN_1_2025 + ONE_DAY as i64) as u64;
+        let fixture = setup_revoke(&mut svm, 1, start_timestamp, 900_000, 0);
+
+        let result = send_revoke(&mut svm, &fixture);
+        assert!(result.is_ok(), "Revoke before the cliff should succeed");
+
+        assert_eq!(token_balance(&svm, &fixture.authority_ata), 900_000);
+        assert_eq!(token_balance(&svm, &fixture.vault), 0);
+        assert_eq!(
+            read_participant(&svm, &fixture.participant_state),
+            (0, 0, 0, true)
+        );
+    }
+
+    #[test]
+    fn test_revoke_mid_vesting_returns_unvested() {
+        let mut svm = setup_svm();
+
+        // Started 3 days ago: 2 of 9 steps vested = 200,000 of 900,000
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let fixture = setup_revoke(&mut svm, 2, start_timestamp, 900_000, 0);
+
+        let result = send_revoke(&mut svm, &fixture);
+        assert!(result.is_ok(), "Revoke mid-vesting should succeed");
+
+        assert_eq!(token_balance(&svm, &fixture.authority_ata), 700_000);
+        assert_eq!(token_balance(&svm, &fixture.vault), 200_000);
+        assert_eq!(
+            read_participant(&svm, &fixture.participant_state),
+            (200_000, 0, 0, true)
+        );
+        assert_eq!(
+            read_total_allocated(&svm, &fixture.schedule),
+            200_000,
+            "Only the vested part stays committed"
+        );
+
+        // The vested part stays claimable, and vesting does not continue afterwards
+        warp_to_timestamp(&mut svm, JAN_1_2025 + (ONE_DAY * 30) as i64);
+        let result = send_claim(&mut svm, &fixture);
+        assert!(
+            result.is_ok(),
+            "Vested tokens should remain claimable after revoke"
+        );
+
+        let participant_ata = derive_ata(&fixture.participant.pubkey(), &fixture.token_mint);
+        assert_eq!(token_balance(&svm, &participant_ata), 200_000);
+        assert_eq!(token_balance(&svm, &fixture.vault), 0);
+    }
+
+    #[test]
+    fn test_revoke_mid_vesting_after_partial_claim() {
+        let mut svm = setup_svm();
+
+        // 2 of 9 steps vested, half of which was already claimed
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let fixture = setup_revoke(&mut svm, 3, start_timestamp, 900_000, 100_000);
+
+        let result = send_revoke(&mut svm, &fixture);
+        assert!(
+            result.is_ok(),
+            "Revoke after a partial claim should succeed"
+        );
+
+        assert_eq!(token_balance(&svm, &fixture.authority_ata), 700_000);
+        assert_eq!(token_balance(&svm, &fixture.vault), 100_000);
+        assert_eq!(
+            read_participant(&svm, &fixture.participant_state),
+            (200_000, 100_000, 0, true)
+        );
+    }
+
+    #[test]
+    fn test_revoke_after_partial_claim_sweeps_only_the_surplus() {
+        let mut svm = setup_svm();
+
+        // 2 of 9 steps vested, half of which was already claimed out of the vault
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let fixture = setup_revoke(&mut svm, 11, start_timestamp, 900_000, 100_000);
+        set_total_claimed(&mut svm, &fixture.schedule, 100_000);
+
+        assert!(send_revoke(&mut svm, &fixture).is_ok());
+        assert_eq!(read_total_allocated(&svm, &fixture.schedule), 200_000);
+        assert_eq!(token_balance(&svm, &fixture.authority_ata), 700_000);
+
+        // The vault's 100,000 is still owed to the participant, so there is nothing to sweep
+        svm.expire_blockhash();
+        let result = send_sweep_surplus(&mut svm, &fixture);
+        assert_custom_error(&result, NO_SURPLUS_ERROR);
+
+        // A stray deposit on top of it is swept, the participant's share is not
+        create_ata_with_balance(&mut svm, &fixture.schedule, &fixture.token_mint, 150_000);
+        svm.expire_blockhash();
+        let result = send_sweep_surplus(&mut svm, &fixture);
+        assert!(result.is_ok(), "The stray deposit should be sweepable");
+        assert_eq!(token_balance(&svm, &fixture.vault), 100_000);
+        assert_eq!(token_balance(&svm, &fixture.authority_ata), 750_000);
+    }
+
+    #[test]
+    fn test_revoke_after_completion_returns_nothing() {
+        let mut svm = setup_svm();
+
+        // Started 30 days ago, fully vested
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 30) as i64) as u64;
+        let fixture = setup_revoke(&mut svm, 4, start_timestamp, 900_000, 0);
+
+        let result = send_revoke(&mut svm, &fixture);
+        assert!(result.is_ok(), "Revoke after completion should succeed");
+
+        assert_eq!(token_balance(&svm, &fixture.authority_ata), 0);
+        assert_eq!(token_balance(&svm, &fixture.vault), 900_000);
+        assert_eq!(
+            read_participant(&svm, &fixture.participant_state),
+            (900_000, 0, 0, true)
+        );
+    }
+
+    #[test]
+    fn test_revoke_to_configured_treasury() {
+        let mut svm = setup_svm();
+
+        // Started 3 days ago: 2 of 9 steps vested = 200,000 of 900,000
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let fixture = setup_revoke(&mut svm, 8, start_timestamp, 900_000, 0);
+
+        let treasury = Keypair::new();
+        let treasury_ata =
+            create_ata_with_balance(&mut svm, &treasury.pubkey(), &fixture.token_mint, 0);
+        set_revoke_destination(&mut svm, &fixture.schedule, &treasury_ata);
+
+        let result = send_revoke_to(&mut svm, &fixture, &treasury_ata);
+        assert!(
+            result.is_ok(),
+            "Revoke to the configured treasury should succeed"
+        );
+
+        assert_eq!(token_balance(&svm, &treasury_ata), 700_000);
+        assert_eq!(token_balance(&svm, &fixture.authority_ata), 0);
+        assert_eq!(token_balance(&svm, &fixture.vault), 200_000);
+    }
+
+    // ==================== FAILURE CASES ====================
+
+    #[test]
+    fn test_revoke_to_authority_when_treasury_configured_rejected() {
+        let mut svm = setup_svm();
+
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let fixture = setup_revoke(&mut svm, 9, start_timestamp, 900_000, 0);
+
+        let treasury = Keypair::new();
+        let treasury_ata =
+            create_ata_with_balance(&mut svm, &treasury.pubkey(), &fixture.token_mint, 0);
+        set_revoke_destination(&mut svm, &fixture.schedule, &treasury_ata);
+
+        let result = send_revoke(&mut svm, &fixture);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::InvalidAccountData)
+        );
+        assert_eq!(token_balance(&svm, &fixture.vault), 900_000);
+    }
+
+    #[test]
+    fn test_claim_after_revoke_does_not_keep_vesting() {
+        let mut svm = setup_svm();
+
+        // Started 3 days ago: 2 of 9 steps vested and already claimed
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let fixture = setup_revoke(&mut svm, 7, start_timestamp, 900_000, 200_000);
+
+        let result = send_revoke(&mut svm, &fixture);
+        assert!(result.is_ok(), "Revoke should succeed");
+        assert_eq!(token_balance(&svm, &fixture.authority_ata), 700_000);
+
+        // Without the revoke everything would have vested by now
+        warp_to_timestamp(&mut svm, JAN_1_2025 + (ONE_DAY * 30) as i64);
+        let result = send_claim(&mut svm, &fixture);
+        assert_custom_error(&result, VESTING_REVOKED_ERROR);
+
+        assert_eq!(
+            read_participant(&svm, &fixture.participant_state),
+            (200_000, 200_000, 0, true)
+        );
+    }
+
+    #[test]
+    fn test_revoke_twice_rejected() {
+        let mut svm = setup_svm();
+
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let fixture = setup_revoke(&mut svm, 5, start_timestamp, 900_000, 0);
+
+        assert!(send_revoke(&mut svm, &fixture).is_ok());
+
+        svm.expire_blockhash();
+        let result = send_revoke(&mut svm, &fixture);
+        assert_custom_error(&result, VESTING_REVOKED_ERROR);
+    }
+
+    #[test]
+    fn test_revoke_more_than_total_allocated_rejected() {
+        let mut svm = setup_svm();
+
+        let start_timestamp = (JAN_1_2025 + ONE_DAY as i64) as u64;
+        let fixture = setup_revoke(&mut svm, 10, start_timestamp, 900_000, 0);
+        // Accounting that lost track of part of this allocation
+        set_total_allocated(&mut svm, &fixture.schedule, 500_000);
+
+        let result = send_revoke(&mut svm, &fixture);
+        assert_custom_error(&result, ALLOCATION_OVERFLOW_ERROR);
+
+        assert_eq!(token_balance(&svm, &fixture.vault), 900_000);
+        assert_eq!(read_total_allocated(&svm, &fixture.schedule), 500_000);
+    }
+
+    #[test]
+    fn test_revoke_below_total_claimed_rejected() {
+        let mut svm = setup_svm();
+
+        // Accounting that claims more was paid out than the remaining allocation covers
+        let start_timestamp = (JAN_1_2025 + ONE_DAY as i64) as u64;
+        let fixture = setup_revoke(&mut svm, 12, start_timestamp, 900_000, 0);
+        set_total_claimed(&mut svm, &fixture.schedule, 100_000);
+
+        let result = send_revoke(&mut svm, &fixture);
+        assert_custom_error(&result, ALLOCATION_OVERFLOW_ERROR);
+
+        assert_eq!(token_balance(&svm, &fixture.vault), 900_000);
+        assert_eq!(read_total_allocated(&svm, &fixture.schedule), 900_000);
+    }
+
+    #[test]
+    fn test_revoke_wrong_authority_rejected() {
+        let mut svm = setup_svm();
+
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let mut fixture = setup_revoke(&mut svm, 6, start_timestamp, 900_000, 0);
+
+        let impostor = Keypair::new();
+        svm.airdrop(&impostor.pubkey(), 10_000_000_000).unwrap();
+        fixture.authority_ata =
+            create_ata_with_balance(&mut svm, &impostor.pubkey(), &fixture.token_mint, 0);
+        fixture.authority = impostor;
+
+        let result = send_revoke(&mut svm, &fixture);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::IllegalOwner)
+        );
+        assert_eq!(token_balance(&svm, &fixture.vault), 900_000);
+    }
+}
@@ -0,0 +1,221 @@
+mod common;
+
+#[cfg(test)]
+mod schedule_registry_tests {
+    use crate::common::*;
+    use litesvm::LiteSVM;
+    use pinocchio_system::ID;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction, InstructionError},
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::{Transaction, TransactionError},
+    };
+
+    use spl_associated_token_account::ID as ATA_PROGRAM_ID;
+    use spl_token::ID as TOKEN_PROGRAM_ID;
+    use token_vesting::instruction_builder::{generation_pda, vest_schedule_pda};
+    use token_vesting::ScheduleRegistry;
+
+    fn create_initialize_instruction_data(
+        seed: u64,
+        start_timestamp: u64,
+        cliff_duration: u64,
+        total_duration: u64,
+        step_duration: u64,
+        bump: u8,
+    ) -> Vec<u8> {
+        let mut data = vec![0u8]; // Discriminator for Initialize
+        data.extend_from_slice(&seed.to_le_bytes());
+        data.extend_from_slice(&start_timestamp.to_le_bytes());
+        data.extend_from_slice(&cliff_duration.to_le_bytes());
+        data.extend_from_slice(&total_duration.to_le_bytes());
+        data.extend_from_slice(&step_duration.to_le_bytes());
+        data.push(bump);
+        data.extend_from_slice(&[0u8; 32]); // Cosigner (unused while threshold is 0)
+        data.extend_from_slice(&0u64.to_le_bytes()); // Cosign threshold
+        data.extend_from_slice(&0u64.to_le_bytes()); // Claim window (disabled)
+        data.push(0); // Use vault authority: no
+        data.push(0); // Vault authority bump (unused)
+        data.extend_from_slice(&[0u8; 32]); // Fee recipient (unused while fee is 0)
+        data.extend_from_slice(&0u16.to_le_bytes()); // Claim fee bps
+        data.push(1); // Allow adding participants after start
+        data.push(0); // Vesting mode: stepped
+        data.extend_from_slice(&0u64.to_le_bytes()); // Cliff unlock bps
+        data.extend_from_slice(&[0u8; 32]); // Revoke destination: authority ATA
+        data.push(0); // Pause freezes accrual: no
+        data.extend_from_slice(&0u64.to_le_bytes()); // Max participants: unlimited
+        data.extend_from_slice(&0u64.to_le_bytes()); // Min claim amount: none
+        data.push(0); // Allow escrow destination: no
+        data.extend_from_slice(&[0u8; 16]); // Label: none
+        data.extend_from_slice(&0u64.to_le_bytes()); // Claim cooldown: none
+        data.push(0); // Cooldown exempts participant: no
+        data
+    }
+
+    fn build_initialize_instruction(
+        initializer: &Pubkey,
+        vest_schedule_pda: &Pubkey,
+        token_mint: &Pubkey,
+        vault: &Pubkey,
+        instruction_data: Vec<u8>,
+    ) -> Instruction {
+        // The seed follows the discriminator
+        let seed = u64::from_le_bytes(instruction_data[1..9].try_into().unwrap());
+
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(*initializer, true),
+                AccountMeta::new(*vest_schedule_pda, false),
+                AccountMeta::new_readonly(*token_mint, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new_readonly(ID.into(), false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ATA_PROGRAM_ID, false),
+                AccountMeta::new(generation_pda(seed).0, false),
+            ],
+            data: instruction_data,
+        }
+    }
+
+    fn derive_registry_pda(authority: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"vest_registry", authority.as_ref()], &PROGRAM_ID).0
+    }
+
+    // Initializes a schedule for a fresh mint, passing `registry` after the fixed accounts
+    fn send_initialize_with_registry(
+        svm: &mut LiteSVM,
+        initializer: &Keypair,
+        seed: u64,
+        registry: &Pubkey,
+    ) -> (
+        Pubkey,
+        Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>,
+    ) {
+        let token_mint = create_mock_token_mint(svm, &initializer.pubkey());
+        let (vest_schedule_pda, bump) = vest_schedule_pda(seed);
+        let vault = derive_ata(&vest_schedule_pda, &token_mint);
+
+        let mut instruction = build_initialize_instruction(
+            &initializer.pubkey(),
+            &vest_schedule_pda,
+            &token_mint,
+            &vault,
+            create_initialize_instruction_data(
+                seed,
+                (JAN_1_2025 + ONE_DAY as i64) as u64,
+                ONE_DAY,
+                ONE_DAY * 10,
+                ONE_DAY,
+                bump,
+            ),
+        );
+        instruction
+            .accounts
+            .push(AccountMeta::new(*registry, false));
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&initializer.pubkey()),
+            &[initializer],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        (vest_schedule_pda, result)
+    }
+
+    fn registered_schedules(svm: &LiteSVM, registry: &Pubkey) -> Vec<Pubkey> {
+        let data = svm.get_account(registry).unwrap().data;
+        let count = ScheduleRegistry::load(&data).unwrap().count();
+        assert_eq!(data.len(), ScheduleRegistry::len_for(count));
+
+        (0..count)
+            .map(|index| {
+                let schedule = ScheduleRegistry::schedule_at(&data, index)
+                    .unwrap()
+                    .unwrap();
+                Pubkey::new_from_array(schedule)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_registry_lists_schedules_of_one_authority() {
+        let mut svm = setup_svm();
+        let authority = Keypair::new();
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+        let registry = derive_registry_pda(&authority.pubkey());
+
+        // e.g. a governance token pool and a stablecoin bonus pool
+        let (first_schedule, result) =
+            send_initialize_with_registry(&mut svm, &authority, 1, &registry);
+        assert!(result.is_ok(), "First schedule should create the registry");
+        assert_eq!(registered_schedules(&svm, &registry), vec![first_schedule]);
+
+        let (second_schedule, result) =
+            send_initialize_with_registry(&mut svm, &authority, 2, &registry);
+        assert!(result.is_ok(), "Second schedule should be appended");
+
+        let account = svm.get_account(&registry).unwrap();
+        assert_eq!(account.owner, PROGRAM_ID);
+        assert_eq!(
+            ScheduleRegistry::load(&account.data).unwrap().authority(),
+            &authority.pubkey().to_bytes()
+        );
+        assert_eq!(
+            registered_schedules(&svm, &registry),
+            vec![first_schedule, second_schedule]
+        );
+    }
+
+    #[test]
+    fn test_registry_of_another_authority_rejected() {
+        let mut svm = setup_svm();
+        let authority = Keypair::new();
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+        let other_registry = derive_registry_pda(&Pubkey::new_unique());
+        let (schedule, result) =
+            send_initialize_with_registry(&mut svm, &authority, 3, &other_registry);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::InvalidAccountData)
+        );
+        assert!(svm.get_account(&schedule).is_none());
+    }
+
+    #[test]
+    fn test_register_appends_in_order() {
+        let mut data = vec![0u8; ScheduleRegistry::len_for(0)];
+        ScheduleRegistry::load_mut(&mut data)
+            .unwrap()
+            .set_inner([7u8; 32], 254);
+        assert_eq!(ScheduleRegistry::load(&data).unwrap().count(), 0);
+        assert_eq!(ScheduleRegistry::schedule_at(&data, 0).unwrap(), None);
+
+        // No room for the entry yet
+        assert!(ScheduleRegistry::register(&mut data, &[1u8; 32]).is_err());
+
+        for schedule in [[1u8; 32], [2u8; 32]] {
+            let count = ScheduleRegistry::load(&data).unwrap().count();
+            data.resize(ScheduleRegistry::len_for(count + 1), 0);
+            ScheduleRegistry::register(&mut data, &schedule).unwrap();
+        }
+
+        let registry = ScheduleRegistry::load(&data).unwrap();
+        assert_eq!(registry.count(), 2);
+        assert_eq!(registry.authority(), &[7u8; 32]);
+        assert_eq!(
+            ScheduleRegistry::schedule_at(&data, 0).unwrap(),
+            Some([1u8; 32])
+        );
+        assert_eq!(
+            ScheduleRegistry::schedule_at(&data, 1).unwrap(),
+            Some([2u8; 32])
+        );
+        assert_eq!(ScheduleRegistry::schedule_at(&data, 2).unwrap(), None);
+    }
+}
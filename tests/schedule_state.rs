@@ -0,0 +1,1086 @@
+#[cfg(test)]
+mod schedule_state_tests {
+    use pinocchio::program_error::ProgramError;
+    use token_vesting::{
+        Claimable, PinocchioError, VestParticipant, VestSchedule, VestStatus, ACCOUNT_VERSION,
+    };
+
+    // January 1, 2025 00:00:00 UTC
+    const JAN_1_2025: u64 = 1735689600;
+    const ONE_DAY: u64 = 86_400;
+
+    fn create_vest_schedule_data(
+        start_timestamp: u64,
+        cliff_duration: u64,
+        total_duration: u64,
+        step_duration: u64,
+    ) -> Vec<u8> {
+        let mut data = vec![0u8; VestSchedule::LEN];
+        VestSchedule::load_mut(&mut data).unwrap().set_inner(
+            [1u8; 32],
+            [2u8; 32],
+            [3u8; 32],
+            1,
+            start_timestamp,
+            cliff_duration,
+            total_duration,
+            step_duration,
+            255,
+        );
+        data
+    }
+
+    #[test]
+    fn test_set_inner_writes_current_version() {
+        let data = create_vest_schedule_data(JAN_1_2025, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+        let schedule = VestSchedule::load(&data).unwrap();
+
+        assert_eq!(data[1], ACCOUNT_VERSION);
+        assert_eq!(schedule.version(), ACCOUNT_VERSION);
+    }
+
+    #[test]
+    fn test_load_rejects_unknown_future_version() {
+        let mut data = create_vest_schedule_data(JAN_1_2025, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+        data[1] = ACCOUNT_VERSION + 1;
+
+        assert_eq!(
+            VestSchedule::load(&data).err(),
+            Some(PinocchioError::UnsupportedVersion.into())
+        );
+        assert!(VestSchedule::load_mut(&mut data).is_err());
+    }
+
+    #[test]
+    fn test_total_allocated_checked_add_and_sub() {
+        let mut data = create_vest_schedule_data(JAN_1_2025, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+        let schedule = VestSchedule::load_mut(&mut data).unwrap();
+
+        schedule.add_allocation(u64::MAX - 1).unwrap();
+        assert_eq!(
+            schedule.add_allocation(2).err(),
+            Some(PinocchioError::AllocationOverflow.into())
+        );
+        assert_eq!(
+            schedule.total_allocated(),
+            u64::MAX - 1,
+            "Unchanged after the failed add"
+        );
+
+        assert_eq!(
+            schedule.remove_allocation(u64::MAX).err(),
+            Some(PinocchioError::AllocationOverflow.into())
+        );
+        schedule.remove_allocation(u64::MAX - 1).unwrap();
+        assert_eq!(schedule.total_allocated(), 0);
+    }
+
+    #[test]
+    fn test_remove_allocation_floored_at_total_claimed() {
+        let mut data = create_vest_schedule_data(JAN_1_2025, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+        let schedule = VestSchedule::load_mut(&mut data).unwrap();
+
+        schedule.add_allocation(900_000).unwrap();
+        schedule.add_claimed(300_000).unwrap();
+
+        // Only the 600,000 not yet paid out of the vault can be released
+        assert_eq!(
+            schedule.remove_allocation(600_001).err(),
+            Some(PinocchioError::AllocationOverflow.into())
+        );
+        assert_eq!(schedule.total_allocated(), 900_000);
+
+        schedule.remove_allocation(600_000).unwrap();
+        assert_eq!(schedule.total_allocated(), 300_000);
+        assert_eq!(schedule.surplus(0), 0);
+    }
+
+    #[test]
+    fn test_pause_freezing_accrual_lags_by_paused_duration() {
+        let mut data = create_vest_schedule_data(JAN_1_2025, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+        let schedule = VestSchedule::load_mut(&mut data).unwrap();
+        schedule.set_pause_freezes_accrual(true);
+
+        // Paused after 2 of 9 steps, for 2 days
+        schedule.set_paused(true, JAN_1_2025 + ONE_DAY * 3);
+        assert!(schedule.is_paused());
+        assert_eq!(
+            schedule.vested_amount(JAN_1_2025 + ONE_DAY * 4, 900_000),
+            200_000
+        );
+
+        schedule.set_paused(false, JAN_1_2025 + ONE_DAY * 5);
+        assert!(!schedule.is_paused());
+        assert_eq!(schedule.total_paused(), ONE_DAY * 2);
+
+        // Day 6 vests like day 4 did, and the end moves out by the pause
+        assert_eq!(
+            schedule.vested_amount(JAN_1_2025 + ONE_DAY * 6, 900_000),
+            300_000
+        );
+        assert_eq!(
+            schedule.vested_amount(JAN_1_2025 + ONE_DAY * 11, 900_000),
+            800_000
+        );
+        assert_eq!(
+            schedule.vested_amount(JAN_1_2025 + ONE_DAY * 12, 900_000),
+            900_000
+        );
+    }
+
+    #[test]
+    fn test_repeated_pauses_accumulate_into_total_paused() {
+        let mut data = create_vest_schedule_data(JAN_1_2025, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+        let schedule = VestSchedule::load_mut(&mut data).unwrap();
+        schedule.set_pause_freezes_accrual(true);
+
+        schedule.set_paused(true, JAN_1_2025 + ONE_DAY * 3);
+        schedule.set_paused(false, JAN_1_2025 + ONE_DAY * 4);
+
+        // During the second pause accrual stops at its start, less the first pause
+        schedule.set_paused(true, JAN_1_2025 + ONE_DAY * 6);
+        assert_eq!(
+            schedule.calculate_claimable_amount(JAN_1_2025 + ONE_DAY * 8, 900_000, 0),
+            400_000
+        );
+
+        schedule.set_paused(false, JAN_1_2025 + ONE_DAY * 8);
+        assert_eq!(schedule.total_paused(), ONE_DAY * 3);
+        assert_eq!(
+            schedule.calculate_claimable_amount(JAN_1_2025 + ONE_DAY * 9, 900_000, 400_000),
+            100_000
+        );
+
+        // Three days of pauses push the end from day 10 to day 13
+        assert_eq!(
+            schedule.vested_amount(JAN_1_2025 + ONE_DAY * 12, 900_000),
+            800_000
+        );
+        assert_eq!(
+            schedule.vested_amount(JAN_1_2025 + ONE_DAY * 13, 900_000),
+            900_000
+        );
+    }
+
+    #[test]
+    fn test_pause_without_freezing_keeps_accruing() {
+        let mut data = create_vest_schedule_data(JAN_1_2025, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+        let schedule = VestSchedule::load_mut(&mut data).unwrap();
+
+        schedule.set_paused(true, JAN_1_2025 + ONE_DAY * 3);
+        assert_eq!(
+            schedule.vested_amount(JAN_1_2025 + ONE_DAY * 4, 900_000),
+            300_000
+        );
+
+        schedule.set_paused(false, JAN_1_2025 + ONE_DAY * 5);
+        assert_eq!(schedule.total_paused(), 0);
+        assert_eq!(
+            schedule.vested_amount(JAN_1_2025 + ONE_DAY * 6, 900_000),
+            500_000
+        );
+    }
+
+    #[test]
+    fn test_set_paused_toggles_and_records_timestamp() {
+        let mut data = create_vest_schedule_data(JAN_1_2025, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+        let schedule = VestSchedule::load_mut(&mut data).unwrap();
+        schedule.set_pause_freezes_accrual(true);
+        assert!(!schedule.is_paused());
+        assert_eq!(schedule.paused_at(), 0);
+
+        schedule.set_paused(true, JAN_1_2025 + ONE_DAY);
+        assert!(schedule.is_paused());
+        assert_eq!(schedule.paused_at(), JAN_1_2025 + ONE_DAY);
+
+        // Pausing again keeps the original start of the pause
+        schedule.set_paused(true, JAN_1_2025 + ONE_DAY * 2);
+        assert_eq!(schedule.paused_at(), JAN_1_2025 + ONE_DAY);
+
+        schedule.set_paused(false, JAN_1_2025 + ONE_DAY * 3);
+        assert!(!schedule.is_paused());
+        assert_eq!(schedule.paused_at(), 0);
+        assert_eq!(schedule.total_paused(), ONE_DAY * 2);
+
+        // Resuming while not paused adds nothing
+        schedule.set_paused(false, JAN_1_2025 + ONE_DAY * 4);
+        assert_eq!(schedule.total_paused(), ONE_DAY * 2);
+
+        schedule.set_paused(true, JAN_1_2025 + ONE_DAY * 5);
+        schedule.set_paused(false, JAN_1_2025 + ONE_DAY * 6);
+        assert_eq!(schedule.total_paused(), ONE_DAY * 3);
+    }
+
+    #[test]
+    fn test_pause_at_timestamp_zero_is_still_paused() {
+        let mut data = create_vest_schedule_data(JAN_1_2025, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+        let schedule = VestSchedule::load_mut(&mut data).unwrap();
+        schedule.set_pause_freezes_accrual(true);
+
+        // A clock reading zero leaves paused_at at its unpaused value
+        schedule.set_paused(true, 0);
+        assert!(schedule.is_paused());
+        assert_eq!(schedule.paused_at(), 0);
+
+        // So pausing again doesn't restart the pause, and resuming counts it from zero
+        schedule.set_paused(true, ONE_DAY);
+        assert_eq!(schedule.paused_at(), 0);
+
+        schedule.set_paused(false, ONE_DAY * 2);
+        assert!(!schedule.is_paused());
+        assert_eq!(schedule.total_paused(), ONE_DAY * 2);
+    }
+
+    #[test]
+    fn test_status_boundaries() {
+        let data = create_vest_schedule_data(JAN_1_2025, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+        let schedule = VestSchedule::load(&data).unwrap();
+
+        assert_eq!(schedule.status(JAN_1_2025 - 1), VestStatus::NotStarted);
+        assert_eq!(schedule.status(JAN_1_2025), VestStatus::Cliff);
+        assert_eq!(schedule.status(JAN_1_2025 + ONE_DAY - 1), VestStatus::Cliff);
+        assert_eq!(schedule.status(JAN_1_2025 + ONE_DAY), VestStatus::Stepping);
+        assert_eq!(
+            schedule.status(JAN_1_2025 + ONE_DAY * 10 - 1),
+            VestStatus::Stepping
+        );
+        assert_eq!(
+            schedule.status(JAN_1_2025 + ONE_DAY * 10),
+            VestStatus::Completed
+        );
+
+        assert!(!schedule.is_cliff_completed(JAN_1_2025 + ONE_DAY - 1));
+        assert!(schedule.is_cliff_completed(JAN_1_2025 + ONE_DAY));
+    }
+
+    #[test]
+    fn test_status_without_cliff_skips_cliff_phase() {
+        let data = create_vest_schedule_data(JAN_1_2025, 0, ONE_DAY * 10, ONE_DAY);
+        let schedule = VestSchedule::load(&data).unwrap();
+
+        assert_eq!(schedule.status(JAN_1_2025 - 1), VestStatus::NotStarted);
+        assert_eq!(schedule.status(JAN_1_2025), VestStatus::Stepping);
+    }
+
+    #[test]
+    fn test_zero_cliff_steps_from_start() {
+        // No cliff, then 10 daily steps starting at the start timestamp
+        let schedule = VestSchedule::new_for_test(JAN_1_2025, 0, ONE_DAY * 10, ONE_DAY);
+
+        assert!(schedule.validate().is_ok());
+        assert!(!schedule.is_cliff_completed(JAN_1_2025 - 1));
+        assert!(schedule.is_cliff_completed(JAN_1_2025));
+        assert_eq!(schedule.next_unlock_timestamp(JAN_1_2025 - 1), JAN_1_2025);
+        assert_eq!(
+            schedule.next_unlock_timestamp(JAN_1_2025),
+            JAN_1_2025 + ONE_DAY
+        );
+
+        assert_eq!(
+            schedule.calculate_claimable_amount(JAN_1_2025, 1_000_000, 0),
+            0
+        );
+        assert_eq!(
+            schedule.calculate_claimable_amount(JAN_1_2025 + ONE_DAY - 1, 1_000_000, 0),
+            0
+        );
+        assert_eq!(
+            schedule.calculate_claimable_amount(JAN_1_2025 + ONE_DAY, 1_000_000, 0),
+            100_000
+        );
+        assert_eq!(
+            schedule.calculate_claimable_amount(JAN_1_2025 + ONE_DAY * 10, 1_000_000, 0),
+            1_000_000
+        );
+    }
+
+    #[test]
+    fn test_stepped_claims_release_rounding_dust_at_completion() {
+        // 7 steps that don't divide the allocation evenly
+        let data = create_vest_schedule_data(JAN_1_2025, ONE_DAY, ONE_DAY * 8, ONE_DAY);
+        let schedule = VestSchedule::load(&data).unwrap();
+        let allocated = 1_000_000u64;
+        assert_eq!(schedule.total_steps(), 7);
+
+        let mut claimed = 0u64;
+        for step in 1..7 {
+            let timestamp = JAN_1_2025 + ONE_DAY * (step + 1);
+            let claim = schedule.calculate_claimable_amount(timestamp, allocated, claimed);
+            assert!(
+                claim == allocated / 7 || claim == allocated / 7 + 1,
+                "step {step}: {claim}"
+            );
+            claimed += claim;
+        }
+        assert_eq!(claimed, allocated * 6 / 7, "Rounded down mid-schedule");
+
+        let completion = JAN_1_2025 + ONE_DAY * 8;
+        let final_claim = schedule.calculate_claimable_amount(completion, allocated, claimed);
+        assert_eq!(claimed + final_claim, allocated);
+        assert_eq!(
+            schedule.calculate_claimable_amount(u64::MAX / 2, allocated, allocated),
+            0
+        );
+    }
+
+    #[test]
+    fn test_start_near_u64_max_does_not_wrap_into_vesting() {
+        // start + cliff and start + total both overflow; wrapping would put the cliff and end
+        // in the distant past and release everything
+        let start = u64::MAX - ONE_DAY / 2;
+        let data = create_vest_schedule_data(start, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+        let schedule = VestSchedule::load(&data).unwrap();
+        let allocated = 900_000;
+
+        assert_eq!(schedule.status(JAN_1_2025), VestStatus::NotStarted);
+        assert_eq!(schedule.status(u64::MAX - 1), VestStatus::Cliff);
+        assert_eq!(
+            schedule.calculate_claimable_amount(JAN_1_2025, allocated, 0),
+            0
+        );
+        assert_eq!(
+            schedule.calculate_claimable_amount(u64::MAX - 1, allocated, 0),
+            0
+        );
+        assert_eq!(schedule.next_unlock_timestamp(u64::MAX - 1), u64::MAX);
+    }
+
+    #[test]
+    fn test_whole_unit_allocation_accumulates_across_steps() {
+        // 3 units of a 0-decimal mint over 9 steps: one unit every third step
+        let data = create_vest_schedule_data(JAN_1_2025, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+        let schedule = VestSchedule::load(&data).unwrap();
+        let allocated = 3u64;
+        assert_eq!(schedule.total_steps(), 9);
+
+        let mut claimed = 0u64;
+        let mut claims = Vec::new();
+        for step in 1..=9 {
+            let timestamp = JAN_1_2025 + ONE_DAY * (step + 1);
+            let claim = schedule.calculate_claimable_amount(timestamp, allocated, claimed);
+            claims.push(claim);
+            claimed += claim;
+        }
+
+        assert_eq!(claims, vec![0, 0, 1, 0, 0, 1, 0, 0, 1]);
+        assert_eq!(claimed, allocated);
+    }
+
+    #[test]
+    fn test_next_unlock_timestamp_follows_cliff_and_steps() {
+        // 1 day cliff, then 1 day steps until day 10
+        let mut data = create_vest_schedule_data(JAN_1_2025, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+        let schedule = VestSchedule::load_mut(&mut data).unwrap();
+
+        assert_eq!(
+            schedule.next_unlock_timestamp(JAN_1_2025 - 1),
+            JAN_1_2025 + ONE_DAY
+        );
+        assert_eq!(
+            schedule.next_unlock_timestamp(JAN_1_2025 + 1),
+            JAN_1_2025 + ONE_DAY
+        );
+        assert_eq!(
+            schedule.next_unlock_timestamp(JAN_1_2025 + ONE_DAY),
+            JAN_1_2025 + ONE_DAY * 2
+        );
+        assert_eq!(
+            schedule.next_unlock_timestamp(JAN_1_2025 + ONE_DAY * 3 + ONE_DAY / 2),
+            JAN_1_2025 + ONE_DAY * 4
+        );
+        assert_eq!(
+            schedule.next_unlock_timestamp(JAN_1_2025 + ONE_DAY * 10 - 1),
+            JAN_1_2025 + ONE_DAY * 10
+        );
+        assert_eq!(schedule.next_unlock_timestamp(JAN_1_2025 + ONE_DAY * 10), 0);
+
+        // A frozen pause unlocks nothing until resumed, then the boundaries move out
+        schedule.set_pause_freezes_accrual(true);
+        schedule.set_paused(true, JAN_1_2025 + ONE_DAY * 3);
+        assert_eq!(schedule.next_unlock_timestamp(JAN_1_2025 + ONE_DAY * 4), 0);
+        schedule.set_paused(false, JAN_1_2025 + ONE_DAY * 5);
+        assert_eq!(
+            schedule.next_unlock_timestamp(JAN_1_2025 + ONE_DAY * 5),
+            JAN_1_2025 + ONE_DAY * 6
+        );
+        assert_eq!(
+            schedule.next_unlock_timestamp(JAN_1_2025 + ONE_DAY * 11),
+            JAN_1_2025 + ONE_DAY * 12
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_zero_total_steps() {
+        let data = create_vest_schedule_data(JAN_1_2025, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+        assert!(VestSchedule::load(&data).unwrap().validate().is_ok());
+
+        // The step is longer than the 12 hours left after the cliff
+        let mut data =
+            create_vest_schedule_data(JAN_1_2025, ONE_DAY, ONE_DAY + ONE_DAY / 2, ONE_DAY);
+        assert_eq!(VestSchedule::load(&data).unwrap().total_steps(), 0);
+        assert_eq!(
+            VestSchedule::load(&data).unwrap().validate().err(),
+            Some(PinocchioError::InvalidDurations.into())
+        );
+
+        // Linear schedules don't step, so the same durations are fine
+        let schedule = VestSchedule::load_mut(&mut data).unwrap();
+        schedule.set_vesting_mode(VestSchedule::VESTING_MODE_LINEAR);
+        assert!(schedule.validate().is_ok());
+
+        let data = create_vest_schedule_data(JAN_1_2025, ONE_DAY, ONE_DAY * 10, 0);
+        assert_eq!(
+            VestSchedule::load(&data).unwrap().validate().err(),
+            Some(PinocchioError::InvalidDurations.into())
+        );
+    }
+
+    #[test]
+    fn test_zero_step_duration_vests_only_at_completion_without_panicking() {
+        // Initialize never writes this, but an out-of-band account could
+        let data = create_vest_schedule_data(JAN_1_2025, ONE_DAY, ONE_DAY * 10, 0);
+        let schedule = VestSchedule::load(&data).unwrap();
+        let allocated = 900_000;
+        assert_eq!(schedule.total_steps(), 0);
+
+        for now in [
+            JAN_1_2025,
+            JAN_1_2025 + ONE_DAY,
+            JAN_1_2025 + ONE_DAY * 5,
+            JAN_1_2025 + ONE_DAY * 10 - 1,
+        ] {
+            assert_eq!(
+                schedule.calculate_claimable_amount(now, allocated, 0),
+                0,
+                "at {now}"
+            );
+        }
+        assert_eq!(
+            schedule.next_unlock_timestamp(JAN_1_2025 + ONE_DAY * 5),
+            JAN_1_2025 + ONE_DAY * 10
+        );
+        assert_eq!(schedule.steps_covered(allocated, allocated), 0);
+
+        assert_eq!(
+            schedule.calculate_claimable_amount(JAN_1_2025 + ONE_DAY * 10, allocated, 0),
+            allocated
+        );
+    }
+
+    #[test]
+    fn test_claim_fee_rounds_down() {
+        let mut data = create_vest_schedule_data(JAN_1_2025, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+        let schedule = VestSchedule::load_mut(&mut data).unwrap();
+
+        assert_eq!(schedule.claim_fee(200_000), 0, "No fee configured");
+
+        schedule.set_claim_fee([9u8; 32], 250);
+        assert_eq!(schedule.claim_fee(200_000), 5_000);
+        assert_eq!(schedule.claim_fee(39), 0);
+        assert_eq!(schedule.claim_fee(u64::MAX), u64::MAX / 40);
+    }
+
+    #[test]
+    fn test_len_matches_struct_layout() {
+        // discriminator(1) + version(1) + 8*Pubkey(256) + 16*u64(128) + fee_bps(2) + 2*bump(2) + 7*flag(7) + label(16) + generation(4)
+        assert_eq!(VestSchedule::LEN, 417);
+        assert_eq!(VestSchedule::LEN, std::mem::size_of::<VestSchedule>());
+    }
+
+    #[test]
+    fn test_set_inner_writes_discriminator_and_vault() {
+        let data = create_vest_schedule_data(JAN_1_2025, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+
+        assert_eq!(data[0], VestSchedule::DISCRIMINATOR);
+        // discriminator(1) + version(1) + token_mint(32) + authority(32)
+        assert_eq!(&data[66..98], &[3u8; 32]);
+        assert_eq!(VestSchedule::load(&data).unwrap().vault(), &[3u8; 32]);
+    }
+
+    #[test]
+    fn test_linear_vesting_claimable_amounts() {
+        // Cliff 1 day, total 10 days: 9 days of per-second vesting after the cliff
+        let mut data = create_vest_schedule_data(JAN_1_2025, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+        VestSchedule::load_mut(&mut data)
+            .unwrap()
+            .set_vesting_mode(VestSchedule::VESTING_MODE_LINEAR);
+        let schedule = VestSchedule::load(&data).unwrap();
+        let cliff_end = JAN_1_2025 + ONE_DAY;
+        let allocated = 900_000u64;
+
+        assert!(schedule.is_linear());
+        assert_eq!(
+            schedule.calculate_claimable_amount(cliff_end - 1, allocated, 0),
+            0
+        );
+        assert_eq!(
+            schedule.calculate_claimable_amount(cliff_end, allocated, 0),
+            0
+        );
+        // 100_000 per day, so ~1.157 per second, rounded down
+        assert_eq!(
+            schedule.calculate_claimable_amount(cliff_end + 1, allocated, 0),
+            1
+        );
+        assert_eq!(
+            schedule.calculate_claimable_amount(cliff_end + ONE_DAY / 2, allocated, 0),
+            50_000
+        );
+        assert_eq!(
+            schedule.calculate_claimable_amount(cliff_end + ONE_DAY * 3, allocated, 0),
+            300_000
+        );
+        assert_eq!(
+            schedule.calculate_claimable_amount(cliff_end + ONE_DAY * 3, allocated, 250_000),
+            50_000
+        );
+        assert_eq!(
+            schedule.calculate_claimable_amount(JAN_1_2025 + ONE_DAY * 10, allocated, 0),
+            allocated
+        );
+        assert_eq!(
+            schedule.calculate_claimable_amount(u64::MAX, allocated, 0),
+            allocated
+        );
+    }
+
+    #[test]
+    fn test_linear_vesting_runs_ahead_of_stepped_between_steps() {
+        let stepped_data = create_vest_schedule_data(JAN_1_2025, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+        let stepped = VestSchedule::load(&stepped_data).unwrap();
+
+        let mut linear_data = stepped_data.clone();
+        VestSchedule::load_mut(&mut linear_data)
+            .unwrap()
+            .set_vesting_mode(VestSchedule::VESTING_MODE_LINEAR);
+        let linear = VestSchedule::load(&linear_data).unwrap();
+
+        let cliff_end = JAN_1_2025 + ONE_DAY;
+        let allocated = 900_000u64;
+
+        // Both modes agree on step boundaries, linear is ahead in between
+        for step in 0..=9 {
+            let at = cliff_end + ONE_DAY * step;
+            assert_eq!(
+                linear.vested_amount(at, allocated),
+                stepped.vested_amount(at, allocated)
+            );
+            if step < 9 {
+                let mid = at + ONE_DAY / 2;
+                assert!(
+                    linear.vested_amount(mid, allocated) > stepped.vested_amount(mid, allocated)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_steps_covered_inverts_vested_at_step() {
+        // 7 steps over 700_001 tokens so step sizes differ by rounding
+        let data = create_vest_schedule_data(JAN_1_2025, ONE_DAY, ONE_DAY * 8, ONE_DAY);
+        let schedule = VestSchedule::load(&data).unwrap();
+        let allocated = 700_001u64;
+
+        assert_eq!(schedule.total_steps(), 7);
+        assert_eq!(schedule.steps_covered(0, allocated), 0);
+
+        for step in 1..=7 {
+            let vested = schedule.vested_at_step(step, allocated);
+            assert_eq!(schedule.steps_covered(vested, allocated), step);
+            assert_eq!(schedule.steps_covered(vested - 1, allocated), step - 1);
+        }
+
+        assert_eq!(schedule.vested_at_step(7, allocated), allocated);
+        assert_eq!(schedule.steps_covered(u64::MAX, allocated), 7);
+        assert_eq!(schedule.steps_covered(u64::MAX, 0), 0);
+    }
+
+    fn create_backloaded_schedule_data() -> Vec<u8> {
+        // Cliff 1 day, total 11 days: 10 steps of 1 day after the cliff
+        let mut data = create_vest_schedule_data(JAN_1_2025, ONE_DAY, ONE_DAY * 11, ONE_DAY);
+        VestSchedule::load_mut(&mut data)
+            .unwrap()
+            .set_vesting_mode(VestSchedule::VESTING_MODE_BACKLOADED);
+        data
+    }
+
+    #[test]
+    fn test_backloaded_releases_less_than_linear_at_half_time() {
+        let backloaded_data = create_backloaded_schedule_data();
+        let backloaded = VestSchedule::load(&backloaded_data).unwrap();
+
+        let mut linear_data = backloaded_data.clone();
+        VestSchedule::load_mut(&mut linear_data)
+            .unwrap()
+            .set_vesting_mode(VestSchedule::VESTING_MODE_LINEAR);
+        let linear = VestSchedule::load(&linear_data).unwrap();
+
+        let cliff_end = JAN_1_2025 + ONE_DAY;
+        let half_time = cliff_end + ONE_DAY * 5;
+        let allocated = 1_000_000u64;
+
+        assert!(backloaded.is_backloaded());
+        assert_eq!(
+            linear.calculate_claimable_amount(half_time, allocated, 0),
+            500_000
+        );
+        // (5 / 10)^2 of the allocation
+        assert_eq!(
+            backloaded.calculate_claimable_amount(half_time, allocated, 0),
+            250_000
+        );
+        assert_eq!(
+            backloaded.calculate_claimable_amount(cliff_end + ONE_DAY, allocated, 0),
+            10_000
+        );
+        assert_eq!(
+            backloaded.calculate_claimable_amount(cliff_end + ONE_DAY * 9, allocated, 0),
+            810_000
+        );
+
+        // Both clamp to the full allocation at completion
+        let end = JAN_1_2025 + ONE_DAY * 11;
+        assert_eq!(
+            backloaded.calculate_claimable_amount(end, allocated, 0),
+            allocated
+        );
+        assert_eq!(
+            linear.calculate_claimable_amount(end, allocated, 0),
+            allocated
+        );
+        assert_eq!(
+            backloaded.calculate_claimable_amount(u64::MAX, u64::MAX, 0),
+            u64::MAX
+        );
+    }
+
+    #[test]
+    fn test_backloaded_steps_covered_inverts_vested_at_step() {
+        let data = create_backloaded_schedule_data();
+        let schedule = VestSchedule::load(&data).unwrap();
+        let allocated = 1_000_003u64;
+
+        assert_eq!(schedule.steps_covered(0, allocated), 0);
+
+        for step in 1..=10 {
+            let vested = schedule.vested_at_step(step, allocated);
+            assert!(vested > schedule.vested_at_step(step - 1, allocated));
+            assert_eq!(schedule.steps_covered(vested, allocated), step);
+            assert_eq!(schedule.steps_covered(vested - 1, allocated), step - 1);
+        }
+
+        assert_eq!(schedule.vested_at_step(10, allocated), allocated);
+        assert_eq!(schedule.steps_covered(u64::MAX, allocated), 10);
+    }
+
+    #[test]
+    fn test_cliff_only_unlocks_everything_at_cliff() {
+        // Initialize stores the total and step durations as the cliff
+        let mut data =
+            create_vest_schedule_data(JAN_1_2025, ONE_DAY * 30, ONE_DAY * 30, ONE_DAY * 30);
+        VestSchedule::load_mut(&mut data)
+            .unwrap()
+            .set_vesting_mode(VestSchedule::VESTING_MODE_CLIFF);
+        let schedule = VestSchedule::load(&data).unwrap();
+        let cliff_end = JAN_1_2025 + ONE_DAY * 30;
+        let allocated = 1_000_000u64;
+
+        assert!(schedule.is_cliff_only());
+        assert!(schedule.validate().is_ok());
+        assert_eq!(
+            schedule.calculate_claimable_amount(cliff_end - 1, allocated, 0),
+            0
+        );
+        assert_eq!(schedule.next_unlock_timestamp(cliff_end - 1), cliff_end);
+        assert_eq!(
+            schedule.calculate_claimable_amount(cliff_end, allocated, 0),
+            allocated
+        );
+        assert_eq!(
+            schedule.calculate_claimable_amount(cliff_end, allocated, 400_000),
+            600_000
+        );
+        assert!(schedule.is_completed(cliff_end));
+        assert_eq!(schedule.next_unlock_timestamp(cliff_end), 0);
+
+        // A participant cliff override moves the end with it, ignoring any total override
+        let terms = schedule.with_duration_overrides(ONE_DAY * 10, ONE_DAY * 90);
+        assert!(terms.validate().is_ok());
+        assert_eq!(
+            terms.vested_amount(JAN_1_2025 + ONE_DAY * 10, allocated),
+            allocated
+        );
+
+        // Crafted durations that don't end at the cliff are rejected
+        let mut crafted =
+            create_vest_schedule_data(JAN_1_2025, ONE_DAY * 30, ONE_DAY * 60, ONE_DAY);
+        VestSchedule::load_mut(&mut crafted)
+            .unwrap()
+            .set_vesting_mode(VestSchedule::VESTING_MODE_CLIFF);
+        assert!(VestSchedule::load(&crafted).unwrap().validate().is_err());
+    }
+
+    #[test]
+    fn test_claimable_says_why_nothing_is_claimable() {
+        let data = create_vest_schedule_data(JAN_1_2025, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+        let schedule = VestSchedule::load(&data).unwrap();
+        let cliff_end = JAN_1_2025 + ONE_DAY;
+        let end = JAN_1_2025 + ONE_DAY * 10;
+        let allocated = 900_000u64;
+
+        assert_eq!(
+            schedule.claimable(cliff_end - 1, allocated, 0),
+            Claimable::BeforeCliff
+        );
+        // At the cliff end no step has completed yet
+        assert_eq!(
+            schedule.claimable(cliff_end, allocated, 0),
+            Claimable::NothingNewYet
+        );
+        assert_eq!(
+            schedule.claimable(cliff_end + ONE_DAY, allocated, 0),
+            Claimable::Amount(100_000)
+        );
+        assert_eq!(
+            schedule.claimable(cliff_end + ONE_DAY, allocated, 100_000),
+            Claimable::NothingNewYet
+        );
+        assert_eq!(
+            schedule.claimable(end, allocated, 100_000),
+            Claimable::Amount(800_000)
+        );
+        assert_eq!(
+            schedule.claimable(end, allocated, allocated),
+            Claimable::Completed
+        );
+    }
+
+    #[test]
+    fn test_claimable_tells_expired_apart_from_completed() {
+        let mut data = create_vest_schedule_data(JAN_1_2025, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+        VestSchedule::load_mut(&mut data)
+            .unwrap()
+            .set_claim_window(ONE_DAY * 2);
+        let schedule = VestSchedule::load(&data).unwrap();
+        let end = JAN_1_2025 + ONE_DAY * 10;
+        let allocated = 900_000u64;
+
+        // Everything vested more than two days ago and nothing was claimed
+        assert_eq!(
+            schedule.claimable(end + ONE_DAY * 3, allocated, 0),
+            Claimable::Expired
+        );
+        // Once the expired part is recorded as forfeited the allocation is done
+        assert_eq!(
+            schedule.claimable(end + ONE_DAY * 3, allocated, allocated),
+            Claimable::Completed
+        );
+        // Inside the window the last steps are still claimable
+        assert_eq!(
+            schedule.claimable(end + ONE_DAY, allocated, 0),
+            Claimable::Amount(100_000)
+        );
+    }
+
+    fn create_cliff_unlock_schedule_data(cliff_unlock_bps: u64) -> Vec<u8> {
+        // Cliff 1 day, total 5 days: 4 steps of 1 day after the cliff
+        let mut data = create_vest_schedule_data(JAN_1_2025, ONE_DAY, ONE_DAY * 5, ONE_DAY);
+        VestSchedule::load_mut(&mut data)
+            .unwrap()
+            .set_cliff_unlock_bps(cliff_unlock_bps);
+        data
+    }
+
+    #[test]
+    fn test_cliff_unlock_zero_is_plain_stepping() {
+        let data = create_cliff_unlock_schedule_data(0);
+        let schedule = VestSchedule::load(&data).unwrap();
+        let cliff_end = JAN_1_2025 + ONE_DAY;
+
+        assert_eq!(schedule.vested_amount(cliff_end - 1, 800_000), 0);
+        assert_eq!(schedule.vested_amount(cliff_end, 800_000), 0);
+        assert_eq!(
+            schedule.vested_amount(cliff_end + ONE_DAY, 800_000),
+            200_000
+        );
+        assert_eq!(
+            schedule.vested_amount(cliff_end + ONE_DAY * 4, 800_000),
+            800_000
+        );
+    }
+
+    #[test]
+    fn test_cliff_unlock_quarter_then_steps_over_remainder() {
+        let data = create_cliff_unlock_schedule_data(2_500);
+        let schedule = VestSchedule::load(&data).unwrap();
+        let cliff_end = JAN_1_2025 + ONE_DAY;
+
+        // 200_000 at the cliff, then the other 600_000 in four steps of 150_000
+        assert_eq!(schedule.cliff_unlock_amount(800_000), 200_000);
+        assert_eq!(schedule.vested_amount(cliff_end - 1, 800_000), 0);
+        assert_eq!(schedule.vested_amount(cliff_end, 800_000), 200_000);
+        assert_eq!(
+            schedule.vested_amount(cliff_end + ONE_DAY - 1, 800_000),
+            200_000
+        );
+        assert_eq!(
+            schedule.vested_amount(cliff_end + ONE_DAY, 800_000),
+            350_000
+        );
+        assert_eq!(
+            schedule.vested_amount(cliff_end + ONE_DAY * 3, 800_000),
+            650_000
+        );
+        assert_eq!(
+            schedule.vested_amount(cliff_end + ONE_DAY * 4, 800_000),
+            800_000
+        );
+
+        // The cliff unlock is not a step of its own
+        assert_eq!(schedule.steps_covered(200_000, 800_000), 0);
+        assert_eq!(schedule.steps_covered(350_000, 800_000), 1);
+        assert_eq!(
+            schedule.vested_at_step(1, 800_000) - schedule.vested_at_step(0, 800_000),
+            150_000
+        );
+    }
+
+    #[test]
+    fn test_cliff_unlock_full_vests_everything_at_cliff() {
+        let data = create_cliff_unlock_schedule_data(10_000);
+        let schedule = VestSchedule::load(&data).unwrap();
+        let cliff_end = JAN_1_2025 + ONE_DAY;
+
+        assert_eq!(schedule.vested_amount(cliff_end - 1, 800_000), 0);
+        assert_eq!(schedule.vested_amount(cliff_end, 800_000), 800_000);
+        assert_eq!(
+            schedule.vested_amount(cliff_end + ONE_DAY * 2, 800_000),
+            800_000
+        );
+        assert_eq!(schedule.steps_covered(800_000, 800_000), 0);
+    }
+
+    #[test]
+    fn test_snapshot_matches_packed_source() {
+        let mut data = create_vest_schedule_data(JAN_1_2025, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+        {
+            let schedule = VestSchedule::load_mut(&mut data).unwrap();
+            schedule.set_cosign([6u8; 32], 500_000);
+            schedule.set_claim_window(ONE_DAY * 2);
+            schedule.set_vault_authority([7u8; 32], 254);
+            schedule.set_claim_fee([8u8; 32], 250);
+            schedule.set_token_program([9u8; 32]);
+            schedule.set_allow_add_after_start(true);
+            schedule.set_vesting_mode(VestSchedule::VESTING_MODE_LINEAR);
+            schedule.set_cliff_unlock_bps(1_000);
+            schedule.set_revoke_destination([10u8; 32]);
+            schedule.add_allocation(1_500_000).unwrap();
+            schedule.set_pause_freezes_accrual(true);
+            schedule.set_paused(true, JAN_1_2025 + ONE_DAY);
+            schedule.set_paused(false, JAN_1_2025 + ONE_DAY * 2);
+            schedule.set_paused(true, JAN_1_2025 + ONE_DAY * 3);
+            schedule.set_claim_cooldown(3_600, true);
+            schedule.set_generation(3);
+        }
+        let schedule = VestSchedule::load(&data).unwrap();
+        let snapshot = schedule.snapshot();
+
+        assert_eq!(snapshot.discriminator, VestSchedule::DISCRIMINATOR);
+        assert_eq!(snapshot.version, schedule.version());
+        assert_eq!(&snapshot.token_mint, schedule.token_mint());
+        assert_eq!(&snapshot.authority, schedule.authority());
+        assert_eq!(&snapshot.vault, schedule.vault());
+        assert_eq!(snapshot.seed, schedule.seed());
+        assert_eq!(snapshot.start_timestamp, schedule.start_timestamp());
+        assert_eq!(snapshot.cliff_duration, schedule.cliff_duration());
+        assert_eq!(snapshot.total_duration, schedule.total_duration());
+        assert_eq!(snapshot.step_duration, schedule.step_duration());
+        assert_eq!(snapshot.bump, schedule.bump());
+        assert_eq!(&snapshot.cosigner, schedule.cosigner());
+        assert_eq!(snapshot.cosign_threshold, schedule.cosign_threshold());
+        assert_eq!(snapshot.claim_window, schedule.claim_window());
+        assert_eq!(&snapshot.vault_authority, schedule.vault_authority());
+        assert_eq!(
+            snapshot.vault_authority_bump,
+            schedule.vault_authority_bump()
+        );
+        assert_eq!(&snapshot.fee_recipient, schedule.fee_recipient());
+        assert_eq!(snapshot.claim_fee_bps, schedule.claim_fee_bps());
+        assert_eq!(&snapshot.token_program, schedule.token_program());
+        assert_eq!(
+            snapshot.allow_add_after_start,
+            schedule.allow_add_after_start()
+        );
+        assert_eq!(snapshot.vesting_mode, schedule.vesting_mode());
+        assert_eq!(snapshot.cliff_unlock_bps, schedule.cliff_unlock_bps());
+        assert_eq!(&snapshot.revoke_destination, schedule.revoke_destination());
+        assert_eq!(snapshot.total_allocated, schedule.total_allocated());
+        assert_eq!(
+            snapshot.pause_freezes_accrual,
+            schedule.pause_freezes_accrual()
+        );
+        assert_eq!(snapshot.paused_at, schedule.paused_at());
+        assert_eq!(snapshot.paused, schedule.is_paused());
+        assert_eq!(snapshot.generation, schedule.generation());
+        assert_eq!(snapshot.total_paused, schedule.total_paused());
+        assert_eq!(snapshot.claim_cooldown, schedule.claim_cooldown());
+        assert_eq!(
+            snapshot.cooldown_exempts_participant,
+            schedule.cooldown_exempts_participant()
+        );
+
+        // Borsh-style serialization has no padding, so it reproduces the account bytes
+        assert_eq!(snapshot.to_bytes().to_vec(), data);
+    }
+
+    #[test]
+    fn test_in_memory_schedule_exact_boundaries() {
+        // 1 day cliff then 9 daily steps, no account buffer needed
+        let schedule = VestSchedule::new_for_test(JAN_1_2025, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+        let cliff_end = JAN_1_2025 + ONE_DAY;
+        let end = JAN_1_2025 + ONE_DAY * 10;
+
+        assert_eq!(schedule.status(JAN_1_2025), VestStatus::Cliff);
+        assert_eq!(
+            schedule.calculate_claimable_amount(cliff_end - 1, 900_000, 0),
+            0
+        );
+        assert_eq!(
+            schedule.calculate_claimable_amount(cliff_end, 900_000, 0),
+            0
+        );
+        assert_eq!(
+            schedule.calculate_claimable_amount(cliff_end + ONE_DAY - 1, 900_000, 0),
+            0
+        );
+        assert_eq!(
+            schedule.calculate_claimable_amount(cliff_end + ONE_DAY, 900_000, 0),
+            100_000
+        );
+        assert_eq!(
+            schedule.calculate_claimable_amount(end - 1, 900_000, 0),
+            800_000
+        );
+        assert_eq!(
+            schedule.calculate_claimable_amount(end, 900_000, 0),
+            900_000
+        );
+        assert_eq!(
+            schedule.calculate_claimable_amount(end, 900_000, 900_000),
+            0
+        );
+    }
+
+    #[test]
+    fn test_in_memory_schedule_matches_account_layout() {
+        let schedule = VestSchedule::new_for_test(JAN_1_2025, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+
+        assert!(schedule.validate().is_ok());
+        assert_eq!(schedule.version(), ACCOUNT_VERSION);
+        assert_eq!(schedule.snapshot().to_bytes().len(), VestSchedule::LEN);
+    }
+
+    #[test]
+    fn test_max_allocation_vests_without_truncation() {
+        // One-second steps over ~585 billion years: the largest step count a u64 clock allows
+        let total_duration = u64::MAX - JAN_1_2025;
+        let mut schedule = VestSchedule::new_for_test(JAN_1_2025, 0, total_duration, 1);
+        let end = JAN_1_2025 + total_duration;
+
+        for vesting_mode in [
+            VestSchedule::VESTING_MODE_STEPPED,
+            VestSchedule::VESTING_MODE_LINEAR,
+            VestSchedule::VESTING_MODE_BACKLOADED,
+        ] {
+            schedule.set_vesting_mode(vesting_mode);
+
+            let mut previous = 0;
+            for timestamp in [
+                JAN_1_2025,
+                JAN_1_2025 + 1,
+                JAN_1_2025 + total_duration / 3,
+                JAN_1_2025 + total_duration / 2,
+                end - 2,
+                end - 1,
+            ] {
+                let vested = schedule.vested_amount(timestamp, u64::MAX);
+                assert!(vested >= previous, "mode {vesting_mode} went backwards");
+                assert!(vested < u64::MAX, "mode {vesting_mode} completed early");
+                previous = vested;
+            }
+
+            // Halfway through, a linear or stepped schedule has vested half (less rounding),
+            // not a wrapped value
+            if vesting_mode != VestSchedule::VESTING_MODE_BACKLOADED {
+                let halfway = schedule.vested_amount(JAN_1_2025 + total_duration / 2, u64::MAX);
+                assert!(
+                    u64::MAX / 2 - halfway <= 1,
+                    "mode {vesting_mode} vested {halfway}"
+                );
+            }
+
+            assert_eq!(schedule.vested_amount(end, u64::MAX), u64::MAX);
+            assert_eq!(schedule.vested_at_step(u64::MAX, u64::MAX), u64::MAX);
+            assert_eq!(
+                schedule.calculate_claimable_amount(end, u64::MAX, 0),
+                u64::MAX
+            );
+        }
+    }
+
+    #[test]
+    fn test_oversized_cliff_unlock_clamps_to_allocation() {
+        // Above 10,000 bps can only be written out of band; it must not vest past the allocation
+        let mut schedule = VestSchedule::new_for_test(JAN_1_2025, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+        schedule.set_cliff_unlock_bps(u64::MAX);
+
+        assert_eq!(schedule.cliff_unlock_amount(u64::MAX), u64::MAX);
+        assert_eq!(
+            schedule.vested_amount(JAN_1_2025 + ONE_DAY, 900_000),
+            900_000
+        );
+        assert_eq!(schedule.vested_at_step(5, 900_000), 900_000);
+    }
+
+    #[test]
+    fn test_from_bytes_round_trips_account_data() {
+        let mut data = create_vest_schedule_data(JAN_1_2025, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+        {
+            let schedule = VestSchedule::load_mut(&mut data).unwrap();
+            schedule.set_claim_fee([8u8; 32], 250);
+            schedule.set_vesting_mode(VestSchedule::VESTING_MODE_BACKLOADED);
+            schedule.add_allocation(1_500_000).unwrap();
+            schedule.set_label(*b"SEED-ROUND\0\0\0\0\0\0");
+            schedule.set_claim_cooldown(3_600, true);
+            schedule.set_generation(2);
+        }
+
+        let view = VestSchedule::from_bytes(&data).unwrap();
+        assert_eq!(view.start_timestamp, JAN_1_2025);
+        assert_eq!(view.claim_fee_bps, 250);
+        assert_eq!(view.vesting_mode, VestSchedule::VESTING_MODE_BACKLOADED);
+        assert_eq!(view.total_allocated, 1_500_000);
+        assert_eq!(&view.label[..10], b"SEED-ROUND");
+        assert_eq!(view.claim_cooldown, 3_600);
+        assert!(view.cooldown_exempts_participant);
+        assert_eq!(view.generation, 2);
+
+        assert_eq!(view.to_bytes().to_vec(), data);
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_other_accounts() {
+        let mut data = create_vest_schedule_data(JAN_1_2025, ONE_DAY, ONE_DAY * 10, ONE_DAY);
+
+        assert_eq!(
+            VestSchedule::from_bytes(&data[..VestSchedule::LEN - 1]).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+
+        data[0] = VestParticipant::DISCRIMINATOR;
+        assert_eq!(
+            VestSchedule::from_bytes(&data).err(),
+            Some(PinocchioError::InvalidDiscriminator.into())
+        );
+    }
+}
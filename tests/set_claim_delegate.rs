@@ -0,0 +1,242 @@
+mod common;
+
+#[cfg(test)]
+mod set_claim_delegate_tests {
+    use crate::common::*;
+    use litesvm::LiteSVM;
+    use pinocchio_system::ID;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction, InstructionError},
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::{Transaction, TransactionError},
+    };
+    use spl_associated_token_account::ID as ATA_PROGRAM_ID;
+    use spl_token::ID as TOKEN_PROGRAM_ID;
+    use token_vesting::instruction_builder::config_pda;
+
+    const CLAIM_DISCRIMINATOR: u8 = 2;
+    const SET_CLAIM_DELEGATE_DISCRIMINATOR: u8 = 31;
+
+    struct Fixture {
+        participant: Keypair,
+        token_mint: Pubkey,
+        schedule: Pubkey,
+        participant_state: Pubkey,
+        vault: Pubkey,
+    }
+
+    // Started 3 days ago with a 1 day cliff and 1 day steps: 2 of 9 steps vested
+    fn setup_schedule(svm: &mut LiteSVM, seed: u64) -> Fixture {
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&participant.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(svm, &authority.pubkey());
+
+        let start_timestamp = (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64;
+        let schedule = create_vest_schedule(
+            svm,
+            &authority.pubkey(),
+            &token_mint,
+            seed,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        let participant_state =
+            create_participant_state(svm, &participant.pubkey(), &schedule, 900_000, 0);
+        let vault = create_ata_with_balance(svm, &schedule, &token_mint, 900_000);
+
+        Fixture {
+            participant,
+            token_mint,
+            schedule,
+            participant_state,
+            vault,
+        }
+    }
+
+    fn send_set_claim_delegate(
+        svm: &mut LiteSVM,
+        fixture: &Fixture,
+        signer: &Keypair,
+        delegate: &Pubkey,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(signer.pubkey(), true),
+                AccountMeta::new(fixture.participant_state, false),
+                AccountMeta::new_readonly(*delegate, false),
+            ],
+            data: vec![SET_CLAIM_DELEGATE_DISCRIMINATOR],
+        };
+
+        svm.expire_blockhash();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        result
+    }
+
+    // Claim signed by `claimant` in the participant's place, passed after Claim's accounts
+    fn send_delegated_claim(
+        svm: &mut LiteSVM,
+        fixture: &Fixture,
+        claimant: &Keypair,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let participant_ata = derive_ata(&fixture.participant.pubkey(), &fixture.token_mint);
+
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(fixture.participant.pubkey(), false),
+                AccountMeta::new(fixture.participant_state, false),
+                AccountMeta::new(participant_ata, false),
+                AccountMeta::new(fixture.schedule, false),
+                AccountMeta::new(fixture.vault, false),
+                AccountMeta::new_readonly(fixture.token_mint, false),
+                AccountMeta::new_readonly(ID.into(), false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ATA_PROGRAM_ID, false),
+                AccountMeta::new_readonly(config_pda().0, false),
+                AccountMeta::new(claimant.pubkey(), true),
+            ],
+            data: vec![CLAIM_DISCRIMINATOR],
+        };
+
+        svm.expire_blockhash();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&claimant.pubkey()),
+            &[claimant],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        result
+    }
+
+    fn stored_delegate(svm: &LiteSVM, fixture: &Fixture) -> Pubkey {
+        let participant_state = read_participant_state(svm, &fixture.participant_state);
+        Pubkey::new_from_array(participant_state.claim_delegate)
+    }
+
+    fn funded_keypair(svm: &mut LiteSVM) -> Keypair {
+        let keypair = Keypair::new();
+        svm.airdrop(&keypair.pubkey(), 10_000_000_000).unwrap();
+        keypair
+    }
+
+    // ==================== SUCCESS CASES ====================
+
+    #[test]
+    fn test_delegate_claims_into_participant_ata() {
+        let mut svm = setup_svm();
+        let fixture = setup_schedule(&mut svm, 1);
+        let delegate = funded_keypair(&mut svm);
+
+        let result =
+            send_set_claim_delegate(&mut svm, &fixture, &fixture.participant, &delegate.pubkey());
+        assert!(
+            result.is_ok(),
+            "Participant should be able to set a delegate"
+        );
+        assert_eq!(stored_delegate(&svm, &fixture), delegate.pubkey());
+
+        // The participant's ATA doesn't exist yet, so the delegate also pays to create it
+        let result = send_delegated_claim(&mut svm, &fixture, &delegate);
+        assert!(result.is_ok(), "Delegate claim should succeed");
+
+        let participant_ata = derive_ata(&fixture.participant.pubkey(), &fixture.token_mint);
+        assert_eq!(token_balance(&svm, &participant_ata), 200_000);
+        assert_eq!(token_balance(&svm, &fixture.vault), 700_000);
+        assert!(svm
+            .get_account(&derive_ata(&delegate.pubkey(), &fixture.token_mint))
+            .is_none());
+    }
+
+    #[test]
+    fn test_participant_clears_delegate() {
+        let mut svm = setup_svm();
+        let fixture = setup_schedule(&mut svm, 2);
+        let delegate = funded_keypair(&mut svm);
+
+        let result =
+            send_set_claim_delegate(&mut svm, &fixture, &fixture.participant, &delegate.pubkey());
+        assert!(result.is_ok());
+
+        // Naming the participant themselves removes the delegate
+        let participant = fixture.participant.pubkey();
+        let result =
+            send_set_claim_delegate(&mut svm, &fixture, &fixture.participant, &participant);
+        assert!(
+            result.is_ok(),
+            "Participant should be able to clear the delegate"
+        );
+        assert_eq!(stored_delegate(&svm, &fixture), Pubkey::default());
+
+        let result = send_delegated_claim(&mut svm, &fixture, &delegate);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+        );
+    }
+
+    // ==================== FAILURE CASES ====================
+
+    #[test]
+    fn test_non_delegate_claim_rejected() {
+        let mut svm = setup_svm();
+        let fixture = setup_schedule(&mut svm, 3);
+        let delegate = funded_keypair(&mut svm);
+        let stranger = funded_keypair(&mut svm);
+
+        // Without any delegate, and then with someone else as the delegate
+        let result = send_delegated_claim(&mut svm, &fixture, &stranger);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+        );
+
+        let result =
+            send_set_claim_delegate(&mut svm, &fixture, &fixture.participant, &delegate.pubkey());
+        assert!(result.is_ok());
+
+        let result = send_delegated_claim(&mut svm, &fixture, &stranger);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+        );
+
+        assert_eq!(token_balance(&svm, &fixture.vault), 900_000);
+    }
+
+    #[test]
+    fn test_set_claim_delegate_by_non_participant_rejected() {
+        let mut svm = setup_svm();
+        let fixture = setup_schedule(&mut svm, 4);
+        let stranger = funded_keypair(&mut svm);
+
+        let result = send_set_claim_delegate(&mut svm, &fixture, &stranger, &stranger.pubkey());
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::IllegalOwner)
+        );
+        assert_eq!(stored_delegate(&svm, &fixture), Pubkey::default());
+    }
+}
@@ -0,0 +1,166 @@
+mod common;
+
+#[cfg(test)]
+mod set_claimed_tests {
+    use crate::common::*;
+    use litesvm::LiteSVM;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction, InstructionError},
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::{Transaction, TransactionError},
+    };
+
+    const SET_CLAIMED_DISCRIMINATOR: u8 = 3;
+
+    // PinocchioError::ClaimExceedsAllocation
+    const CLAIM_EXCEEDS_ALLOCATION_ERROR: u32 = 10;
+    // PinocchioError::ClaimedAmountDecrease
+    const CLAIMED_AMOUNT_DECREASE_ERROR: u32 = 20;
+
+    // 900,000 allocated, of which the 100,000 the participant fixtures have claimed was paid
+    // out of the vault
+    fn create_committed_schedule(svm: &mut LiteSVM, authority: &Pubkey, seed: u64) -> Pubkey {
+        let schedule = create_vest_schedule(
+            svm,
+            authority,
+            &Pubkey::new_unique(),
+            seed,
+            (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+        update_vest_schedule(svm, &schedule, |schedule| {
+            schedule.total_allocated = 900_000;
+            schedule.total_claimed = 100_000;
+        });
+
+        schedule
+    }
+
+    fn send_set_claimed(
+        svm: &mut LiteSVM,
+        authority: &Keypair,
+        schedule: &Pubkey,
+        participant_state: &Pubkey,
+        claimed_amount: u64,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let mut data = vec![SET_CLAIMED_DISCRIMINATOR];
+        data.extend_from_slice(&claimed_amount.to_le_bytes());
+
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(authority.pubkey(), true),
+                AccountMeta::new(*schedule, false),
+                AccountMeta::new(*participant_state, false),
+            ],
+            data,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&authority.pubkey()),
+            &[authority],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        result
+    }
+
+    #[test]
+    fn test_set_claimed_reconciles() {
+        let mut svm = setup_svm();
+        let authority = Keypair::new();
+        let participant = Pubkey::new_unique();
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+        let schedule = create_committed_schedule(&mut svm, &authority.pubkey(), 1);
+        let participant_state =
+            create_participant_state(&mut svm, &participant, &schedule, 900_000, 100_000);
+
+        let result = send_set_claimed(&mut svm, &authority, &schedule, &participant_state, 300_000);
+        assert!(result.is_ok(), "Reconciliation should succeed");
+        assert_eq!(
+            read_participant_state(&svm, &participant_state).claimed_amount,
+            300_000
+        );
+        assert_eq!(
+            read_vest_schedule(&svm, &schedule).total_allocated,
+            700_000,
+            "The 200,000 paid off-chain is no longer owed out of the vault"
+        );
+
+        // Setting the full allocation is the upper bound and still allowed
+        svm.expire_blockhash();
+        let result = send_set_claimed(&mut svm, &authority, &schedule, &participant_state, 900_000);
+        assert!(result.is_ok(), "Claimed may reach the allocation");
+        assert_eq!(
+            read_participant_state(&svm, &participant_state).claimed_amount,
+            900_000
+        );
+        assert_eq!(read_vest_schedule(&svm, &schedule).total_allocated, 100_000);
+    }
+
+    #[test]
+    fn test_set_claimed_below_current_rejected() {
+        let mut svm = setup_svm();
+        let authority = Keypair::new();
+        let participant = Pubkey::new_unique();
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+        let schedule = create_committed_schedule(&mut svm, &authority.pubkey(), 2);
+        let participant_state =
+            create_participant_state(&mut svm, &participant, &schedule, 900_000, 100_000);
+
+        let result = send_set_claimed(&mut svm, &authority, &schedule, &participant_state, 99_999);
+        assert_custom_error(&result, CLAIMED_AMOUNT_DECREASE_ERROR);
+        assert_eq!(
+            read_participant_state(&svm, &participant_state).claimed_amount,
+            100_000
+        );
+    }
+
+    #[test]
+    fn test_set_claimed_above_allocation_rejected() {
+        let mut svm = setup_svm();
+        let authority = Keypair::new();
+        let participant = Pubkey::new_unique();
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+        let schedule = create_committed_schedule(&mut svm, &authority.pubkey(), 3);
+        let participant_state =
+            create_participant_state(&mut svm, &participant, &schedule, 900_000, 100_000);
+
+        let result = send_set_claimed(&mut svm, &authority, &schedule, &participant_state, 900_001);
+        assert_custom_error(&result, CLAIM_EXCEEDS_ALLOCATION_ERROR);
+        assert_eq!(
+            read_participant_state(&svm, &participant_state).claimed_amount,
+            100_000
+        );
+        assert_eq!(read_vest_schedule(&svm, &schedule).total_allocated, 900_000);
+    }
+
+    #[test]
+    fn test_set_claimed_wrong_authority_rejected() {
+        let mut svm = setup_svm();
+        let authority = Keypair::new();
+        let impostor = Keypair::new();
+        let participant = Pubkey::new_unique();
+        svm.airdrop(&impostor.pubkey(), 10_000_000_000).unwrap();
+
+        let schedule = create_committed_schedule(&mut svm, &authority.pubkey(), 4);
+        let participant_state =
+            create_participant_state(&mut svm, &participant, &schedule, 900_000, 100_000);
+
+        let result = send_set_claimed(&mut svm, &impostor, &schedule, &participant_state, 200_000);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::IllegalOwner)
+        );
+    }
+}
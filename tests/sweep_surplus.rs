@@ -0,0 +1,118 @@
+mod common;
+
+#[cfg(test)]
+mod sweep_surplus_tests {
+    use crate::common::*;
+    use litesvm::LiteSVM;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction, InstructionError},
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::{Transaction, TransactionError},
+    };
+    use spl_token::ID as TOKEN_PROGRAM_ID;
+
+    const SWEEP_SURPLUS_DISCRIMINATOR: u8 = 25;
+
+    // PinocchioError::NoSurplus
+    const NO_SURPLUS_ERROR: u32 = 38;
+
+    struct Fixture {
+        authority: Keypair,
+        authority_ata: Pubkey,
+        vault: Pubkey,
+        schedule: Pubkey,
+        token_mint: Pubkey,
+    }
+
+    // Mid-schedule: 100,000 allocated of which 40,000 was claimed, so the vault owes 60,000
+    fn setup_schedule(svm: &mut LiteSVM, seed: u64, vault_balance: u64) -> Fixture {
+        let authority = Keypair::new();
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(svm, &authority.pubkey());
+        let schedule = create_vest_schedule(
+            svm,
+            &authority.pubkey(),
+            &token_mint,
+            seed,
+            (JAN_1_2025 - (ONE_DAY * 5) as i64) as u64,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        update_vest_schedule(svm, &schedule, |schedule| {
+            schedule.total_allocated = 100_000;
+            schedule.total_claimed = 40_000;
+        });
+
+        let authority_ata = create_ata_with_balance(svm, &authority.pubkey(), &token_mint, 0);
+        let vault = create_ata_with_balance(svm, &schedule, &token_mint, vault_balance);
+
+        Fixture {
+            authority,
+            authority_ata,
+            vault,
+            schedule,
+            token_mint,
+        }
+    }
+
+    fn send_sweep_surplus(
+        svm: &mut LiteSVM,
+        fixture: &Fixture,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(fixture.authority.pubkey(), true),
+                AccountMeta::new(fixture.authority_ata, false),
+                AccountMeta::new(fixture.vault, false),
+                AccountMeta::new_readonly(fixture.schedule, false),
+                AccountMeta::new_readonly(fixture.token_mint, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            ],
+            data: vec![SWEEP_SURPLUS_DISCRIMINATOR],
+        };
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&fixture.authority.pubkey()),
+            &[&fixture.authority],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        result
+    }
+
+    #[test]
+    fn test_sweep_surplus_returns_excess_to_authority() {
+        let mut svm = setup_svm();
+        // 30,000 more than the 60,000 still owed
+        let fixture = setup_schedule(&mut svm, 1, 90_000);
+
+        let result = send_sweep_surplus(&mut svm, &fixture);
+        assert!(result.is_ok(), "Sweeping a surplus should succeed");
+
+        assert_eq!(token_balance(&svm, &fixture.authority_ata), 30_000);
+        assert_eq!(token_balance(&svm, &fixture.vault), 60_000);
+    }
+
+    #[test]
+    fn test_sweep_surplus_without_surplus_rejected() {
+        let mut svm = setup_svm();
+        let fixture = setup_schedule(&mut svm, 2, 60_000);
+
+        let result = send_sweep_surplus(&mut svm, &fixture);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::Custom(NO_SURPLUS_ERROR))
+        );
+
+        assert_eq!(token_balance(&svm, &fixture.vault), 60_000);
+    }
+}
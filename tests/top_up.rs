@@ -0,0 +1,211 @@
+mod common;
+
+#[cfg(test)]
+mod top_up_tests {
+    use crate::common::*;
+    use litesvm::LiteSVM;
+    use pinocchio_system::ID;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction, InstructionError},
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::{Transaction, TransactionError},
+    };
+    use spl_token::ID as TOKEN_PROGRAM_ID;
+    use token_vesting::instruction_builder::vest_participant_pda;
+
+    const TOP_UP_DISCRIMINATOR: u8 = 22;
+
+    // PinocchioError::CannotAddParticipantsAfterCliff
+    const CANNOT_ADD_PARTICIPANTS_AFTER_CLIFF_ERROR: u32 = 9;
+
+    fn create_add_participant_instruction_data(
+        allocated_amount: u64,
+        participant_bump: u8,
+    ) -> Vec<u8> {
+        let mut data = vec![1u8]; // Discriminator for AddParticipant
+        data.extend_from_slice(&allocated_amount.to_le_bytes());
+        data.push(participant_bump);
+        data
+    }
+
+    fn build_add_participant_instruction(
+        authority: &Pubkey,
+        authority_ata: &Pubkey,
+        vault: &Pubkey,
+        participant: &Pubkey,
+        participant_state: &Pubkey,
+        schedule: &Pubkey,
+        token_mint: &Pubkey,
+        instruction_data: Vec<u8>,
+    ) -> Instruction {
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(*authority, true),
+                AccountMeta::new(*authority_ata, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new_readonly(*participant, false),
+                AccountMeta::new(*participant_state, false),
+                AccountMeta::new(*schedule, false),
+                AccountMeta::new_readonly(*token_mint, false),
+                AccountMeta::new_readonly(ID.into(), false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            ],
+            data: instruction_data,
+        }
+    }
+
+    struct Fixture {
+        authority: Keypair,
+        authority_ata: Pubkey,
+        vault: Pubkey,
+        participant_state: Pubkey,
+        schedule: Pubkey,
+        token_mint: Pubkey,
+    }
+
+    // Schedule starting tomorrow with a 1 day cliff, and a participant allocated 100,000
+    fn setup_participant(svm: &mut LiteSVM, seed: u64) -> Fixture {
+        let authority = Keypair::new();
+        let participant = Keypair::new();
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(svm, &authority.pubkey());
+        let schedule = create_vest_schedule(
+            svm,
+            &authority.pubkey(),
+            &token_mint,
+            seed,
+            (JAN_1_2025 + ONE_DAY as i64) as u64,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        let authority_ata =
+            create_ata_with_balance(svm, &authority.pubkey(), &token_mint, 1_000_000);
+        let vault = create_ata_with_balance(svm, &schedule, &token_mint, 0);
+        let (participant_state, participant_bump) =
+            vest_participant_pda(&participant.pubkey(), &schedule);
+
+        let instruction = build_add_participant_instruction(
+            &authority.pubkey(),
+            &authority_ata,
+            &vault,
+            &participant.pubkey(),
+            &participant_state,
+            &schedule,
+            &token_mint,
+            create_add_participant_instruction_data(100_000, participant_bump),
+        );
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&authority.pubkey()),
+            &[&authority],
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        assert!(result.is_ok(), "Adding the participant should succeed");
+
+        Fixture {
+            authority,
+            authority_ata,
+            vault,
+            participant_state,
+            schedule,
+            token_mint,
+        }
+    }
+
+    fn send_top_up(
+        svm: &mut LiteSVM,
+        fixture: &Fixture,
+        amount: u64,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let mut data = vec![TOP_UP_DISCRIMINATOR];
+        data.extend_from_slice(&amount.to_le_bytes());
+
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(fixture.authority.pubkey(), true),
+                AccountMeta::new(fixture.authority_ata, false),
+                AccountMeta::new(fixture.vault, false),
+                AccountMeta::new(fixture.participant_state, false),
+                AccountMeta::new(fixture.schedule, false),
+                AccountMeta::new_readonly(fixture.token_mint, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&fixture.authority.pubkey()),
+            &[&fixture.authority],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(transaction);
+        print_transaction_logs(&result);
+        result
+    }
+
+    #[test]
+    fn test_top_up_before_cliff_increases_allocation() {
+        let mut svm = setup_svm();
+        let fixture = setup_participant(&mut svm, 1);
+
+        let result = send_top_up(&mut svm, &fixture, 50_000);
+        assert!(result.is_ok(), "Top-up before the cliff should succeed");
+
+        assert_eq!(
+            read_participant_state(&svm, &fixture.participant_state).allocated_amount,
+            150_000
+        );
+        assert_eq!(
+            read_vest_schedule(&svm, &fixture.schedule).total_allocated,
+            150_000
+        );
+        assert_eq!(token_balance(&svm, &fixture.vault), 150_000);
+        assert_eq!(token_balance(&svm, &fixture.authority_ata), 850_000);
+    }
+
+    #[test]
+    fn test_top_up_after_cliff_rejected() {
+        let mut svm = setup_svm();
+        let fixture = setup_participant(&mut svm, 2);
+
+        // Start is tomorrow and the cliff a day later
+        warp_to_timestamp(&mut svm, JAN_1_2025 + (ONE_DAY * 2) as i64);
+
+        let result = send_top_up(&mut svm, &fixture, 50_000);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(CANNOT_ADD_PARTICIPANTS_AFTER_CLIFF_ERROR)
+            )
+        );
+        assert_eq!(
+            read_participant_state(&svm, &fixture.participant_state).allocated_amount,
+            100_000
+        );
+    }
+
+    #[test]
+    fn test_top_up_zero_amount_rejected() {
+        let mut svm = setup_svm();
+        let fixture = setup_participant(&mut svm, 3);
+
+        let result = send_top_up(&mut svm, &fixture, 0);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::InvalidInstructionData)
+        );
+    }
+}
@@ -0,0 +1,226 @@
+mod common;
+
+#[cfg(test)]
+mod transfer_authority_tests {
+    use crate::common::*;
+    use litesvm::LiteSVM;
+    use pinocchio_system::ID;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction, InstructionError},
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::{Transaction, TransactionError},
+    };
+    use spl_token::ID as TOKEN_PROGRAM_ID;
+    use token_vesting::instruction_builder::vest_participant_pda;
+
+    const TRANSFER_AUTHORITY_DISCRIMINATOR: u8 = 17;
+
+    fn create_add_participant_instruction_data(
+        allocated_amount: u64,
+        participant_bump: u8,
+    ) -> Vec<u8> {
+        let mut data = vec![1u8]; // Discriminator for AddParticipant
+        data.extend_from_slice(&allocated_amount.to_le_bytes());
+        data.push(participant_bump);
+        data
+    }
+
+    fn build_add_participant_instruction(
+        authority: &Pubkey,
+        authority_ata: &Pubkey,
+        vault: &Pubkey,
+        participant: &Pubkey,
+        participant_state: &Pubkey,
+        schedule: &Pubkey,
+        token_mint: &Pubkey,
+        instruction_data: Vec<u8>,
+    ) -> Instruction {
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(*authority, true),
+                AccountMeta::new(*authority_ata, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new_readonly(*participant, false),
+                AccountMeta::new(*participant_state, false),
+                AccountMeta::new(*schedule, false),
+                AccountMeta::new_readonly(*token_mint, false),
+                AccountMeta::new_readonly(ID.into(), false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            ],
+            data: instruction_data,
+        }
+    }
+
+    struct Fixture {
+        authority: Keypair,
+        token_mint: Pubkey,
+        schedule: Pubkey,
+        vault: Pubkey,
+    }
+
+    fn setup_transfer(svm: &mut LiteSVM, seed: u64) -> Fixture {
+        let authority = Keypair::new();
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(svm, &authority.pubkey());
+
+        let start_timestamp = (JAN_1_2025 + ONE_DAY as i64) as u64;
+        let schedule = create_vest_schedule(
+            svm,
+            &authority.pubkey(),
+            &token_mint,
+            seed,
+            start_timestamp,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+        let vault = create_ata_with_balance(svm, &schedule, &token_mint, 0);
+
+        Fixture {
+            authority,
+            token_mint,
+            schedule,
+            vault,
+        }
+    }
+
+    fn send_transfer_authority(
+        svm: &mut LiteSVM,
+        signer: &Keypair,
+        schedule: &Pubkey,
+        new_authority: &Pubkey,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(signer.pubkey(), true),
+                AccountMeta::new(*schedule, false),
+                AccountMeta::new_readonly(*new_authority, false),
+            ],
+            data: vec![TRANSFER_AUTHORITY_DISCRIMINATOR],
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        result
+    }
+
+    fn send_add_participant(
+        svm: &mut LiteSVM,
+        signer: &Keypair,
+        fixture: &Fixture,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let participant = Pubkey::new_unique();
+        let signer_ata =
+            create_ata_with_balance(svm, &signer.pubkey(), &fixture.token_mint, 1_000_000);
+        let (participant_state, participant_bump) =
+            vest_participant_pda(&participant, &fixture.schedule);
+
+        let instruction = build_add_participant_instruction(
+            &signer.pubkey(),
+            &signer_ata,
+            &fixture.vault,
+            &participant,
+            &participant_state,
+            &fixture.schedule,
+            &fixture.token_mint,
+            create_add_participant_instruction_data(100_000, participant_bump),
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        result
+    }
+
+    fn stored_authority(svm: &LiteSVM, schedule: &Pubkey) -> Pubkey {
+        Pubkey::new_from_array(read_vest_schedule(svm, schedule).authority)
+    }
+
+    #[test]
+    fn test_transfer_authority_success() {
+        let mut svm = setup_svm();
+        let fixture = setup_transfer(&mut svm, 1);
+
+        let new_authority = Keypair::new();
+        svm.airdrop(&new_authority.pubkey(), 10_000_000_000)
+            .unwrap();
+
+        let result = send_transfer_authority(
+            &mut svm,
+            &fixture.authority,
+            &fixture.schedule,
+            &new_authority.pubkey(),
+        );
+        assert!(
+            result.is_ok(),
+            "Current authority should be able to hand over"
+        );
+        assert_eq!(
+            stored_authority(&svm, &fixture.schedule),
+            new_authority.pubkey()
+        );
+
+        let result = send_add_participant(&mut svm, &new_authority, &fixture);
+        assert!(result.is_ok(), "New authority should manage the schedule");
+    }
+
+    #[test]
+    fn test_transfer_authority_wrong_authority_rejected() {
+        let mut svm = setup_svm();
+        let fixture = setup_transfer(&mut svm, 2);
+
+        let impostor = Keypair::new();
+        svm.airdrop(&impostor.pubkey(), 10_000_000_000).unwrap();
+
+        let result =
+            send_transfer_authority(&mut svm, &impostor, &fixture.schedule, &impostor.pubkey());
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::IllegalOwner)
+        );
+        assert_eq!(
+            stored_authority(&svm, &fixture.schedule),
+            fixture.authority.pubkey()
+        );
+    }
+
+    #[test]
+    fn test_old_authority_cannot_add_participant_after_transfer() {
+        let mut svm = setup_svm();
+        let fixture = setup_transfer(&mut svm, 3);
+
+        let new_authority = Pubkey::new_unique();
+        let result = send_transfer_authority(
+            &mut svm,
+            &fixture.authority,
+            &fixture.schedule,
+            &new_authority,
+        );
+        assert!(result.is_ok());
+
+        let result = send_add_participant(&mut svm, &fixture.authority, &fixture);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::IllegalOwner)
+        );
+    }
+}
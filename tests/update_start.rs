@@ -0,0 +1,189 @@
+mod common;
+
+#[cfg(test)]
+mod update_start_tests {
+    use crate::common::*;
+    use litesvm::LiteSVM;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction, InstructionError},
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::{Transaction, TransactionError},
+    };
+
+    const UPDATE_START_DISCRIMINATOR: u8 = 20;
+
+    // PinocchioError::StartTimestampInPast
+    const START_TIMESTAMP_IN_PAST_ERROR: u32 = 6;
+    // PinocchioError::InvalidDurations
+    const INVALID_DURATIONS_ERROR: u32 = 7;
+    // PinocchioError::ScheduleAlreadyStarted
+    const SCHEDULE_ALREADY_STARTED_ERROR: u32 = 18;
+
+    struct Fixture {
+        authority: Keypair,
+        schedule: Pubkey,
+    }
+
+    // Schedule starting a day after the current clock
+    fn setup_schedule(svm: &mut LiteSVM, seed: u64) -> Fixture {
+        let authority = Keypair::new();
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+        let token_mint = create_mock_token_mint(svm, &authority.pubkey());
+        let schedule = create_vest_schedule(
+            svm,
+            &authority.pubkey(),
+            &token_mint,
+            seed,
+            (JAN_1_2025 + ONE_DAY as i64) as u64,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+
+        Fixture {
+            authority,
+            schedule,
+        }
+    }
+
+    fn send_update_start(
+        svm: &mut LiteSVM,
+        signer: &Keypair,
+        schedule: &Pubkey,
+        start_timestamp: u64,
+    ) -> Result<litesvm::types::TransactionMetadata, litesvm::types::FailedTransactionMetadata>
+    {
+        let mut data = vec![UPDATE_START_DISCRIMINATOR];
+        data.extend_from_slice(&start_timestamp.to_le_bytes());
+
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(signer.pubkey(), true),
+                AccountMeta::new(*schedule, false),
+            ],
+            data,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+        result
+    }
+
+    fn stored_start(svm: &LiteSVM, schedule: &Pubkey) -> u64 {
+        read_vest_schedule(svm, schedule).start_timestamp
+    }
+
+    #[test]
+    fn test_update_start_reschedules_unstarted_schedule() {
+        let mut svm = setup_svm();
+        let fixture = setup_schedule(&mut svm, 1);
+
+        // Launch slips by a week
+        let new_start = (JAN_1_2025 + ONE_DAY as i64 * 8) as u64;
+        let result = send_update_start(&mut svm, &fixture.authority, &fixture.schedule, new_start);
+        assert!(
+            result.is_ok(),
+            "Authority should be able to move an unstarted schedule"
+        );
+        assert_eq!(stored_start(&svm, &fixture.schedule), new_start);
+
+        // Past the original start, but the schedule hasn't begun under the new one
+        warp_to_timestamp(&mut svm, JAN_1_2025 + ONE_DAY as i64 * 2);
+        let new_start = (JAN_1_2025 + ONE_DAY as i64 * 3) as u64;
+        let result = send_update_start(&mut svm, &fixture.authority, &fixture.schedule, new_start);
+        assert!(result.is_ok(), "The start can also be brought forward");
+        assert_eq!(stored_start(&svm, &fixture.schedule), new_start);
+    }
+
+    #[test]
+    fn test_update_start_after_start_rejected() {
+        let mut svm = setup_svm();
+        let fixture = setup_schedule(&mut svm, 2);
+        let original_start = stored_start(&svm, &fixture.schedule);
+
+        warp_to_timestamp(&mut svm, original_start as i64);
+
+        let result = send_update_start(
+            &mut svm,
+            &fixture.authority,
+            &fixture.schedule,
+            original_start + ONE_DAY * 7,
+        );
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(SCHEDULE_ALREADY_STARTED_ERROR)
+            )
+        );
+        assert_eq!(stored_start(&svm, &fixture.schedule), original_start);
+    }
+
+    #[test]
+    fn test_update_start_to_past_or_by_other_signer_rejected() {
+        let mut svm = setup_svm();
+        let fixture = setup_schedule(&mut svm, 3);
+        let original_start = stored_start(&svm, &fixture.schedule);
+
+        let result = send_update_start(
+            &mut svm,
+            &fixture.authority,
+            &fixture.schedule,
+            (JAN_1_2025 - 1) as u64,
+        );
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(START_TIMESTAMP_IN_PAST_ERROR)
+            )
+        );
+
+        let impostor = Keypair::new();
+        svm.airdrop(&impostor.pubkey(), 10_000_000_000).unwrap();
+        let result = send_update_start(
+            &mut svm,
+            &impostor,
+            &fixture.schedule,
+            original_start + ONE_DAY,
+        );
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::IllegalOwner)
+        );
+        assert_eq!(stored_start(&svm, &fixture.schedule), original_start);
+    }
+
+    #[test]
+    fn test_update_start_past_clock_range_rejected() {
+        let mut svm = setup_svm();
+        let fixture = setup_schedule(&mut svm, 4);
+        let original_start = stored_start(&svm, &fixture.schedule);
+
+        // The end still fits in a u64, but the clock can never reach the start
+        let result = send_update_start(
+            &mut svm,
+            &fixture.authority,
+            &fixture.schedule,
+            i64::MAX as u64 + 1,
+        );
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(INVALID_DURATIONS_ERROR)
+            )
+        );
+        assert_eq!(stored_start(&svm, &fixture.schedule), original_start);
+    }
+}
@@ -0,0 +1,29 @@
+#[cfg(test)]
+mod vault_tests {
+    use pinocchio::program_error::ProgramError;
+    use token_vesting::Vault;
+
+    #[test]
+    fn test_required_amount_sums_components() {
+        assert_eq!(Vault::required_amount(&[]).unwrap(), 0);
+        assert_eq!(
+            Vault::required_amount(&[100_000, 2_500, 500]).unwrap(),
+            103_000
+        );
+    }
+
+    #[test]
+    fn test_required_amount_at_overflow_edge() {
+        // Exactly u64::MAX still fits
+        assert_eq!(
+            Vault::required_amount(&[u64::MAX - 1, 1]).unwrap(),
+            u64::MAX
+        );
+
+        // One more wraps and must be rejected
+        assert_eq!(
+            Vault::required_amount(&[u64::MAX, 1]).unwrap_err(),
+            ProgramError::ArithmeticOverflow
+        );
+    }
+}
@@ -0,0 +1,179 @@
+mod common;
+
+#[cfg(test)]
+mod verify_pdas_tests {
+    use crate::common::*;
+    use litesvm::LiteSVM;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    };
+    use token_vesting::instruction_builder::{vest_participant_pda, vest_schedule_pda};
+
+    const VERIFY_PDAS_DISCRIMINATOR: u8 = 6;
+
+    // Writes the schedule at `address` with `bump` stored, so callers can plant mismatches
+    fn write_vest_schedule(svm: &mut LiteSVM, address: &Pubkey, seed: u64, bump: u8) {
+        let mut schedule = vest_schedule_fixture(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            seed,
+            (JAN_1_2025 - (ONE_DAY * 3) as i64) as u64,
+            ONE_DAY,
+            ONE_DAY * 10,
+            ONE_DAY,
+        );
+        schedule.bump = bump;
+        set_vest_schedule(svm, address, &schedule);
+    }
+
+    fn write_participant_state(
+        svm: &mut LiteSVM,
+        address: &Pubkey,
+        participant: &Pubkey,
+        schedule: &Pubkey,
+        bump: u8,
+    ) {
+        let mut participant_state = vest_participant_fixture(participant, schedule, 900_000, 0);
+        participant_state.bump = bump;
+        set_participant_state(svm, address, &participant_state);
+    }
+
+    // Returns [schedule_ok, participant_ok]
+    fn send_verify_pdas(
+        svm: &mut LiteSVM,
+        schedule: &Pubkey,
+        participant_state: &Pubkey,
+    ) -> Vec<u8> {
+        let payer = Keypair::new();
+        svm.airdrop(&payer.pubkey(), 10_000_000_000).unwrap();
+
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(*schedule, false),
+                AccountMeta::new_readonly(*participant_state, false),
+            ],
+            data: vec![VERIFY_PDAS_DISCRIMINATOR],
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[&payer],
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        print_transaction_logs(&result);
+
+        let meta = result.expect("VerifyPdas should not fail on a mismatch");
+        assert_eq!(meta.return_data.program_id, PROGRAM_ID);
+        meta.return_data.data
+    }
+
+    #[test]
+    fn test_verify_pdas_all_match() {
+        let mut svm = setup_svm();
+        let participant = Pubkey::new_unique();
+
+        let (schedule, schedule_bump) = vest_schedule_pda(1);
+        write_vest_schedule(&mut svm, &schedule, 1, schedule_bump);
+
+        let (participant_state, participant_bump) = vest_participant_pda(&participant, &schedule);
+        write_participant_state(
+            &mut svm,
+            &participant_state,
+            &participant,
+            &schedule,
+            participant_bump,
+        );
+
+        assert_eq!(
+            send_verify_pdas(&mut svm, &schedule, &participant_state),
+            vec![1, 1]
+        );
+    }
+
+    #[test]
+    fn test_verify_pdas_schedule_not_at_its_pda() {
+        let mut svm = setup_svm();
+        let participant = Pubkey::new_unique();
+
+        // Schedule stored for seed 2 but living at the seed 3 address
+        let (schedule, schedule_bump) = vest_schedule_pda(3);
+        write_vest_schedule(&mut svm, &schedule, 2, schedule_bump);
+
+        let (participant_state, participant_bump) = vest_participant_pda(&participant, &schedule);
+        write_participant_state(
+            &mut svm,
+            &participant_state,
+            &participant,
+            &schedule,
+            participant_bump,
+        );
+
+        assert_eq!(
+            send_verify_pdas(&mut svm, &schedule, &participant_state),
+            vec![0, 1]
+        );
+    }
+
+    #[test]
+    fn test_verify_pdas_participant_bump_mismatch() {
+        let mut svm = setup_svm();
+        let participant = Pubkey::new_unique();
+
+        let (schedule, schedule_bump) = vest_schedule_pda(4);
+        write_vest_schedule(&mut svm, &schedule, 4, schedule_bump);
+
+        // Right address, but a stale bump stored in the account
+        let (participant_state, participant_bump) = vest_participant_pda(&participant, &schedule);
+        write_participant_state(
+            &mut svm,
+            &participant_state,
+            &participant,
+            &schedule,
+            participant_bump.wrapping_sub(1),
+        );
+
+        assert_eq!(
+            send_verify_pdas(&mut svm, &schedule, &participant_state),
+            vec![1, 0]
+        );
+    }
+
+    #[test]
+    fn test_verify_pdas_participant_of_another_schedule() {
+        let mut svm = setup_svm();
+        let participant = Pubkey::new_unique();
+
+        let (schedule, schedule_bump) = vest_schedule_pda(5);
+        write_vest_schedule(&mut svm, &schedule, 5, schedule_bump);
+
+        let (other_schedule, other_schedule_bump) = vest_schedule_pda(6);
+        write_vest_schedule(&mut svm, &other_schedule, 6, other_schedule_bump);
+
+        // A valid participant state, but of the second schedule
+        let (participant_state, participant_bump) =
+            vest_participant_pda(&participant, &other_schedule);
+        write_participant_state(
+            &mut svm,
+            &participant_state,
+            &participant,
+            &other_schedule,
+            participant_bump,
+        );
+
+        assert_eq!(
+            send_verify_pdas(&mut svm, &other_schedule, &participant_state),
+            vec![1, 1]
+        );
+        assert_eq!(
+            send_verify_pdas(&mut svm, &schedule, &participant_state),
+            vec![1, 0]
+        );
+    }
+}
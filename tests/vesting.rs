@@ -0,0 +1,12 @@
+#[cfg(test)]
+mod vesting_tests {
+    use token_vesting::{VEST_GENERATION_SEED, VEST_PARTICIPANT_SEED, VEST_SCHEDULE_SEED};
+
+    #[test]
+    fn test_seed_constants_match_pda_derivation_literals() {
+        // Clients derive PDAs with these literals, a change here breaks every existing account
+        assert_eq!(VEST_SCHEDULE_SEED, b"vest_schedule");
+        assert_eq!(VEST_PARTICIPANT_SEED, b"vest_participant");
+        assert_eq!(VEST_GENERATION_SEED, b"vest_gen");
+    }
+}